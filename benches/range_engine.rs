@@ -0,0 +1,143 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use pyrometer::context::exprs::BinOp;
+use pyrometer::Analyzer;
+use shared::analyzer::{GraphLike, Search};
+use shared::context::ContextNode;
+use shared::range::elem::RangeOp;
+use shared::{nodes::FunctionNode, Edge, Node};
+use solang_parser::pt::{Expression, Identifier, Loc};
+use std::collections::BTreeSet;
+
+/// Parses and analyzes a contract, returning the number of statements parsed
+fn statement_count(sol: &str) -> u64 {
+    let mut analyzer = Analyzer::default();
+    analyzer.enable_metrics();
+    let _ = analyzer.parse(sol);
+    analyzer
+        .metrics()
+        .map_or(0, |metrics| metrics.statements as u64)
+}
+
+/// Benchmarks analyzing a whole contract, reported as statements parsed per second
+fn bench_contract(c: &mut Criterion, name: &str, sol: &str) {
+    let mut group = c.benchmark_group("parse_ctx_statement");
+    group.throughput(Throughput::Elements(statement_count(sol)));
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            let mut analyzer = Analyzer::default();
+            analyzer.parse(sol)
+        })
+    });
+    group.finish();
+}
+
+fn arithmetic(c: &mut Criterion) {
+    bench_contract(c, "math", include_str!("../tests/test_data/math.sol"));
+}
+
+fn loops(c: &mut Criterion) {
+    bench_contract(c, "loops", include_str!("../tests/test_data/loops.sol"));
+}
+
+/// The body context of `f(uint256 a, uint256 b)`, used as the context for the operation
+/// benchmarks
+fn op_ctx(analyzer: &mut Analyzer) -> ContextNode {
+    let sol = "contract Ops { function f(uint256 a, uint256 b) public {} }";
+    let (maybe_entry, _) = analyzer.parse(sol);
+    let entry = maybe_entry.unwrap();
+    analyzer
+        .search_children(entry, &Edge::Func)
+        .into_iter()
+        .map(FunctionNode::from)
+        .find(|func| func.name(analyzer).starts_with("f("))
+        .and_then(|func| func.maybe_body_ctx(analyzer))
+        .expect("No body context")
+}
+
+fn variable(name: &str) -> Expression {
+    Expression::Variable(Identifier {
+        loc: Loc::Implicit,
+        name: name.to_string(),
+    })
+}
+
+fn ops(c: &mut Criterion) {
+    let mut analyzer = Analyzer::default();
+    let ctx = op_ctx(&mut analyzer);
+    let (lhs, rhs) = (variable("a"), variable("b"));
+
+    let mut group = c.benchmark_group("op_expr");
+    [
+        RangeOp::Add,
+        RangeOp::Sub,
+        RangeOp::Mul,
+        RangeOp::Div,
+        RangeOp::Mod,
+        RangeOp::Exp,
+        RangeOp::Shl,
+        RangeOp::Shr,
+        RangeOp::BitAnd,
+        RangeOp::BitOr,
+        RangeOp::BitXor,
+    ]
+    .into_iter()
+    .for_each(|op| {
+        group.bench_function(op.to_string(), |b| {
+            b.iter_batched(
+                || analyzer.clone(),
+                |mut analyzer| analyzer.op_expr(Loc::Implicit, &lhs, &rhs, ctx, op, false),
+                BatchSize::SmallInput,
+            )
+        });
+    });
+    group.finish();
+}
+
+/// Every variable name of every context, as `(context, name)` pairs to look up
+fn lookups(analyzer: &Analyzer) -> Vec<(ContextNode, String)> {
+    analyzer
+        .graph
+        .node_indices()
+        .filter(|idx| matches!(analyzer.node(*idx), Node::Context(_)))
+        .map(ContextNode::from)
+        .flat_map(|ctx| {
+            ctx.vars(analyzer)
+                .into_iter()
+                .map(|var| var.name(analyzer))
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .map(move |name| (ctx, name))
+        })
+        .collect()
+}
+
+/// Compares cached variable lookups to scanning the edges of the context
+fn var_lookup(c: &mut Criterion) {
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(include_str!("../tests/test_data/function_calls.sol"));
+    let lookups = lookups(&analyzer);
+
+    let mut group = c.benchmark_group("var_lookup");
+    group.throughput(Throughput::Elements(lookups.len() as u64));
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            lookups.iter().for_each(|(ctx, name)| {
+                black_box(ctx.latest_var_by_name(&analyzer, name));
+            })
+        })
+    });
+    group.bench_function("edge_scan", |b| {
+        b.iter(|| {
+            lookups.iter().for_each(|(ctx, name)| {
+                black_box(
+                    ctx.var_by_name_from_edges(&analyzer, name)
+                        .map(|var| var.latest_version(&analyzer)),
+                );
+            })
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, arithmetic, loops, ops, var_lookup);
+criterion_main!(benches);
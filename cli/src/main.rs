@@ -4,7 +4,14 @@ use clap::{ArgAction, Parser, ValueHint};
 use pyrometer::context::queries::storage_write::StorageRangeQuery;
 use pyrometer::{
     context::{
-        analyzers::{bounds::FunctionVarsBoundAnalyzer, ReportDisplay},
+        analyzers::{
+            bounds::FunctionVarsBoundAnalyzer,
+            findings::{
+                filter_findings, filter_reports, suppress_rules, FindingsAnalyzer, Severity,
+            },
+            registry::{AnalyzerRegistry, BoundsLint},
+            ReportDisplay,
+        },
         queries::storage_write::AccessStorageWriteQuery,
         *,
     },
@@ -23,6 +30,7 @@ use std::process::Command;
 use shared::nodes::FunctionNode;
 
 use shared::Edge;
+use solang_parser::pt::Loc;
 use std::env::temp_dir;
 use std::fs;
 
@@ -41,6 +49,9 @@ struct Args {
     pub dot: bool,
     #[clap(long, short, default_value = "false")]
     pub open_dot: bool,
+    /// Print every context with its variables and their ranges, one per line, in a stable order
+    #[clap(long, default_value = "false")]
+    pub dump: bool,
     #[clap(long, short)]
     pub eval: Option<bool>,
     #[clap(long, short)]
@@ -49,10 +60,68 @@ struct Args {
     pub access_query: Vec<String>,
     #[clap(long, short)]
     pub write_query: Vec<String>,
+    /// Print analysis metrics (graph size, contexts created and statement parse time)
+    #[clap(long, default_value = "false")]
+    pub metrics: bool,
+    /// Disable a lint by name (`infinite-loop`, `unbounded-loop`, `loop-counter-overflow`,
+    /// `mutability`, `unsafe-cast`, `event-arg-mismatch`, `recursion`, `branch-budget`, `cei`,
+    /// `failable-require`, `failable-assert`, `redundant-require`, `unused-return`,
+    /// `packed-hash-collision`, `underflow`, `chained-comparison`, `divide-before-multiply`,
+    /// `index-out-of-bounds`, `over-transfer`, `strict-balance-equality`,
+    /// `missing-access-control`, `fixed-point`, `constant-result`, `tainted-sink` or `bounds`)
+    #[clap(long)]
+    pub disable: Vec<String>,
+    /// Report `require` conditions that can fail instead of only assuming they hold
+    #[clap(long, default_value = "false")]
+    pub strict_requires: bool,
+    /// Don't inline calls to matching functions, i.e. `_transfer`, `ERC20._transfer` or `ERC20.*`,
+    /// using the full range of their return types instead
+    #[clap(long)]
+    pub trusted: Vec<String>,
+    /// Only print lint findings, as JSON for consumption by CI tools
+    #[clap(long, default_value = "false")]
+    pub json: bool,
+    /// Only report lint findings at least this severe (`advice`, `warning` or `error`)
+    #[clap(long, default_value = "advice")]
+    pub min_severity: String,
+    /// Exit with a non-zero status if any lint finding is at least this severe (`advice`,
+    /// `warning` or `error`)
+    #[clap(long)]
+    pub fail_on: Option<String>,
+    /// Analyze with the semantics of this compiler version, i.e. `0.7.6`. Before 0.8.0
+    /// arithmetic wraps instead of reverting on overflow
+    #[clap(long)]
+    pub solc_version: Option<String>,
+}
+
+fn parse_severity(name: &str) -> Severity {
+    Severity::from_name(name).unwrap_or_else(|| {
+        eprintln!("Unknown severity: {name}, expected `advice`, `warning` or `error`");
+        std::process::exit(2)
+    })
+}
+
+fn parse_solc_version(version: &str) -> (u64, u64, u64) {
+    let parts = version
+        .trim_start_matches('v')
+        .split('.')
+        .map(|part| part.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>();
+    match parts.as_deref() {
+        Some([major, minor]) => (*major, *minor, 0),
+        Some([major, minor, patch]) => (*major, *minor, *patch),
+        _ => {
+            eprintln!("Invalid compiler version: {version}, expected i.e. `0.7.6`");
+            std::process::exit(2)
+        }
+    }
 }
 
 fn main() {
     let args = Args::parse();
+    let min_severity = parse_severity(&args.min_severity);
+    let fail_on = args.fail_on.as_deref().map(parse_severity);
+    let solc_version = args.solc_version.as_deref().map(parse_solc_version);
     let path_str = args.path.to_string();
     let verbosity = args.verbosity;
     let config = match verbosity {
@@ -114,7 +183,15 @@ fn main() {
 
     let sol = fs::read_to_string(args.path.clone()).expect("Could not find file");
 
-    let mut analyzer = Analyzer::default();
+    let mut analyzer = Analyzer {
+        strict_requires: args.strict_requires,
+        trusted_fns: args.trusted.clone(),
+        solc_version,
+        ..Default::default()
+    };
+    if args.metrics {
+        analyzer.enable_metrics();
+    }
     let t0 = std::time::Instant::now();
     let (maybe_entry, mut all_sources) = analyzer.parse(&sol);
     let _parse_time = t0.elapsed().as_millis();
@@ -129,6 +206,17 @@ fn main() {
             .collect::<BTreeMap<_, _>>(),
     );
 
+    analyzer
+        .diagnostics
+        .iter()
+        .for_each(|(loc, msg)| match loc {
+            Loc::File(file_no, start, _end) => {
+                let file = file_mapping.get(file_no).map_or("", |file| file.as_str());
+                eprintln!("Warning: {msg} ({file}:{start})")
+            }
+            _ => eprintln!("Warning: {msg}"),
+        });
+
     let mut source_map = sources(
         all_sources
             .iter()
@@ -140,54 +228,99 @@ fn main() {
         println!("{}", analyzer.dot_str_no_tmps());
     }
 
+    if args.dump {
+        println!("{}", analyzer.debug_dump());
+    }
+
+    let mut registry = AnalyzerRegistry::default();
+    registry.register(BoundsLint {
+        report_config: config,
+    });
+    args.disable.iter().for_each(|name| {
+        if !registry.disable(name) {
+            eprintln!("Unknown lint: {name}, known lints: {:?}", registry.names());
+        }
+    });
+
+    if args.json {
+        let sources = all_sources
+            .iter()
+            .map(|(_entry, name, src, _num)| (name.clone(), src.clone()))
+            .collect::<BTreeMap<_, _>>();
+        let findings = analyzer.findings(&file_mapping, &registry);
+        let failed = fail_on.map_or(false, |fail_on| {
+            findings.iter().any(|finding| finding.severity >= fail_on)
+        });
+        let findings = filter_findings(findings, min_severity);
+        println!("{}", analyzer.reports_to_json(&findings, &sources));
+        if failed {
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let all_contracts = analyzer
         .search_children(entry, &Edge::Contract)
         .into_iter()
         .map(ContractNode::from)
         .collect::<Vec<_>>();
     let _t1 = std::time::Instant::now();
-    if args.contracts.is_empty() {
-        let funcs = analyzer.search_children(entry, &Edge::Func);
-        for func in funcs.into_iter() {
-            if !args.funcs.is_empty() {
-                if args.funcs.iter().any(|analyze_for| {
-                    FunctionNode::from(func)
-                        .name(&analyzer)
-                        .starts_with(analyze_for)
-                }) {
-                    if let Some(ctx) = FunctionNode::from(func).maybe_body_ctx(&analyzer) {
-                        let analysis = analyzer.bounds_for_all(&file_mapping, ctx, config);
-                        analysis.print_reports(&mut source_map, &analyzer);
+    // the cli prints the bounds of the selected functions itself
+    if registry.is_enabled("bounds") {
+        if args.contracts.is_empty() {
+            let funcs = analyzer.search_children(entry, &Edge::Func);
+            for func in funcs.into_iter() {
+                if !args.funcs.is_empty() {
+                    if args.funcs.iter().any(|analyze_for| {
+                        FunctionNode::from(func)
+                            .name(&analyzer)
+                            .starts_with(analyze_for)
+                    }) {
+                        if let Some(ctx) = FunctionNode::from(func).maybe_body_ctx(&analyzer) {
+                            let analysis = analyzer.bounds_for_all(&file_mapping, ctx, config);
+                            analysis.print_reports(&mut source_map, &analyzer);
+                        }
                     }
+                } else if let Some(ctx) = FunctionNode::from(func).maybe_body_ctx(&analyzer) {
+                    let analysis = analyzer.bounds_for_all(&file_mapping, ctx, config);
+                    analysis.print_reports(&mut source_map, &analyzer);
                 }
-            } else if let Some(ctx) = FunctionNode::from(func).maybe_body_ctx(&analyzer) {
-                let analysis = analyzer.bounds_for_all(&file_mapping, ctx, config);
-                analysis.print_reports(&mut source_map, &analyzer);
             }
-        }
-    } else {
-        // println!("specified contracts: {:?}", all_contracts);
-        all_contracts
-            .iter()
-            .filter(|contract| args.contracts.contains(&contract.name(&analyzer)))
-            .for_each(|contract| {
-                let funcs = contract.funcs(&analyzer);
-                for func in funcs.into_iter() {
-                    if !args.funcs.is_empty() {
-                        if args.funcs.contains(&func.name(&analyzer)) {
+        } else {
+            // println!("specified contracts: {:?}", all_contracts);
+            all_contracts
+                .iter()
+                .filter(|contract| args.contracts.contains(&contract.name(&analyzer)))
+                .for_each(|contract| {
+                    let funcs = contract.funcs(&analyzer);
+                    for func in funcs.into_iter() {
+                        if !args.funcs.is_empty() {
+                            if args.funcs.contains(&func.name(&analyzer)) {
+                                let ctx = func.body_ctx(&analyzer);
+                                let analysis = analyzer.bounds_for_all(&file_mapping, ctx, config);
+                                analysis.print_reports(&mut source_map, &analyzer);
+                            }
+                        } else {
                             let ctx = func.body_ctx(&analyzer);
                             let analysis = analyzer.bounds_for_all(&file_mapping, ctx, config);
                             analysis.print_reports(&mut source_map, &analyzer);
                         }
-                    } else {
-                        let ctx = func.body_ctx(&analyzer);
-                        let analysis = analyzer.bounds_for_all(&file_mapping, ctx, config);
-                        analysis.print_reports(&mut source_map, &analyzer);
                     }
-                }
-            });
+                });
+        }
     }
 
+    let findings = suppress_rules(
+        analyzer.findings(&file_mapping, &registry),
+        &["bounds".to_string()],
+    );
+    let failed = fail_on.map_or(false, |fail_on| {
+        findings.iter().any(|finding| finding.severity >= fail_on)
+    });
+    filter_reports(findings, min_severity)
+        .into_iter()
+        .for_each(|report| report.print(&mut source_map).unwrap());
+
     args.access_query.iter().for_each(|query| {
         let split: Vec<&str> = query.split('.').collect();
         analyzer
@@ -223,6 +356,14 @@ fn main() {
         println!();
     });
 
+    if let Some(metrics) = analyzer.metrics() {
+        println!("nodes: {}", metrics.nodes);
+        println!("edges: {}", metrics.edges);
+        println!("contexts: {}", metrics.contexts);
+        println!("statements: {}", metrics.statements);
+        println!("statement time: {:?}ms", metrics.statement_time.as_millis());
+    }
+
     // println!("parse time: {:?}ms", parse_time);
     // println!("analyzer time: {:?}ms", t1.elapsed().as_millis());
     // println!("total time: {:?}ms", t0.elapsed().as_millis());
@@ -246,4 +387,8 @@ fn main() {
             .output()
             .expect("failed to execute process");
     }
+
+    if failed {
+        std::process::exit(1);
+    }
 }
@@ -3,16 +3,31 @@ use crate::range::Range;
 use crate::BlockNode;
 use crate::MsgNode;
 
-use crate::context::ContextVarNode;
+use crate::context::{ContextEdge, ContextNode, ContextVarNode};
 use crate::range::range_string::ToRangeString;
-use crate::{Builtin, Edge, Function, FunctionParam, FunctionReturn, Node, NodeIdx};
+use crate::range::{elem::RangeElem, elem_ty::Elem};
+use crate::{
+    Builtin, Concrete, Edge, Function, FunctionNode, FunctionParam, FunctionReturn, Node, NodeIdx,
+    NodeKind, VarType,
+};
 use petgraph::visit::EdgeRef;
+use solang_parser::pt::Loc;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use petgraph::dot::Dot;
-use petgraph::{graph::*, Directed, Direction};
+use petgraph::{graph::*, stable_graph::StableGraph, Directed, Direction};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// The type given to a number literal before any type from its context is applied
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LiteralTy {
+    /// The smallest type that fits the value, i.e. `uint8` for `200`
+    SmallestFitting,
+    /// Always 256 bits, i.e. `uint256` for `200`
+    Full,
+}
 
 pub trait AnalyzerLike: GraphLike {
     type Expr;
@@ -34,24 +49,93 @@ pub trait AnalyzerLike: GraphLike {
     fn parse_expr(&mut self, expr: &Self::Expr) -> NodeIdx;
     fn msg(&mut self) -> MsgNode;
     fn block(&mut self) -> BlockNode;
+    /// The maximum number of times a function can appear in a call stack before recursive calls
+    /// to it are no longer inlined
+    fn max_call_depth(&self) -> usize;
+    /// The maximum number of subcontexts a function can have before further branches are merged
+    /// instead of forked
+    fn max_branch_contexts(&self) -> usize;
+    /// Whether `require` conditions that can fail are recorded for reporting, rather than only
+    /// being assumed to hold. `assert` conditions that can fail are always recorded
+    fn strict_requires(&self) -> bool;
+    /// Whether arithmetic outside of `unchecked` blocks reverts on overflow and underflow, as it
+    /// does from Solidity 0.8.0. When false, all arithmetic wraps, but subtractions that can
+    /// go below zero are still reported unless guarded, i.e. by SafeMath
+    fn checked_arithmetic(&self) -> bool {
+        true
+    }
+    /// The type given to number literals that have no type from their context
+    fn literal_ty(&self) -> LiteralTy {
+        LiteralTy::SmallestFitting
+    }
+    /// Whether calls to the function are treated as opaque: the body isn't inlined and its
+    /// returns take the full range of their types
+    fn is_trusted_fn(&self, _func: FunctionNode) -> bool {
+        false
+    }
+    /// Metrics collection, if enabled. Collection is disabled by default
+    fn metrics_mut(&mut self) -> Option<&mut Metrics> {
+        None
+    }
+    /// Records something the analyzer skipped instead of analyzing, like an unsupported
+    /// expression, so analysis can continue past it. Diagnostics are dropped by default
+    fn add_diagnostic(&mut self, _loc: Loc, _msg: String) {}
+}
+
+/// Performance metrics collected during analysis
+#[derive(Debug, Clone, Default)]
+pub struct Metrics {
+    /// Total number of nodes in the graph
+    pub nodes: usize,
+    /// Total number of edges in the graph
+    pub edges: usize,
+    /// Number of contexts (including forks and subcontexts) created
+    pub contexts: usize,
+    /// Number of statements parsed, including nested statements
+    pub statements: usize,
+    /// Wall-clock time spent parsing statements
+    pub statement_time: Duration,
+    /// Current nesting of statement parsing, so nested statements aren't timed twice
+    statement_depth: usize,
+}
+
+impl Metrics {
+    /// Records the start of a statement, returning a timer if it is a top level statement
+    pub fn enter_statement(&mut self) -> Option<Instant> {
+        self.statements += 1;
+        self.statement_depth += 1;
+        if self.statement_depth == 1 {
+            Some(Instant::now())
+        } else {
+            None
+        }
+    }
+
+    /// Records the end of a statement started with `enter_statement`
+    pub fn exit_statement(&mut self, start: Option<Instant>) {
+        self.statement_depth = self.statement_depth.saturating_sub(1);
+        if let Some(start) = start {
+            self.statement_time += start.elapsed();
+        }
+    }
 }
 
 struct G<'a> {
-    pub graph: &'a Graph<Node, Edge, Directed, usize>,
+    pub graph: &'a StableGraph<Node, Edge, Directed, usize>,
 }
 impl GraphLike for G<'_> {
-    fn graph_mut(&mut self) -> &mut Graph<Node, Edge, Directed, usize> {
+    fn graph_mut(&mut self) -> &mut StableGraph<Node, Edge, Directed, usize> {
         panic!("Should call this")
     }
 
-    fn graph(&self) -> &Graph<Node, Edge, Directed, usize> {
+    fn graph(&self) -> &StableGraph<Node, Edge, Directed, usize> {
         self.graph
     }
 }
 
 pub trait GraphLike {
-    fn graph_mut(&mut self) -> &mut Graph<Node, Edge, Directed, usize>;
-    fn graph(&self) -> &Graph<Node, Edge, Directed, usize>;
+    fn graph_mut(&mut self) -> &mut StableGraph<Node, Edge, Directed, usize>;
+    fn graph(&self) -> &StableGraph<Node, Edge, Directed, usize>;
 
     fn add_node(&mut self, node: impl Into<Node>) -> NodeIdx {
         self.graph_mut().add_node(node.into())
@@ -63,6 +147,11 @@ pub trait GraphLike {
             .expect("Index not in graph")
     }
 
+    /// Gets the kind of a node, for classifying nodes without matching on the full `Node`
+    fn node_kind(&self, node: impl Into<NodeIdx>) -> NodeKind {
+        self.node(node).kind()
+    }
+
     fn node_mut(&mut self, node: impl Into<NodeIdx>) -> &mut Node {
         self.graph_mut()
             .node_weight_mut(node.into())
@@ -75,8 +164,141 @@ pub trait GraphLike {
         to_node: impl Into<NodeIdx>,
         edge: impl Into<Edge>,
     ) {
-        self.graph_mut()
-            .add_edge(from_node.into(), to_node.into(), edge.into());
+        let from_node = from_node.into();
+        let to_node = to_node.into();
+        let edge = edge.into();
+        // keep the context's cache of variables in sync with its variable edges
+        if edge == Edge::Context(ContextEdge::Variable) {
+            let name = match (self.node(from_node), self.node(to_node)) {
+                (Node::ContextVar(cvar), Node::Context(_)) => Some(cvar.name.clone()),
+                _ => None,
+            };
+            if let (Some(name), Node::Context(ctx)) = (name, self.node_mut(to_node)) {
+                ctx.vars_by_name
+                    .entry(name)
+                    .or_insert_with(|| from_node.into());
+            }
+        }
+        self.graph_mut().add_edge(from_node, to_node, edge);
+    }
+
+    /// Removes all edges of the given kind from one node to another, keeping the context's cache
+    /// of variables in sync
+    fn remove_edge(
+        &mut self,
+        from_node: impl Into<NodeIdx>,
+        to_node: impl Into<NodeIdx>,
+        edge: impl Into<Edge>,
+    ) {
+        let from_node = from_node.into();
+        let to_node = to_node.into();
+        let edge = edge.into();
+        let edge_idxs: Vec<_> = self
+            .graph()
+            .edges_directed(from_node, Direction::Outgoing)
+            .filter(|e| e.target() == to_node && *e.weight() == edge)
+            .map(|e| e.id())
+            .collect();
+        edge_idxs.into_iter().for_each(|idx| {
+            self.graph_mut().remove_edge(idx);
+        });
+
+        if edge == Edge::Context(ContextEdge::Variable) {
+            if let Node::Context(ctx) = self.node_mut(to_node) {
+                ctx.vars_by_name
+                    .retain(|_, var| NodeIdx::from(*var) != from_node);
+            }
+        }
+    }
+
+    /// Removes a node and all of its edges. If the node is a variable, the ranges of variables
+    /// that refer to it are re-resolved first so they don't point at a removed node
+    fn remove_node(&mut self, node: impl Into<NodeIdx>)
+    where
+        Self: std::marker::Sized,
+    {
+        self.remove_nodes(BTreeSet::from([node.into()]));
+    }
+
+    /// Removes a batch of nodes and all of their edges, like `remove_node`. The dependents of
+    /// all removed variables are re-resolved in a single pass over the graph, so prefer this
+    /// over removing many nodes one by one
+    fn remove_nodes(&mut self, nodes: BTreeSet<NodeIdx>)
+    where
+        Self: std::marker::Sized,
+    {
+        let vars: BTreeSet<ContextVarNode> = nodes
+            .iter()
+            .filter(|node| matches!(self.node(**node), Node::ContextVar(_)))
+            .map(|node| ContextVarNode::from(*node))
+            .collect();
+        if !vars.is_empty() {
+            self.invalidate_dependents(&vars);
+        }
+        vars.into_iter().for_each(|var| {
+            let node = NodeIdx::from(var);
+            let ctxs: Vec<_> = self
+                .graph()
+                .edges_directed(node, Direction::Outgoing)
+                .filter(|e| *e.weight() == Edge::Context(ContextEdge::Variable))
+                .map(|e| e.target())
+                .collect();
+            ctxs.into_iter()
+                .for_each(|ctx| self.remove_edge(node, ctx, Edge::Context(ContextEdge::Variable)));
+        });
+        nodes.into_iter().for_each(|node| {
+            self.graph_mut().remove_node(node);
+        });
+    }
+
+    /// Re-resolves the ranges of variables that refer to any of `vars` through `Elem::Dynamic`,
+    /// so `vars` can be changed or removed without leaving dependents stale. Bounds that refer to
+    /// them are replaced by their current evaluated value and exclusions that refer to them are
+    /// dropped, which can only widen the range. Returns the variables that were updated
+    fn invalidate_dependents(&mut self, vars: &BTreeSet<ContextVarNode>) -> Vec<ContextVarNode>
+    where
+        Self: std::marker::Sized,
+    {
+        let refers_to_vars =
+            |elem: &Elem<Concrete>| elem.dependent_on().iter().any(|dep| vars.contains(dep));
+        let updates: Vec<_> = self
+            .graph()
+            .node_indices()
+            .filter(|idx| matches!(self.node(*idx), Node::ContextVar(_)))
+            .map(ContextVarNode::from)
+            .filter(|dep| !vars.contains(dep))
+            .filter_map(|dep| {
+                let range = dep.range(self)?;
+                if !refers_to_vars(&range.min)
+                    && !refers_to_vars(&range.max)
+                    && !range.exclusions.iter().any(refers_to_vars)
+                {
+                    return None;
+                }
+
+                let mut new_range = range.clone();
+                if refers_to_vars(&range.min) {
+                    new_range.min = range.evaled_range_min(self);
+                }
+                if refers_to_vars(&range.max) {
+                    new_range.max = range.evaled_range_max(self);
+                }
+                new_range.exclusions.retain(|excl| !refers_to_vars(excl));
+                Some((dep, new_range))
+            })
+            .collect();
+
+        updates
+            .into_iter()
+            .map(|(dep, new_range)| {
+                if let Node::ContextVar(cvar) = self.node_mut(dep) {
+                    if let VarType::BuiltIn(_, ref mut maybe_range) = cvar.ty {
+                        *maybe_range = Some(new_range);
+                    }
+                }
+                dep
+            })
+            .collect()
     }
 
     fn dot_str(&self) -> String
@@ -311,6 +533,57 @@ pub trait GraphLike {
         dot_str.push(raw_end_str.to_string());
         dot_str.join("\n")
     }
+
+    /// A line-oriented dump of every context followed by the latest version of each of its
+    /// non-temporary variables and their evaluated ranges. Contexts are ordered by node index
+    /// and variables by where they were declared, so the output is stable across runs and
+    /// suitable for golden-file comparisons
+    fn debug_dump(&self) -> String
+    where
+        Self: AnalyzerLike,
+        Self: Sized,
+    {
+        let mut lines = vec![];
+        self.graph()
+            .node_indices()
+            .filter(|idx| matches!(self.node(*idx), Node::Context(_)))
+            .map(ContextNode::from)
+            .for_each(|ctx| {
+                let killed = if ctx.is_killed(self) { " (killed)" } else { "" };
+                lines.push(format!("{}{killed}", ctx.path(self)));
+
+                let mut vars = ctx
+                    .local_vars(self)
+                    .into_iter()
+                    .filter(|var| !var.underlying(self).is_tmp)
+                    .collect::<Vec<_>>();
+                vars.sort_by_key(|var| (var.first_version(self).underlying(self).loc, *var));
+                let mut seen = BTreeSet::new();
+                vars.into_iter().for_each(|var| {
+                    let latest = var.latest_version(self);
+                    if !seen.insert(latest) {
+                        return;
+                    }
+                    let range_str = latest
+                        .range(self)
+                        .map(|r| {
+                            format!(
+                                " ∈ [{}, {}]",
+                                r.evaled_range_min(self).to_decimal_string(false, self),
+                                r.evaled_range_max(self).to_decimal_string(true, self)
+                            )
+                        })
+                        .unwrap_or_default();
+                    let underlying = latest.underlying(self);
+                    lines.push(format!(
+                        "    {}: {}{range_str}",
+                        underlying.display_name,
+                        underlying.ty.as_string(self)
+                    ));
+                });
+            });
+        lines.join("\n")
+    }
 }
 
 impl<T> Search for T where T: GraphLike {}
@@ -327,6 +600,32 @@ pub trait Search: GraphLike {
                 .next()
         }
     }
+    /// Finds all descendants of a node that are reachable using only edges of type `edge_ty`. Unlike
+    /// [`Search::search_children`], the search stops at any edge that isn't `edge_ty`. Nodes are only
+    /// visited once, so cycles in the graph are fine.
+    ///
+    /// i.e.: a <-my_edge- b <-my_edge- c <-other_edge- d
+    ///
+    /// This function would return [b, c] if we are looking for `my_edge` and start at a.
+    fn search_for_descendants(&self, start: NodeIdx, edge_ty: &Edge) -> Vec<NodeIdx> {
+        let mut seen: BTreeSet<NodeIdx> = BTreeSet::default();
+        seen.insert(start);
+        let mut descendants = vec![];
+        let mut queue = std::collections::VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            self.graph()
+                .edges_directed(node, Direction::Incoming)
+                .filter(|edge| edge.weight() == edge_ty)
+                .for_each(|edge| {
+                    if seen.insert(edge.source()) {
+                        descendants.push(edge.source());
+                        queue.push_back(edge.source());
+                    }
+                });
+        }
+        descendants
+    }
+
     /// Finds any child nodes that have some edge `edge_ty` incoming. Builds up a set of these
     ///
     /// i.e.: a -my_edge-> b -other_edge-> c -my_edge-> d
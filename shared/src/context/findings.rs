@@ -0,0 +1,172 @@
+use crate::context::{ContextVarNode, TaintSink};
+use crate::nodes::{EventParamNode, FunctionNode};
+use solang_parser::pt::Loc;
+
+/// The kind of a [`ContextFinding`], which a context's findings are keyed by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum FindingKind {
+    InfiniteLoop,
+    UnsafeCast,
+    RecursionLimited,
+    MergedBranch,
+    FailableRequire,
+    UnusedReturn,
+    PackedHashCollision,
+    PossibleUnderflow,
+    ChainedComparison,
+    DivideBeforeMultiply,
+    OutOfBoundsIndex,
+    OverTransfer,
+    StrictBalanceCheck,
+    SenderCheck,
+    ConstantResult,
+    FailableAssert,
+    RedundantRequire,
+    RawCall,
+    UnboundedLoop,
+    EventArgMismatch,
+    TaintedSink,
+    LoopCounterOverflow,
+}
+
+/// Something noticed about a context while analyzing it, which lints report on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContextFinding {
+    /// The context is the body of a loop whose condition is always true and whose body has no
+    /// way of exiting the loop, with the location of the loop
+    InfiniteLoop(Loc),
+    /// A narrowing cast of a variable whose range may not fit in the cast type, with the
+    /// location of the cast and the variable being cast
+    UnsafeCast(Loc, ContextVarNode),
+    /// The context is a recursive call that exceeded the maximum call depth, with the location
+    /// of the call. The call isn't inlined and its returns are widened to the full range of
+    /// their types
+    RecursionLimited(Loc),
+    /// A branch (`if`, `try` or loop) that was merged instead of forked because the function
+    /// exceeded its branch budget
+    MergedBranch(Loc),
+    /// A `require` whose condition can fail. Only recorded when the analyzer treats requires
+    /// strictly
+    FailableRequire(Loc),
+    /// A function call made as a statement whose returns are ignored, with the location of the
+    /// call and the called function
+    UnusedReturn(Loc, FunctionNode),
+    /// The hash of an `abi.encodePacked` call that packs multiple dynamically sized values,
+    /// with the location of the `abi.encodePacked` call
+    PackedHashCollision(Loc),
+    /// A checked unsigned subtraction whose rhs may exceed the lhs, with the location of the
+    /// subtraction and the lhs and rhs
+    PossibleUnderflow(Loc, ContextVarNode, ContextVarNode),
+    /// A comparison with a boolean operand, like `a < b < c`, which Solidity parses as
+    /// `(a < b) < c`
+    ChainedComparison(Loc),
+    /// A multiplication of a division result, like `a / b * c`, which truncates before
+    /// multiplying
+    DivideBeforeMultiply(Loc),
+    /// An array index by a ternary, like `arr[c ? i : j]`, whose branches together reach past
+    /// the largest length the array can have
+    OutOfBoundsIndex(Loc),
+    /// A `transfer` or `send` out of the contract whose amount may exceed the contract's
+    /// balance
+    OverTransfer(Loc),
+    /// A strict equality comparison against the contract's balance, like
+    /// `address(this).balance == x`, which can be broken by force-sending ether
+    StrictBalanceCheck(Loc),
+    /// A require or condition involving `msg.sender` or `tx.origin`, used to tell whether a
+    /// function restricts who can call it
+    SenderCheck(Loc),
+    /// An arithmetic or bitwise operation on a varying operand whose result is always the same
+    /// value, like `x * 0`, with the location of the operation and its result
+    ConstantResult(Loc, ContextVarNode),
+    /// An `assert` whose condition can fail
+    FailableAssert(Loc),
+    /// A `require` whose condition already holds given the ranges at that point
+    RedundantRequire(Loc),
+    /// A call that hands control to an address instead of a known contract function, i.e. a
+    /// `transfer` or `send` out of the contract or a low-level `call` or `delegatecall`, with
+    /// the location and name of the call. Each is an external call to the target
+    RawCall(Loc, String),
+    /// The context is the body of a loop bounded by the length of a dynamic array that nothing
+    /// caps, i.e. `i < arr.length`, with the location of the loop
+    UnboundedLoop(Loc),
+    /// An argument of an emitted event that may not fit the type of its event parameter, with
+    /// the location of the argument, the argument and the parameter
+    EventArgMismatch(Loc, ContextVarNode, EventParamNode),
+    /// A sink that receives a tainted value, with the location of the sink, the kind of sink
+    /// and the tainted variable
+    TaintedSink(Loc, TaintSink, ContextVarNode),
+    /// The context is the body of a for loop whose counter is incremented past the maximum of
+    /// its type before the loop condition fails, i.e. `for (uint8 i; i < 256; i++)`, with the
+    /// location of the increment and the counter
+    LoopCounterOverflow(Loc, ContextVarNode),
+}
+
+impl ContextFinding {
+    pub fn kind(&self) -> FindingKind {
+        match self {
+            ContextFinding::InfiniteLoop(..) => FindingKind::InfiniteLoop,
+            ContextFinding::UnsafeCast(..) => FindingKind::UnsafeCast,
+            ContextFinding::RecursionLimited(..) => FindingKind::RecursionLimited,
+            ContextFinding::MergedBranch(..) => FindingKind::MergedBranch,
+            ContextFinding::FailableRequire(..) => FindingKind::FailableRequire,
+            ContextFinding::UnusedReturn(..) => FindingKind::UnusedReturn,
+            ContextFinding::PackedHashCollision(..) => FindingKind::PackedHashCollision,
+            ContextFinding::PossibleUnderflow(..) => FindingKind::PossibleUnderflow,
+            ContextFinding::ChainedComparison(..) => FindingKind::ChainedComparison,
+            ContextFinding::DivideBeforeMultiply(..) => FindingKind::DivideBeforeMultiply,
+            ContextFinding::OutOfBoundsIndex(..) => FindingKind::OutOfBoundsIndex,
+            ContextFinding::OverTransfer(..) => FindingKind::OverTransfer,
+            ContextFinding::StrictBalanceCheck(..) => FindingKind::StrictBalanceCheck,
+            ContextFinding::SenderCheck(..) => FindingKind::SenderCheck,
+            ContextFinding::ConstantResult(..) => FindingKind::ConstantResult,
+            ContextFinding::FailableAssert(..) => FindingKind::FailableAssert,
+            ContextFinding::RedundantRequire(..) => FindingKind::RedundantRequire,
+            ContextFinding::RawCall(..) => FindingKind::RawCall,
+            ContextFinding::UnboundedLoop(..) => FindingKind::UnboundedLoop,
+            ContextFinding::EventArgMismatch(..) => FindingKind::EventArgMismatch,
+            ContextFinding::TaintedSink(..) => FindingKind::TaintedSink,
+            ContextFinding::LoopCounterOverflow(..) => FindingKind::LoopCounterOverflow,
+        }
+    }
+
+    /// The location the finding was made at
+    pub fn loc(&self) -> Loc {
+        match self {
+            ContextFinding::InfiniteLoop(loc)
+            | ContextFinding::UnsafeCast(loc, _)
+            | ContextFinding::RecursionLimited(loc)
+            | ContextFinding::MergedBranch(loc)
+            | ContextFinding::FailableRequire(loc)
+            | ContextFinding::UnusedReturn(loc, _)
+            | ContextFinding::PackedHashCollision(loc)
+            | ContextFinding::PossibleUnderflow(loc, _, _)
+            | ContextFinding::ChainedComparison(loc)
+            | ContextFinding::DivideBeforeMultiply(loc)
+            | ContextFinding::OutOfBoundsIndex(loc)
+            | ContextFinding::OverTransfer(loc)
+            | ContextFinding::StrictBalanceCheck(loc)
+            | ContextFinding::SenderCheck(loc)
+            | ContextFinding::ConstantResult(loc, _)
+            | ContextFinding::FailableAssert(loc)
+            | ContextFinding::RedundantRequire(loc)
+            | ContextFinding::RawCall(loc, _)
+            | ContextFinding::UnboundedLoop(loc)
+            | ContextFinding::EventArgMismatch(loc, _, _)
+            | ContextFinding::TaintedSink(loc, _, _)
+            | ContextFinding::LoopCounterOverflow(loc, _) => *loc,
+        }
+    }
+
+    /// The variables the finding refers to
+    pub fn vars(&self) -> Vec<ContextVarNode> {
+        match self {
+            ContextFinding::LoopCounterOverflow(_, var)
+            | ContextFinding::UnsafeCast(_, var)
+            | ContextFinding::EventArgMismatch(_, var, _)
+            | ContextFinding::ConstantResult(_, var)
+            | ContextFinding::TaintedSink(_, _, var) => vec![*var],
+            ContextFinding::PossibleUnderflow(_, lhs, rhs) => vec![*lhs, *rhs],
+            _ => vec![],
+        }
+    }
+}
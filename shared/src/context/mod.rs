@@ -1,5 +1,6 @@
 use crate::analyzer::{AnalyzerLike, Search};
-use crate::nodes::FunctionNode;
+use crate::nodes::{Builtin, ErrorNode, FunctionNode};
+use crate::range::SolcRange;
 use crate::ContractNode;
 use crate::FunctionParamNode;
 use crate::GraphLike;
@@ -9,6 +10,8 @@ use solang_parser::pt::Loc;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 
+mod findings;
+pub use findings::*;
 mod var;
 pub use var::*;
 
@@ -73,6 +76,37 @@ impl ModifierState {
     }
 }
 
+/// A relation `lhs + rhs == total` between two named variables and a total, established by a
+/// require like `require(a + b == total)`. The variables are named so that the relation holds
+/// for their latest versions in any subcontext
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SumRelation {
+    pub lhs: String,
+    pub rhs: String,
+    pub total: ContextVarNode,
+}
+
+/// A use of a value that shouldn't be controlled by an attacker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaintSink {
+    /// The target of a `delegatecall`, which runs its code with the caller's storage
+    DelegatecallTarget,
+    /// An index into an array
+    ArrayIndex,
+    /// The ether sent along with a low level `call`
+    CallValue,
+}
+
+impl TaintSink {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaintSink::DelegatecallTarget => "delegatecall target",
+            TaintSink::ArrayIndex => "array index",
+            TaintSink::CallValue => "call value",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Context {
     /// The function associated with this context
@@ -106,6 +140,26 @@ pub struct Context {
     pub ret: Vec<(Loc, ContextVarNode)>,
     /// Range adjustments to occur after the statement finishes. Useful for post in/decrement
     pub post_statement_range_adjs: Vec<(ContextVarNode, Loc, bool)>,
+    /// What lints report on in this context, keyed by the kind of finding
+    pub findings: BTreeMap<FindingKind, Vec<ContextFinding>>,
+    /// If this context was killed by reverting with a custom error, this is the error
+    pub reverted_with: Option<ErrorNode>,
+    /// Whether statements in this context are inside an `unchecked` block, in which case
+    /// arithmetic wraps instead of reverting
+    pub unchecked: bool,
+    /// The conditions of the branches taken to reach this context from the function entry, in
+    /// the order they were taken
+    pub path_conditions: Vec<ContextVarNode>,
+    /// Relations `lhs + rhs == total` established by requires and branch conditions on the
+    /// path to this context, used to narrow one side of the sum when the other narrows
+    pub sum_relations: Vec<SumRelation>,
+    /// The result of the latest `gasleft()` call on the path to this context. Gas only
+    /// decreases, so the next call's result is at most this
+    pub last_gasleft: Option<ContextVarNode>,
+    /// The latest known version of each variable declared in this context, keyed by name, so
+    /// lookups don't have to search the graph. Versions created outside of `advance_var_in_ctx`
+    /// may not be reflected, so entries should be followed by `latest_version`
+    pub vars_by_name: HashMap<String, ContextVarNode>,
 }
 
 impl Context {
@@ -127,6 +181,13 @@ impl Context {
             loc,
             modifier_state: None,
             post_statement_range_adjs: vec![],
+            findings: Default::default(),
+            reverted_with: None,
+            unchecked: false,
+            path_conditions: vec![],
+            sum_relations: vec![],
+            last_gasleft: None,
+            vars_by_name: Default::default(),
         }
     }
 
@@ -174,6 +235,16 @@ impl Context {
             loc,
             modifier_state,
             post_statement_range_adjs: vec![],
+            findings: Default::default(),
+            reverted_with: None,
+            // function calls start checked regardless of the caller
+            unchecked: fn_call.is_none()
+                && ext_fn_call.is_none()
+                && parent_ctx.underlying(analyzer).unchecked,
+            path_conditions: parent_ctx.underlying(analyzer).path_conditions.clone(),
+            sum_relations: parent_ctx.underlying(analyzer).sum_relations.clone(),
+            last_gasleft: parent_ctx.underlying(analyzer).last_gasleft,
+            vars_by_name: Default::default(),
         }
     }
 
@@ -187,6 +258,48 @@ impl Context {
         self.children.push(child_node);
     }
 
+    /// Records a finding in this context
+    pub fn add_finding(&mut self, finding: ContextFinding) {
+        self.findings
+            .entry(finding.kind())
+            .or_default()
+            .push(finding);
+    }
+
+    /// The findings of a kind recorded in this context
+    pub fn findings_of(&self, kind: FindingKind) -> &[ContextFinding] {
+        self.findings.get(&kind).map(|f| &f[..]).unwrap_or_default()
+    }
+
+    /// Every variable this context refers to, which must outlive any pruning of temporaries
+    pub fn referenced_vars(&self) -> Vec<ContextVarNode> {
+        let mut vars: Vec<ContextVarNode> = self.ctx_deps.values().copied().collect();
+        vars.extend(self.ret.iter().map(|(_, var)| *var));
+        vars.extend(
+            self.post_statement_range_adjs
+                .iter()
+                .map(|(var, _, _)| *var),
+        );
+        if let Some(modifier_state) = &self.modifier_state {
+            vars.extend(
+                modifier_state
+                    .renamed_inputs
+                    .iter()
+                    .flat_map(|(from, to)| [*from, *to]),
+            );
+        }
+        vars.extend(
+            self.findings
+                .values()
+                .flatten()
+                .flat_map(ContextFinding::vars),
+        );
+        vars.extend(self.path_conditions.iter().copied());
+        vars.extend(self.sum_relations.iter().map(|relation| relation.total));
+        vars.extend(self.last_gasleft);
+        vars
+    }
+
     pub fn as_string(&mut self) -> String {
         "Context".to_string()
     }
@@ -210,6 +323,64 @@ impl ContextNode {
             .collect()
     }
 
+    /// This context followed by all of its subcontexts, keeping only the nodes that are contexts
+    pub fn self_and_subcontexts(&self, analyzer: &(impl GraphLike + Search)) -> Vec<ContextNode> {
+        let mut ctxs = vec![*self];
+        ctxs.extend(
+            self.subcontexts(analyzer)
+                .into_iter()
+                .filter(|subctx| matches!(analyzer.node(*subctx), Node::Context(_))),
+        );
+        ctxs
+    }
+
+    /// The root context of the function this context is a part of
+    pub fn root(&self, analyzer: &impl GraphLike) -> ContextNode {
+        match self.underlying(analyzer).parent_ctx {
+            Some(parent) => parent.root(analyzer),
+            None => *self,
+        }
+    }
+
+    /// The immediate parent of this context, if it is a branch of or a call from another context
+    pub fn parent(&self, analyzer: &impl GraphLike) -> Option<ContextNode> {
+        self.underlying(analyzer).parent_ctx
+    }
+
+    /// Whether this context was created by a function call from its parent, as opposed to a
+    /// branch of it
+    pub fn is_call(&self, analyzer: &impl GraphLike) -> bool {
+        let underlying = self.underlying(analyzer);
+        underlying.fn_call.is_some() || underlying.ext_fn_call.is_some()
+    }
+
+    /// The function whose body is being executed in this context: the innermost inlined call,
+    /// or the context's own function if it is not part of a call
+    pub fn executing_fn(&self, analyzer: &impl GraphLike) -> FunctionNode {
+        let underlying = self.underlying(analyzer);
+        if let Some(func) = underlying.fn_call.or(underlying.ext_fn_call) {
+            return func;
+        }
+        match underlying.parent_ctx {
+            Some(parent) => parent.executing_fn(analyzer),
+            None => underlying.parent_fn,
+        }
+    }
+
+    /// Gets the number of times the function appears in the call stack leading to this context
+    pub fn call_depth(&self, analyzer: &impl GraphLike, func: FunctionNode) -> usize {
+        let underlying = self.underlying(analyzer);
+        let is_call = underlying.fn_call == Some(func)
+            || underlying.ext_fn_call == Some(func)
+            || (underlying.parent_ctx.is_none() && underlying.parent_fn == func);
+        let depth = usize::from(is_call);
+        if let Some(parent) = underlying.parent_ctx {
+            depth + parent.call_depth(analyzer, func)
+        } else {
+            depth
+        }
+    }
+
     /// Gets the associated contract for the function for the context
     pub fn associated_contract(&self, analyzer: &(impl GraphLike + Search)) -> ContractNode {
         self.associated_fn(analyzer)
@@ -256,6 +427,12 @@ impl ContextNode {
     pub fn is_fn_ext(&self, fn_node: FunctionNode, analyzer: &(impl GraphLike + Search)) -> bool {
         match fn_node.contract(analyzer) {
             None => false,
+            // internal library functions are inlined into the caller
+            Some(fn_ctrt)
+                if fn_ctrt.is_library(analyzer) && !fn_node.is_public_or_ext(analyzer) =>
+            {
+                false
+            }
             Some(fn_ctrt) => {
                 if let Some(self_ctrt) = self.associated_fn(analyzer).contract(analyzer) {
                     Some(self_ctrt) != Some(fn_ctrt)
@@ -298,8 +475,55 @@ impl ContextNode {
         }
     }
 
+    /// Seeds the context with an assumed range for a global variable, i.e. `msg.value`,
+    /// `block.timestamp` or `this.balance` (the contract's own balance). Member accesses of the
+    /// global in this context (or any of its subcontexts) resolve to the seeded variable instead
+    /// of an unconstrained one. Returns `None` if the name isn't a global that can be assumed
+    pub fn assume_global(
+        &self,
+        analyzer: &mut (impl GraphLike + AnalyzerLike),
+        name: &str,
+        range: SolcRange,
+    ) -> Option<ContextVarNode> {
+        let builtin = match name {
+            "msg.sender" | "block.coinbase" => Builtin::Address,
+            "msg.sig" => Builtin::Bytes(4),
+            "msg.value" | "block.basefee" | "block.chainid" | "block.difficulty"
+            | "block.gaslimit" | "block.number" | "block.prevrandao" | "block.timestamp"
+            | "this.balance" => Builtin::Uint(256),
+            _ => return None,
+        };
+
+        let node = analyzer.builtin_or_add(builtin);
+        let mut var = ContextVar::new_from_builtin(Loc::Implicit, node.into(), analyzer);
+        var.name = name.to_string();
+        var.display_name = name.to_string();
+        var.is_tmp = false;
+        var.is_symbolic = true;
+        let cvar = ContextVarNode::from(analyzer.add_node(Node::ContextVar(var)));
+        analyzer.add_edge(cvar, *self, Edge::Context(ContextEdge::Variable));
+        cvar.set_range_min(analyzer, range.min);
+        cvar.set_range_max(analyzer, range.max);
+        cvar.set_range_exclusions(analyzer, range.exclusions);
+        Some(cvar)
+    }
+
     /// Gets a variable by name in the context
     pub fn var_by_name(&self, analyzer: &impl GraphLike, name: &str) -> Option<ContextVarNode> {
+        if let Some(var) = self.underlying(analyzer).vars_by_name.get(name) {
+            Some(var.first_version(analyzer))
+        } else {
+            self.var_by_name_from_edges(analyzer, name)
+        }
+    }
+
+    /// Gets a variable by name in the context by searching the graph instead of using the
+    /// context's cache of variables
+    pub fn var_by_name_from_edges(
+        &self,
+        analyzer: &impl GraphLike,
+        name: &str,
+    ) -> Option<ContextVarNode> {
         analyzer
             .search_children(self.0.into(), &Edge::Context(ContextEdge::Variable))
             .into_iter()
@@ -321,21 +545,7 @@ impl ContextNode {
         analyzer: &impl GraphLike,
         name: &str,
     ) -> Option<ContextVarNode> {
-        if let Some(var) = analyzer
-            .search_children(self.0.into(), &Edge::Context(ContextEdge::Variable))
-            .into_iter()
-            .filter_map(|cvar_node| {
-                let cvar_node = ContextVarNode::from(cvar_node);
-                let cvar = cvar_node.underlying(analyzer);
-                if cvar.name == name {
-                    Some(cvar_node)
-                } else {
-                    None
-                }
-            })
-            .take(1)
-            .next()
-        {
+        if let Some(var) = self.var_by_name(analyzer, name) {
             Some(var)
         } else if let Some(parent) = self.underlying(analyzer).parent_ctx {
             parent.var_by_name_or_recurse(analyzer, name)
@@ -344,6 +554,21 @@ impl ContextNode {
         }
     }
 
+    /// Replaces the cached version of a variable with its next version, if the cache held the
+    /// previous version
+    pub fn cache_next_version(
+        &self,
+        prev: ContextVarNode,
+        next: ContextVarNode,
+        analyzer: &mut impl GraphLike,
+    ) {
+        let name = next.name(analyzer);
+        let vars_by_name = &mut self.underlying_mut(analyzer).vars_by_name;
+        if vars_by_name.get(&name) == Some(&prev) {
+            vars_by_name.insert(name, next);
+        }
+    }
+
     /// Gets all variables associated with a context
     pub fn vars(&self, analyzer: &impl AnalyzerLike) -> Vec<ContextVarNode> {
         analyzer
@@ -353,6 +578,14 @@ impl ContextNode {
             .collect()
     }
 
+    /// Gets all variables associated with a context, ordered by their source location and then
+    /// their name, so the order doesn't depend on how the graph was built. Used for reports
+    pub fn vars_sorted(&self, analyzer: &impl AnalyzerLike) -> Vec<ContextVarNode> {
+        let mut vars = self.vars(analyzer);
+        vars.sort_by_cached_key(|var| (var.underlying(analyzer).loc, var.name(analyzer), *var));
+        vars
+    }
+
     /// Gets all variables associated with a context
     pub fn local_vars(&self, analyzer: &impl AnalyzerLike) -> Vec<ContextVarNode> {
         analyzer
@@ -375,8 +608,12 @@ impl ContextNode {
         analyzer: &impl AnalyzerLike,
         name: &str,
     ) -> Option<ContextVarNode> {
-        self.var_by_name(analyzer, name)
-            .map(|var| var.latest_version(analyzer))
+        if let Some(var) = self.underlying(analyzer).vars_by_name.get(name) {
+            Some(var.latest_version(analyzer))
+        } else {
+            self.var_by_name_from_edges(analyzer, name)
+                .map(|var| var.latest_version(analyzer))
+        }
     }
 
     /// Reads the current temporary counter and increments the counter
@@ -416,6 +653,26 @@ impl ContextNode {
         context.add_child(child);
     }
 
+    /// Sets whether this context and the forks and non-call children it created are inside an
+    /// `unchecked` block
+    pub fn set_unchecked(&self, unchecked: bool, analyzer: &mut impl AnalyzerLike) {
+        self.underlying_mut(analyzer).unchecked = unchecked;
+        let subctxs = self
+            .underlying(analyzer)
+            .forks
+            .iter()
+            .chain(self.underlying(analyzer).children.iter())
+            .copied()
+            .filter(|subctx| {
+                let underlying = subctx.underlying(analyzer);
+                underlying.fn_call.is_none() && underlying.ext_fn_call.is_none()
+            })
+            .collect::<Vec<_>>();
+        subctxs
+            .into_iter()
+            .for_each(|subctx| subctx.set_unchecked(unchecked, analyzer));
+    }
+
     /// Kills the context by denoting it as killed. Recurses up the contexts and kills
     /// parent contexts if all subcontexts of that context are killed
     pub fn kill(&self, analyzer: &mut impl AnalyzerLike, kill_loc: Loc) {
@@ -510,6 +767,54 @@ impl ContextNode {
         }
     }
 
+    /// Formats the conditions of the branches taken to reach this context followed by the ranges
+    /// of the variables they constrain, i.e. `(x > 100) where x: uint256[101, 200]`. Returns
+    /// `None` if no branch was taken
+    pub fn path_condition(&self, analyzer: &impl GraphLike) -> Option<String> {
+        let conds = &self.underlying(analyzer).path_conditions;
+        if conds.is_empty() {
+            return None;
+        }
+
+        let mut constrained: Vec<ContextVarNode> = vec![];
+        conds.iter().for_each(|cond| {
+            if let Some(tmp) = cond.tmp_of(analyzer) {
+                std::iter::once(tmp.lhs)
+                    .chain(tmp.rhs)
+                    .filter(|var| !var.is_const(analyzer))
+                    .for_each(|var| {
+                        // later versions are narrowed by later conditions
+                        let name = var.display_name(analyzer);
+                        constrained.retain(|other| other.display_name(analyzer) != name);
+                        constrained.push(var);
+                    });
+            }
+        });
+
+        let conds_str = conds
+            .iter()
+            .map(|cond| cond.display_name(analyzer))
+            .collect::<Vec<_>>()
+            .join(" && ");
+        let ranges_str = constrained
+            .iter()
+            .filter_map(|var| {
+                Some(format!(
+                    "{}: {}",
+                    var.display_name(analyzer),
+                    var.range_string(analyzer)?
+                ))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        if ranges_str.is_empty() {
+            Some(conds_str)
+        } else {
+            Some(format!("{conds_str} where {ranges_str}"))
+        }
+    }
+
     pub fn add_return_node(
         &self,
         ret_stmt_loc: Loc,
@@ -1,3 +1,4 @@
+use crate::range::elem::RangeElem;
 use crate::range::elem_ty::Dynamic;
 use crate::range::elem_ty::Elem;
 use crate::range::elem_ty::RangeConcrete;
@@ -17,10 +18,14 @@ use crate::{
     analyzer::Search, context::ContextNode, nodes::ConcreteNode, range::elem::RangeOp, ContextEdge,
     Edge, Field, FunctionParam, FunctionReturn, Node, NodeIdx, VarType,
 };
+use ethers_core::types::{I256, U256};
 
 use petgraph::visit::EdgeRef;
 use petgraph::Direction;
 use solang_parser::pt::{Loc, StorageLocation};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct ContextVarNode(pub usize);
@@ -28,25 +33,11 @@ impl AsDotStr for ContextVarNode {
     fn as_dot_str(&self, analyzer: &impl GraphLike) -> String {
         let underlying = self.underlying(analyzer);
 
-        let range_str = if let Some(r) = underlying.ty.range(analyzer) {
-            format!(
-                "[{}, {}]",
-                r.evaled_range_min(analyzer)
-                    .to_range_string(false, analyzer)
-                    .s,
-                r.evaled_range_max(analyzer)
-                    .to_range_string(true, analyzer)
-                    .s
-            )
-        } else {
-            "".to_string()
-        };
-
         format!(
-            "{} -- {} -- range: {}",
+            "{} -- {}",
             underlying.display_name,
-            underlying.ty.as_string(analyzer),
-            range_str
+            self.range_string(analyzer)
+                .unwrap_or_else(|| underlying.ty.as_string(analyzer))
         )
     }
 }
@@ -99,6 +90,29 @@ impl ContextVarNode {
         &self.underlying(analyzer).ty
     }
 
+    /// Gets the metadata of type `T` an external pass attached to the variable
+    pub fn metadata<'a, T: Any + Send + Sync>(
+        &self,
+        analyzer: &'a impl GraphLike,
+    ) -> Option<&'a T> {
+        self.underlying(analyzer).metadata.get::<T>()
+    }
+
+    /// Attaches metadata of type `T` to the variable, replacing any previous value of that type
+    pub fn set_metadata<T: Any + Send + Sync>(&self, analyzer: &mut impl GraphLike, val: T) {
+        self.underlying_mut(analyzer).metadata.insert(val);
+    }
+
+    /// The builtin type of the variable, or `None` for user types
+    pub fn builtin(&self, analyzer: &impl GraphLike) -> Option<Builtin> {
+        self.underlying(analyzer).ty.builtin(analyzer)
+    }
+
+    /// The user type of the variable, or `None` for builtin types
+    pub fn user_ty(&self, analyzer: &impl GraphLike) -> Option<TypeNode> {
+        self.underlying(analyzer).ty.user_ty()
+    }
+
     pub fn loc(&self, analyzer: &'_ impl GraphLike) -> Loc {
         self.underlying(analyzer)
             .loc
@@ -138,18 +152,72 @@ impl ContextVarNode {
         }
     }
 
+    /// The internal name of the variable, unique within its context
     pub fn name(&self, analyzer: &'_ impl GraphLike) -> String {
         self.underlying(analyzer).name.clone()
     }
 
+    /// The user-facing name of the variable. The internal `tmp{n}` naming of temporaries is
+    /// stripped, falling back to the expression the temporary was constructed from, or its type
     pub fn display_name(&self, analyzer: &'_ impl GraphLike) -> String {
-        self.underlying(analyzer).display_name.clone()
+        let underlying = self.underlying(analyzer);
+        let stripped = strip_tmp_prefix(&underlying.display_name);
+        if !stripped.is_empty() {
+            return stripped.to_string();
+        }
+
+        match underlying.tmp_of {
+            Some(TmpConstruction {
+                lhs,
+                op,
+                rhs: Some(rhs),
+            }) => format!(
+                "({} {} {})",
+                lhs.display_name(analyzer),
+                op.to_string(),
+                rhs.display_name(analyzer)
+            ),
+            Some(TmpConstruction { lhs, op, rhs: None }) => {
+                format!("{}{}", op.to_string(), lhs.display_name(analyzer))
+            }
+            None => underlying.ty.as_string(analyzer),
+        }
     }
 
     pub fn range(&self, analyzer: &'_ impl GraphLike) -> Option<SolcRange> {
         self.underlying(analyzer).ty.range(analyzer)
     }
 
+    /// Formats the type and range of the variable in a human readable way, i.e. `uint256[0, 100]`
+    /// or `int8[-5, 5]`. Bounds that depend on an array length are kept symbolic, i.e.
+    /// `uint256[0, len(arr) - 1]`. Returns `None` if the variable has no range
+    pub fn range_string(&self, analyzer: &'_ impl GraphLike) -> Option<String> {
+        let range = self.range(analyzer)?;
+        let depends_on_len = |elem: &Elem<Concrete>| {
+            elem.dependent_on()
+                .iter()
+                .any(|dep| dep.is_len_var(analyzer))
+        };
+
+        let min = if depends_on_len(&range.range_min()) {
+            range.range_min()
+        } else {
+            range.evaled_range_min(analyzer)
+        };
+        let max = if depends_on_len(&range.range_max()) {
+            range.range_max()
+        } else {
+            range.evaled_range_max(analyzer)
+        };
+
+        Some(format!(
+            "{}[{}, {}]",
+            self.ty(analyzer).as_string(analyzer),
+            min.to_decimal_string(false, analyzer),
+            max.to_decimal_string(true, analyzer)
+        ))
+    }
+
     pub fn range_min(&self, analyzer: &'_ impl GraphLike) -> Option<Elem<Concrete>> {
         Some(self.range(analyzer)?.range_min())
     }
@@ -166,6 +234,42 @@ impl ContextVarNode {
         Some(self.range(analyzer)?.evaled_range_max(analyzer))
     }
 
+    /// The evaluated minimum of the variable as a `U256`. Returns `None` if the minimum doesn't
+    /// resolve to a concrete value or is negative
+    pub fn min_u256(&self, analyzer: &'_ impl GraphLike) -> Option<U256> {
+        self.evaled_range_min(analyzer)?
+            .maybe_concrete()?
+            .val
+            .into_u256()
+    }
+
+    /// The evaluated maximum of the variable as a `U256`. Returns `None` if the maximum doesn't
+    /// resolve to a concrete value or is negative
+    pub fn max_u256(&self, analyzer: &'_ impl GraphLike) -> Option<U256> {
+        self.evaled_range_max(analyzer)?
+            .maybe_concrete()?
+            .val
+            .into_u256()
+    }
+
+    /// The evaluated minimum of the variable as an `I256`. Returns `None` if the minimum doesn't
+    /// resolve to a concrete integer or doesn't fit
+    pub fn min_i256(&self, analyzer: &'_ impl GraphLike) -> Option<I256> {
+        self.evaled_range_min(analyzer)?
+            .maybe_concrete()?
+            .val
+            .into_i256()
+    }
+
+    /// The evaluated maximum of the variable as an `I256`. Returns `None` if the maximum doesn't
+    /// resolve to a concrete integer or doesn't fit
+    pub fn max_i256(&self, analyzer: &'_ impl GraphLike) -> Option<I256> {
+        self.evaled_range_max(analyzer)?
+            .maybe_concrete()?
+            .val
+            .into_i256()
+    }
+
     pub fn is_const(&self, analyzer: &impl GraphLike) -> bool {
         let underlying = self.underlying(analyzer);
         underlying.storage.is_none() && underlying.ty.is_const(analyzer)
@@ -569,6 +673,33 @@ impl ContextVarNode {
     pub fn is_int(&self, analyzer: &impl GraphLike) -> bool {
         self.underlying(analyzer).ty.is_int(analyzer)
     }
+
+    pub fn is_bool(&self, analyzer: &impl GraphLike) -> bool {
+        self.underlying(analyzer).ty.is_bool(analyzer)
+    }
+
+    pub fn is_address(&self, analyzer: &impl GraphLike) -> bool {
+        self.underlying(analyzer).ty.is_address(analyzer)
+    }
+
+    /// Whether the variable is a `fixed` or `ufixed` number, whose range is only approximate
+    pub fn is_fixed_point(&self, analyzer: &impl GraphLike) -> bool {
+        self.builtin(analyzer)
+            .map(|builtin| builtin.is_fixed())
+            .unwrap_or(false)
+    }
+
+    /// Whether the variable is the contract's own balance, i.e. `address(this).balance`
+    pub fn is_self_balance(&self, analyzer: &impl GraphLike) -> bool {
+        self.underlying(analyzer).name == "this.balance"
+    }
+
+    /// Whether the variable is, or is indexed by, the caller of the function, i.e. `msg.sender`
+    /// or `tx.origin`
+    pub fn involves_sender(&self, analyzer: &impl GraphLike) -> bool {
+        let name = &self.underlying(analyzer).name;
+        name.contains("msg.sender") || name.contains("tx.origin")
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -581,6 +712,65 @@ pub struct ContextVar {
     pub tmp_of: Option<TmpConstruction>,
     pub is_symbolic: bool,
     pub ty: VarType,
+    pub metadata: VarMetadata,
+}
+
+/// Facts attached to a variable by external passes (i.e. taint or origin), at most one per type.
+/// Metadata is carried over to new versions of the variable but is ignored when comparing
+/// variables
+#[derive(Default, Clone)]
+pub struct VarMetadata(HashMap<TypeId, Arc<dyn Any + Send + Sync>>);
+
+impl VarMetadata {
+    /// Gets the fact of type `T`, if one was set
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.0
+            .get(&TypeId::of::<T>())
+            .and_then(|val| val.downcast_ref::<T>())
+    }
+
+    /// Sets the fact of type `T`, replacing any previous one
+    pub fn insert<T: Any + Send + Sync>(&mut self, val: T) {
+        self.0.insert(TypeId::of::<T>(), Arc::new(val));
+    }
+
+    /// Removes the fact of type `T`, returning whether there was one
+    pub fn remove<T: Any + Send + Sync>(&mut self) -> bool {
+        self.0.remove(&TypeId::of::<T>()).is_some()
+    }
+}
+
+impl std::fmt::Debug for VarMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VarMetadata({} entries)", self.0.len())
+    }
+}
+
+impl PartialEq for VarMetadata {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for VarMetadata {}
+
+/// Metadata marking a variable as derived from input an attacker may control, i.e. a parameter of
+/// an external function, `msg.data`, or the returndata of an external call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Taint {
+    /// The display name of the untrusted input the variable derives from
+    pub source: String,
+}
+
+/// Strips the internal `tmp{n}` prefix of a temporary's name, i.e. `tmp3(a + b)` becomes
+/// `(a + b)` and `tmp3` becomes an empty string
+fn strip_tmp_prefix(name: &str) -> &str {
+    match name.strip_prefix("tmp") {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => {
+            rest.trim_start_matches(|c: char| c.is_ascii_digit())
+        }
+        _ => name,
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -619,6 +809,7 @@ impl ContextVar {
             tmp_of: None,
             is_symbolic: false,
             ty: VarType::Concrete(concrete_node),
+            metadata: Default::default(),
         }
     }
 
@@ -674,6 +865,7 @@ impl ContextVar {
             tmp_of: None,
             is_symbolic: true,
             ty: VarType::User(TypeNode::Contract(contract_node)),
+            metadata: Default::default(),
         }
     }
 
@@ -687,6 +879,7 @@ impl ContextVar {
             tmp_of: None,
             is_symbolic: false,
             ty: VarType::try_from_idx(analyzer, bn_node.into()).unwrap(),
+            metadata: Default::default(),
         }
     }
 
@@ -877,6 +1070,7 @@ impl ContextVar {
                 tmp_of: None,
                 is_symbolic: true,
                 ty,
+                metadata: Default::default(),
             })
         } else {
             None
@@ -903,6 +1097,7 @@ impl ContextVar {
                 tmp_of: None,
                 is_symbolic: true,
                 ty,
+                metadata: Default::default(),
             })
         } else {
             None
@@ -927,6 +1122,7 @@ impl ContextVar {
             tmp_of: None,
             is_symbolic: index.underlying(analyzer).is_symbolic,
             ty: parent_var.array_underlying_ty(analyzer),
+            metadata: Default::default(),
         }
     }
 
@@ -940,6 +1136,7 @@ impl ContextVar {
             tmp_of: None,
             is_symbolic: false,
             ty: VarType::User(TypeNode::Func(func)),
+            metadata: Default::default(),
         }
     }
 
@@ -958,6 +1155,7 @@ impl ContextVar {
                     tmp_of: None,
                     is_symbolic: true,
                     ty,
+                    metadata: Default::default(),
                 })
             } else {
                 None
@@ -979,6 +1177,7 @@ impl ContextVar {
                     tmp_of: None,
                     is_symbolic: true,
                     ty,
+                    metadata: Default::default(),
                 })
             } else {
                 None
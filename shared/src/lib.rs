@@ -40,6 +40,8 @@ pub enum Node {
     Enum(Enum),
     Error(Error),
     ErrorParam(ErrorParam),
+    Event(Event),
+    EventParam(EventParam),
     Field(Field),
     Var(Var),
     Ty(Ty),
@@ -49,6 +51,36 @@ pub enum Node {
     Block(Block),
 }
 
+/// The kind of a [`Node`], without any of its data
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum NodeKind {
+    Context,
+    ContextVar,
+    ContextFork,
+    FunctionCall,
+    Builtin,
+    VarType,
+    SourceUnit,
+    SourceUnitPart,
+    Contract,
+    Function,
+    FunctionParam,
+    FunctionReturn,
+    Struct,
+    Enum,
+    Error,
+    ErrorParam,
+    Event,
+    EventParam,
+    Field,
+    Var,
+    Ty,
+    Unresolved,
+    Concrete,
+    Msg,
+    Block,
+}
+
 pub fn as_dot_str(idx: NodeIdx, analyzer: &impl GraphLike) -> String {
     use crate::Node::*;
     match analyzer.node(idx) {
@@ -73,6 +105,37 @@ pub fn as_dot_str(idx: NodeIdx, analyzer: &impl GraphLike) -> String {
 }
 
 impl Node {
+    /// Gets the kind of the node
+    pub fn kind(&self) -> NodeKind {
+        match self {
+            Node::Context(_) => NodeKind::Context,
+            Node::ContextVar(_) => NodeKind::ContextVar,
+            Node::ContextFork => NodeKind::ContextFork,
+            Node::FunctionCall => NodeKind::FunctionCall,
+            Node::Builtin(_) => NodeKind::Builtin,
+            Node::VarType(_) => NodeKind::VarType,
+            Node::SourceUnit(_) => NodeKind::SourceUnit,
+            Node::SourceUnitPart(..) => NodeKind::SourceUnitPart,
+            Node::Contract(_) => NodeKind::Contract,
+            Node::Function(_) => NodeKind::Function,
+            Node::FunctionParam(_) => NodeKind::FunctionParam,
+            Node::FunctionReturn(_) => NodeKind::FunctionReturn,
+            Node::Struct(_) => NodeKind::Struct,
+            Node::Enum(_) => NodeKind::Enum,
+            Node::Error(_) => NodeKind::Error,
+            Node::ErrorParam(_) => NodeKind::ErrorParam,
+            Node::Event(_) => NodeKind::Event,
+            Node::EventParam(_) => NodeKind::EventParam,
+            Node::Field(_) => NodeKind::Field,
+            Node::Var(_) => NodeKind::Var,
+            Node::Ty(_) => NodeKind::Ty,
+            Node::Unresolved(_) => NodeKind::Unresolved,
+            Node::Concrete(_) => NodeKind::Concrete,
+            Node::Msg(_) => NodeKind::Msg,
+            Node::Block(_) => NodeKind::Block,
+        }
+    }
+
     pub fn dot_str_color(&self) -> String {
         use crate::Node::*;
         let c = match self {
@@ -120,6 +183,7 @@ pub enum Edge {
     Error,
     ErrorParam,
     Event,
+    EventParam,
     Var,
     Ty,
     Func,
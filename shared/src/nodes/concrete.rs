@@ -275,15 +275,8 @@ impl Concrete {
                         U256::from(2).pow(size.into()) - 1
                     };
 
-                    let (_sign, abs) = val.into_sign_and_abs();
-
-                    if val.signum() <= 0.into() {
-                        Some(Concrete::Uint(size, U256::zero()))
-                    } else if abs < mask {
-                        Some(Concrete::Uint(size, abs))
-                    } else {
-                        Some(Concrete::Uint(size, mask))
-                    }
+                    // reinterpret the two's complement bits, i.e. `uint8(int8(-1)) == 255`
+                    Some(Concrete::Uint(size, val.into_raw() & mask))
                 }
                 Builtin::Int(size) => {
                     let mask = if size == 256 {
@@ -350,7 +343,7 @@ impl Concrete {
                 _ => None,
             },
             Concrete::Address(a) => match builtin {
-                Builtin::Address => Some(self),
+                Builtin::Address | Builtin::AddressPayable | Builtin::Payable => Some(self),
                 Builtin::Uint(size) => {
                     let mask = if size == 256 {
                         U256::MAX
@@ -406,6 +399,21 @@ impl Concrete {
         }
     }
 
+    /// The value of a decimal number literal, `integer * 10**exponent`, e.g. `1e18`. Underscore
+    /// separators are ignored. Computed in 256 bits without truncation, returning `None` if the
+    /// literal doesn't fit or has a negative exponent
+    pub fn dec_literal_val(integer: &str, exponent: &str) -> Option<U256> {
+        let int = U256::from_dec_str(&integer.replace('_', "")).ok()?;
+        if exponent.is_empty() {
+            return Some(int);
+        }
+        let exp = U256::from_dec_str(&exponent.replace('_', "")).ok()?;
+        if int.is_zero() {
+            return Some(int);
+        }
+        int.checked_mul(U256::from(10).checked_pow(exp)?)
+    }
+
     /// Converts a concrete into a `U256`.
     pub fn into_u256(&self) -> Option<U256> {
         match self {
@@ -430,6 +438,21 @@ impl Concrete {
         }
     }
 
+    /// Converts an integer concrete into an `I256`, if it fits
+    pub fn into_i256(&self) -> Option<I256> {
+        match self {
+            Concrete::Int(_, val) => Some(*val),
+            Concrete::Uint(_, val) => {
+                if *val <= I256::MAX.into_raw() {
+                    Some(I256::from_raw(*val))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
     pub fn max_size(&self) -> Self {
         match self {
             Concrete::Uint(_, val) => Concrete::Uint(256, *val),
@@ -559,7 +582,7 @@ impl Concrete {
                     I256::from_raw(U256::from(1u8) << U256::from(*size - 1)) - I256::from(1)
                 };
 
-                let min = max * I256::from(-1i32);
+                let min = I256::from(-1i32) - max;
                 Some(Concrete::Int(*size, min))
             }
             Concrete::Bytes(size, _) => {
@@ -602,6 +625,29 @@ impl Concrete {
         }
     }
 
+    /// Encodes the value as `abi.encodePacked` would, i.e. integers in as many bytes as their
+    /// size and dynamically sized values without their length. Arrays are not supported
+    pub fn packed_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            Concrete::Uint(size, val) => {
+                let mut bytes = [0u8; 32];
+                val.to_big_endian(&mut bytes);
+                Some(bytes[32 - *size as usize / 8..].to_vec())
+            }
+            Concrete::Int(size, val) => {
+                let mut bytes = [0u8; 32];
+                val.into_raw().to_big_endian(&mut bytes);
+                Some(bytes[32 - *size as usize / 8..].to_vec())
+            }
+            Concrete::Bytes(size, b) => Some(b.as_bytes()[..*size as usize].to_vec()),
+            Concrete::Address(a) => Some(a.as_bytes().to_vec()),
+            Concrete::Bool(b) => Some(vec![*b as u8]),
+            Concrete::DynBytes(bytes) => Some(bytes.clone()),
+            Concrete::String(s) => Some(s.as_bytes().to_vec()),
+            Concrete::Array(_) => None,
+        }
+    }
+
     /// Converts to a string
     pub fn as_string(&self) -> String {
         match self {
@@ -1,11 +1,13 @@
 use crate::analyzer::Search;
 use crate::analyzer::{AnalyzerLike, GraphLike};
+use crate::nodes::{EnumNode, ErrorNode, EventNode, StructNode};
 use crate::AsDotStr;
 use crate::Edge;
 use crate::FunctionNode;
 use crate::Node;
 use crate::NodeIdx;
-use solang_parser::pt::{ContractDefinition, ContractTy, Identifier, Loc};
+use petgraph::{visit::EdgeRef, Direction};
+use solang_parser::pt::{ContractDefinition, ContractTy, Expression, Identifier, Loc};
 
 /// An index in the graph that references a [`Contract`] node
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
@@ -53,6 +55,11 @@ impl ContractNode {
         Some(ident.name)
     }
 
+    /// Checks if the [`Contract`] is a library
+    pub fn is_library(&self, analyzer: &'_ impl GraphLike) -> bool {
+        matches!(self.underlying(analyzer).ty, ContractTy::Library(_))
+    }
+
     /// Gets the sourcecode location from the underlying node data for the [`Contract`]
     pub fn loc(&self, analyzer: &'_ impl GraphLike) -> Loc {
         self.underlying(analyzer).loc
@@ -67,6 +74,64 @@ impl ContractNode {
             .collect()
     }
 
+    /// Gets the events declared in the contract and in the contracts it inherits
+    pub fn events(&self, analyzer: &'_ (impl GraphLike + Search)) -> Vec<EventNode> {
+        analyzer
+            .search_children(self.0.into(), &Edge::Event)
+            .into_iter()
+            .map(EventNode::from)
+            .collect()
+    }
+
+    /// Gets the errors declared in the contract and in the contracts it inherits
+    pub fn errors(&self, analyzer: &'_ (impl GraphLike + Search)) -> Vec<ErrorNode> {
+        analyzer
+            .search_children(self.0.into(), &Edge::Error)
+            .into_iter()
+            .map(ErrorNode::from)
+            .collect()
+    }
+
+    /// Gets the constructor defined in the contract, if it has one
+    pub fn constructor(&self, analyzer: &'_ impl GraphLike) -> Option<FunctionNode> {
+        analyzer
+            .graph()
+            .edges_directed(self.0.into(), Direction::Incoming)
+            .find(|edge| *edge.weight() == Edge::Constructor)
+            .map(|edge| FunctionNode::from(edge.source()))
+    }
+
+    /// Gets the base constructors called via the inheritance list (i.e. `contract A is B(1)`)
+    /// alongside the argument expressions passed to them
+    pub fn base_constructor_calls(
+        &self,
+        analyzer: &'_ (impl GraphLike + Search),
+    ) -> Vec<(FunctionNode, Vec<Expression>)> {
+        self.underlying(analyzer)
+            .base_args
+            .iter()
+            .filter_map(|(base, args)| Some((base.constructor(analyzer)?, args.clone())))
+            .collect()
+    }
+
+    /// Gets a struct or enum defined in the contract by name
+    pub fn user_type_by_name(
+        &self,
+        analyzer: &'_ (impl GraphLike + Search),
+        name: &str,
+    ) -> Option<NodeIdx> {
+        analyzer
+            .search_children(self.0.into(), &Edge::Struct)
+            .into_iter()
+            .find(|node| StructNode::from(*node).name(analyzer) == name)
+            .or_else(|| {
+                analyzer
+                    .search_children(self.0.into(), &Edge::Enum)
+                    .into_iter()
+                    .find(|node| EnumNode::from(*node).name(analyzer) == name)
+            })
+    }
+
     pub fn associated_source_unit_part(&self, analyzer: &impl GraphLike) -> NodeIdx {
         analyzer
             .search_for_ancestor(self.0.into(), &Edge::Contract)
@@ -104,6 +169,8 @@ pub struct Contract {
     pub name: Option<Identifier>,
     /// A list of contracts that this contract inherits (TODO: inheritance linearization)
     pub inherits: Vec<ContractNode>,
+    /// Constructor arguments passed to inherited contracts in the inheritance list
+    pub base_args: Vec<(ContractNode, Vec<Expression>)>,
 }
 
 impl From<Contract> for Node {
@@ -120,24 +187,42 @@ impl Contract {
         analyzer: &'_ impl AnalyzerLike,
     ) -> Contract {
         let mut inherits = vec![];
+        let mut base_args = vec![];
         con.base.iter().for_each(|base| {
             let inherited_name = &base.name.identifiers[0].name;
+            let mut found = None;
             for entry in imports.iter().filter_map(|import| import.0) {
                 println!("{:?}", entry);
                 for contract in analyzer.search_children(entry, &Edge::Contract).into_iter() {
                     let name = ContractNode::from(contract).name(analyzer);
                     if &name == inherited_name {
                         inherits.push(ContractNode::from(contract));
+                        found = Some(ContractNode::from(contract));
                         break;
                     }
                 }
             }
+
+            // fall back to contracts already defined in the same file
+            if found.is_none() {
+                if let Some(idx) = analyzer.user_types().get(inherited_name) {
+                    if let Node::Contract(_) = analyzer.node(*idx) {
+                        inherits.push(ContractNode::from(*idx));
+                        found = Some(ContractNode::from(*idx));
+                    }
+                }
+            }
+
+            if let (Some(contract), Some(args)) = (found, &base.args) {
+                base_args.push((contract, args.clone()));
+            }
         });
         Contract {
             loc: con.loc,
             ty: con.ty,
             name: con.name,
             inherits,
+            base_args,
         }
     }
 }
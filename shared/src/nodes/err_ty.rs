@@ -1,6 +1,8 @@
 use crate::AnalyzerLike;
 use crate::AsDotStr;
-use crate::{analyzer::GraphLike, Node, NodeIdx};
+use crate::{analyzer::GraphLike, Edge, Node, NodeIdx, VarType};
+use ethers_core::utils::keccak256;
+use petgraph::{visit::EdgeRef, Direction};
 use solang_parser::pt::{ErrorDefinition, ErrorParameter, Expression, Identifier, Loc};
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
@@ -15,6 +17,51 @@ impl ErrorNode {
             ),
         }
     }
+
+    /// Gets the name of the error
+    pub fn name(&self, analyzer: &impl GraphLike) -> String {
+        self.underlying(analyzer)
+            .name
+            .as_ref()
+            .expect("Error was not named")
+            .name
+            .clone()
+    }
+
+    /// Gets the parameters of the error, in declaration order
+    pub fn params(&self, analyzer: &impl GraphLike) -> Vec<ErrorParamNode> {
+        let mut params: Vec<_> = analyzer
+            .graph()
+            .edges_directed(self.0.into(), Direction::Incoming)
+            .filter(|edge| *edge.weight() == Edge::ErrorParam)
+            .map(|edge| ErrorParamNode::from(edge.source()))
+            .collect();
+        params.sort();
+        params
+    }
+
+    /// The signature of the error, i.e. `InsufficientBalance(uint256,uint256)`
+    pub fn signature(&self, analyzer: &impl GraphLike) -> String {
+        let params = self
+            .params(analyzer)
+            .iter()
+            .map(|param| {
+                let ty = param.underlying(analyzer).ty;
+                match VarType::try_from_idx(analyzer, ty) {
+                    Some(var_ty) => var_ty.as_string(analyzer),
+                    None => "<unknown>".to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        format!("{}({})", self.name(analyzer), params)
+    }
+
+    /// The 4 byte selector of the error
+    pub fn selector(&self, analyzer: &impl GraphLike) -> [u8; 4] {
+        let hash = keccak256(self.signature(analyzer).as_bytes());
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
 }
 impl AsDotStr for ErrorNode {
     fn as_dot_str(&self, analyzer: &impl GraphLike) -> String {
@@ -66,6 +113,18 @@ impl From<ErrorDefinition> for Error {
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct ErrorParamNode(pub usize);
 
+impl ErrorParamNode {
+    pub fn underlying<'a>(&self, analyzer: &'a impl GraphLike) -> &'a ErrorParam {
+        match analyzer.node(*self) {
+            Node::ErrorParam(param) => param,
+            e => panic!(
+                "Node type confusion: expected node to be ErrorParam but it was: {:?}",
+                e
+            ),
+        }
+    }
+}
+
 impl From<NodeIdx> for ErrorParamNode {
     fn from(idx: NodeIdx) -> Self {
         ErrorParamNode(idx.index())
@@ -0,0 +1,143 @@
+use crate::AnalyzerLike;
+use crate::AsDotStr;
+use crate::{analyzer::GraphLike, Edge, Node, NodeIdx};
+use petgraph::{visit::EdgeRef, Direction};
+use solang_parser::pt::{EventDefinition, EventParameter, Expression, Identifier, Loc};
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct EventNode(pub usize);
+impl EventNode {
+    pub fn underlying<'a>(&self, analyzer: &'a impl GraphLike) -> &'a Event {
+        match analyzer.node(*self) {
+            Node::Event(event) => event,
+            e => panic!(
+                "Node type confusion: expected node to be Event but it was: {:?}",
+                e
+            ),
+        }
+    }
+
+    /// Gets the name of the event
+    pub fn name(&self, analyzer: &impl GraphLike) -> String {
+        self.underlying(analyzer)
+            .name
+            .as_ref()
+            .expect("Event was not named")
+            .name
+            .clone()
+    }
+
+    /// Gets the parameters of the event, in declaration order
+    pub fn params(&self, analyzer: &impl GraphLike) -> Vec<EventParamNode> {
+        let mut params: Vec<_> = analyzer
+            .graph()
+            .edges_directed(self.0.into(), Direction::Incoming)
+            .filter(|edge| *edge.weight() == Edge::EventParam)
+            .map(|edge| EventParamNode::from(edge.source()))
+            .collect();
+        params.sort();
+        params
+    }
+}
+
+impl AsDotStr for EventNode {
+    fn as_dot_str(&self, analyzer: &impl GraphLike) -> String {
+        let underlying = self.underlying(analyzer);
+        format!(
+            "event {}",
+            if let Some(name) = &underlying.name {
+                name.name.clone()
+            } else {
+                "".to_string()
+            },
+        )
+    }
+}
+
+impl From<EventNode> for NodeIdx {
+    fn from(val: EventNode) -> Self {
+        val.0.into()
+    }
+}
+
+impl From<NodeIdx> for EventNode {
+    fn from(idx: NodeIdx) -> Self {
+        EventNode(idx.index())
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Event {
+    pub loc: Loc,
+    pub name: Option<Identifier>,
+    pub anonymous: bool,
+}
+
+impl From<Event> for Node {
+    fn from(val: Event) -> Self {
+        Node::Event(val)
+    }
+}
+
+impl From<EventDefinition> for Event {
+    fn from(event: EventDefinition) -> Event {
+        Event {
+            loc: event.loc,
+            name: event.name,
+            anonymous: event.anonymous,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct EventParamNode(pub usize);
+
+impl EventParamNode {
+    pub fn underlying<'a>(&self, analyzer: &'a impl GraphLike) -> &'a EventParam {
+        match analyzer.node(*self) {
+            Node::EventParam(param) => param,
+            e => panic!(
+                "Node type confusion: expected node to be EventParam but it was: {:?}",
+                e
+            ),
+        }
+    }
+}
+
+impl From<NodeIdx> for EventParamNode {
+    fn from(idx: NodeIdx) -> Self {
+        EventParamNode(idx.index())
+    }
+}
+
+impl From<EventParamNode> for NodeIdx {
+    fn from(val: EventParamNode) -> Self {
+        val.0.into()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EventParam {
+    pub loc: Loc,
+    pub ty: NodeIdx,
+    pub name: Option<Identifier>,
+    /// Whether the parameter is a topic of the event log
+    pub indexed: bool,
+}
+
+impl From<EventParam> for Node {
+    fn from(val: EventParam) -> Self {
+        Node::EventParam(val)
+    }
+}
+
+impl EventParam {
+    pub fn new(analyzer: &mut impl AnalyzerLike<Expr = Expression>, param: EventParameter) -> Self {
+        EventParam {
+            loc: param.loc,
+            ty: analyzer.parse_expr(&param.ty),
+            name: param.name,
+            indexed: param.indexed,
+        }
+    }
+}
@@ -15,7 +15,7 @@ use solang_parser::pt::Statement;
 use solang_parser::pt::VariableDefinition;
 use solang_parser::pt::{
     Base, Expression, FunctionAttribute, FunctionDefinition, FunctionTy, Identifier, Loc,
-    Parameter, StorageLocation, Visibility,
+    Mutability, Parameter, StorageLocation, Type, Visibility,
 };
 use std::collections::BTreeMap;
 
@@ -119,10 +119,23 @@ impl FunctionNode {
             .next()
     }
 
+    /// Gets the contract or source unit part the function is defined in. Constructors and the
+    /// `fallback` and `receive` functions are attached to their contract by their own edges
+    fn defined_in(&self, analyzer: &impl GraphLike) -> Option<NodeIdx> {
+        analyzer
+            .graph()
+            .edges_directed(self.0.into(), Direction::Outgoing)
+            .find(|edge| {
+                matches!(
+                    edge.weight(),
+                    Edge::Func | Edge::Constructor | Edge::FallbackFunc | Edge::ReceiveFunc
+                )
+            })
+            .map(|edge| edge.target())
+    }
+
     pub fn maybe_associated_contract(&self, analyzer: &impl GraphLike) -> Option<ContractNode> {
-        let parent = analyzer
-            .search_for_ancestor(self.0.into(), &Edge::Func)
-            .expect("detached function");
+        let parent = self.defined_in(analyzer).expect("detached function");
         match analyzer.node(parent) {
             Node::Contract(_) => Some(parent.into()),
             _ => None,
@@ -130,9 +143,7 @@ impl FunctionNode {
     }
 
     pub fn associated_source_unit_part(&self, analyzer: &impl GraphLike) -> NodeIdx {
-        let parent = analyzer
-            .search_for_ancestor(self.0.into(), &Edge::Func)
-            .expect("detached function");
+        let parent = self.defined_in(analyzer).expect("detached function");
         match analyzer.node(parent) {
             Node::Contract(_) => ContractNode::from(parent).associated_source_unit_part(analyzer),
             Node::SourceUnitPart(..) => parent,
@@ -203,15 +214,9 @@ impl FunctionNode {
     }
 
     pub fn contract(&self, analyzer: &'_ impl GraphLike) -> Option<ContractNode> {
-        analyzer
-            .graph()
-            .edges_directed(self.0.into(), Direction::Outgoing)
-            .filter(|edge| Edge::Func == *edge.weight())
-            .map(|edge| edge.target())
+        self.defined_in(analyzer)
             .filter(|node| matches!(analyzer.node(*node), Node::Contract(_)))
             .map(ContractNode::from)
-            .take(1)
-            .next()
     }
 
     pub fn is_public_or_ext(&self, analyzer: &'_ impl GraphLike) -> bool {
@@ -223,6 +228,25 @@ impl FunctionNode {
             )
         })
     }
+
+    /// Gets the state mutability of the function, if one was specified (i.e. `view`, `pure`, `payable`)
+    pub fn mutability(&self, analyzer: &'_ impl GraphLike) -> Option<Mutability> {
+        self.underlying(analyzer)
+            .attributes
+            .iter()
+            .find_map(|attr| match attr {
+                FunctionAttribute::Mutability(m) => Some(m.clone()),
+                _ => None,
+            })
+    }
+
+    /// Whether the function is `view`, `pure` or `constant`, i.e. it promises not to modify state
+    pub fn is_view_or_pure(&self, analyzer: &'_ impl GraphLike) -> bool {
+        matches!(
+            self.mutability(analyzer),
+            Some(Mutability::View(_)) | Some(Mutability::Pure(_)) | Some(Mutability::Constant(_))
+        )
+    }
 }
 
 impl AsDotStr for FunctionNode {
@@ -334,31 +358,66 @@ impl From<FunctionDefinition> for Function {
 }
 
 impl From<VariableDefinition> for Function {
+    /// Synthesizes the getter of a public state variable. Arrays and mappings are unwrapped
+    /// into an index or key parameter per level, i.e. `mapping(address => uint256[]) public x`
+    /// becomes `function x(address _0, uint256 _1) public view returns (uint256)`
     fn from(var: VariableDefinition) -> Function {
+        let name = var.name.expect("unnamed public variable?");
+        let mut params = vec![];
+        let mut ret_ty = var.ty;
+        let mut accessed = Expression::Variable(name.clone());
+        loop {
+            let (key_ty, inner_ty) = match &ret_ty {
+                Expression::ArraySubscript(_, inner, _) => {
+                    (Expression::Type(var.loc, Type::Uint(256)), *inner.clone())
+                }
+                Expression::Type(_, Type::Mapping { key, value, .. }) => {
+                    (*key.clone(), *value.clone())
+                }
+                _ => break,
+            };
+            ret_ty = inner_ty;
+
+            let key_name = Identifier {
+                loc: var.loc,
+                name: format!("_{}", params.len()),
+            };
+            accessed = Expression::ArraySubscript(
+                var.loc,
+                Box::new(accessed),
+                Some(Box::new(Expression::Variable(key_name.clone()))),
+            );
+            params.push((
+                var.loc,
+                Some(Parameter {
+                    loc: var.loc,
+                    ty: key_ty,
+                    storage: None,
+                    name: Some(key_name),
+                }),
+            ));
+        }
+
         Function {
             loc: var.loc,
             ty: FunctionTy::Function,
-            name: var.name.clone(),
+            name: Some(name),
             name_loc: var.loc,
-            attributes: vec![FunctionAttribute::Visibility(Visibility::Public(Some(
-                var.loc,
-            )))],
+            attributes: vec![
+                FunctionAttribute::Visibility(Visibility::Public(Some(var.loc))),
+                FunctionAttribute::Mutability(Mutability::View(var.loc)),
+            ],
             body: Some(Statement::Block {
                 loc: var.loc,
                 unchecked: false,
-                statements: vec![Statement::Return(
-                    var.loc,
-                    Some(Expression::Variable(
-                        var.name.expect("unnamed public variable?"),
-                    )),
-                )],
+                statements: vec![Statement::Return(var.loc, Some(accessed))],
             }),
-            params: vec![],
+            params,
             returns: vec![(
                 var.loc,
                 Some(Parameter {
                     loc: var.loc,
-                    ty: var.ty,
+                    ty: ret_ty,
                     storage: None,
                     name: None,
                 }),
@@ -8,6 +8,7 @@ use crate::range::SolcRange;
 use crate::GraphLike;
 use crate::Node;
 use crate::NodeIdx;
+use ethers_core::types::{Address, H256, I256, U256};
 use solang_parser::pt::Expression;
 use solang_parser::pt::Type;
 
@@ -21,6 +22,8 @@ mod func_ty;
 pub use func_ty::*;
 mod err_ty;
 pub use err_ty::*;
+mod event_ty;
+pub use event_ty::*;
 mod var_ty;
 pub use var_ty::*;
 mod ty_ty;
@@ -130,6 +133,8 @@ impl VarType {
             | Node::FunctionParam(..)
             | Node::FunctionReturn(..)
             | Node::ErrorParam(..)
+            | Node::Event(..)
+            | Node::EventParam(..)
             | Node::Field(..)
             | Node::SourceUnitPart(..)
             | Node::SourceUnit(..)
@@ -314,6 +319,42 @@ impl VarType {
             _ => false,
         }
     }
+
+    pub fn is_bool(&self, analyzer: &impl GraphLike) -> bool {
+        match self {
+            VarType::BuiltIn(bn, _) => *bn.underlying(analyzer) == Builtin::Bool,
+            VarType::Concrete(c) => matches!(c.underlying(analyzer), Concrete::Bool(_)),
+            _ => false,
+        }
+    }
+
+    pub fn is_address(&self, analyzer: &impl GraphLike) -> bool {
+        match self {
+            VarType::BuiltIn(bn, _) => matches!(
+                bn.underlying(analyzer),
+                Builtin::Address | Builtin::AddressPayable | Builtin::Payable
+            ),
+            VarType::Concrete(c) => matches!(c.underlying(analyzer), Concrete::Address(_)),
+            _ => false,
+        }
+    }
+
+    /// The builtin type, if this is a builtin or concrete type
+    pub fn builtin(&self, analyzer: &impl GraphLike) -> Option<Builtin> {
+        match self {
+            VarType::BuiltIn(bn, _) => Some(bn.underlying(analyzer).clone()),
+            VarType::Concrete(c) => Some(c.underlying(analyzer).as_builtin()),
+            _ => None,
+        }
+    }
+
+    /// The user type node, if this is a contract, struct, enum or function type
+    pub fn user_ty(&self) -> Option<TypeNode> {
+        match self {
+            VarType::User(ty_node) => Some(*ty_node),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -422,6 +463,12 @@ pub enum Builtin {
     Bytes(u8),
     Rational,
     DynamicBytes,
+    /// A signed fixed point number with the given total bits and decimals, i.e. `fixed128x18`.
+    /// Fixed point math isn't analyzed, so its range is that of the underlying integer
+    Fixed(u16, u8),
+    /// An unsigned fixed point number with the given total bits and decimals, i.e. `ufixed128x18`.
+    /// Fixed point math isn't analyzed, so its range is that of the underlying integer
+    Ufixed(u16, u8),
     Array(VarType),
     Mapping(VarType, VarType),
     Func(Vec<VarType>, Vec<VarType>),
@@ -479,6 +526,58 @@ impl Builtin {
         }
     }
 
+    /// Parses a fixed point type name, i.e. `fixed`, `ufixed` or `ufixed128x18`. The parser
+    /// doesn't know these types, so they reach the analyzer as plain identifiers. Bare `fixed`
+    /// and `ufixed` are aliases for `fixed128x18` and `ufixed128x18`
+    pub fn try_from_fixed_name(name: &str) -> Option<Builtin> {
+        let (signed, dims) = if let Some(dims) = name.strip_prefix("ufixed") {
+            (false, dims)
+        } else if let Some(dims) = name.strip_prefix("fixed") {
+            (true, dims)
+        } else {
+            return None;
+        };
+
+        let (bits, decimals) = if dims.is_empty() {
+            (128, 18)
+        } else {
+            let (bits, decimals) = dims.split_once('x')?;
+            (bits.parse::<u16>().ok()?, decimals.parse::<u8>().ok()?)
+        };
+
+        if bits == 0 || bits > 256 || bits % 8 != 0 || decimals > 80 {
+            return None;
+        }
+
+        if signed {
+            Some(Builtin::Fixed(bits, decimals))
+        } else {
+            Some(Builtin::Ufixed(bits, decimals))
+        }
+    }
+
+    /// The value a variable of this type is reset to by `delete`, for value types
+    pub fn zero_value(&self) -> Option<Concrete> {
+        match self {
+            Builtin::Bool => Some(Concrete::Bool(false)),
+            Builtin::Address | Builtin::AddressPayable | Builtin::Payable => {
+                Some(Concrete::Address(Address::zero()))
+            }
+            Builtin::Uint(size) | Builtin::Ufixed(size, _) => {
+                Some(Concrete::Uint(*size, U256::zero()))
+            }
+            Builtin::Int(size) | Builtin::Fixed(size, _) => {
+                Some(Concrete::Int(*size, I256::zero()))
+            }
+            Builtin::Bytes(size) => Some(Concrete::Bytes(*size, H256::zero())),
+            _ => None,
+        }
+    }
+
+    pub fn is_fixed(&self) -> bool {
+        matches!(self, Builtin::Fixed(..) | Builtin::Ufixed(..))
+    }
+
     pub fn is_dyn(&self) -> bool {
         matches!(
             self,
@@ -486,6 +585,20 @@ impl Builtin {
         )
     }
 
+    /// Whether the builtin is a value type: a number, `bool`, an address or fixed size bytes
+    pub fn is_value(&self) -> bool {
+        matches!(
+            self,
+            Builtin::Address
+                | Builtin::AddressPayable
+                | Builtin::Payable
+                | Builtin::Bool
+                | Builtin::Uint(_)
+                | Builtin::Int(_)
+                | Builtin::Bytes(_)
+        )
+    }
+
     pub fn num_size(&self) -> Option<u16> {
         match self {
             Builtin::Uint(size) => Some(*size),
@@ -511,6 +624,10 @@ impl Builtin {
             (Uint(from_size), Uint(to_size)) => from_size <= to_size,
             (Int(from_size), Int(to_size)) => from_size <= to_size,
             (Bytes(from_size), Bytes(to_size)) => from_size <= to_size,
+            (Fixed(from_size, from_dec), Fixed(to_size, to_dec))
+            | (Ufixed(from_size, from_dec), Ufixed(to_size, to_dec)) => {
+                from_size <= to_size && from_dec == to_dec
+            }
             _ => false,
         }
     }
@@ -538,6 +655,8 @@ impl Builtin {
             Bytes(size) => format!("bytes{}", size),
             Rational => "rational".to_string(),
             DynamicBytes => "bytes".to_string(),
+            Fixed(size, decimals) => format!("fixed{}x{}", size, decimals),
+            Ufixed(size, decimals) => format!("ufixed{}x{}", size, decimals),
             Array(v_ty) => format!("{}[]", v_ty.as_string(analyzer)),
             Mapping(key_ty, v_ty) => format!(
                 "mapping ({} => {})",
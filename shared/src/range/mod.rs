@@ -178,7 +178,7 @@ impl SolcRange {
                 } else {
                     let max: I256 =
                         I256::from_raw(U256::from(1u8) << U256::from(size - 1)) - 1.into();
-                    let min = max * I256::from(-1i32);
+                    let min = I256::from(-1i32) - max;
                     Some(SolcRange {
                         min: Elem::Concrete(RangeConcrete {
                             val: Concrete::Int(*size, min),
@@ -203,7 +203,7 @@ impl SolcRange {
                 }),
                 exclusions: vec![],
             }),
-            Builtin::Address => Some(SolcRange {
+            Builtin::Address | Builtin::AddressPayable | Builtin::Payable => Some(SolcRange {
                 min: Elem::Concrete(RangeConcrete {
                     val: Concrete::Address(Address::from_slice(&[0x00; 20])),
                     loc: Loc::Implicit,
@@ -243,6 +243,8 @@ impl SolcRange {
                 })),
                 exclusions: vec![],
             }),
+            Builtin::Fixed(size, _) => Self::try_from_builtin(&Builtin::Int(*size)),
+            Builtin::Ufixed(size, _) => Self::try_from_builtin(&Builtin::Uint(*size)),
             _ => None,
         }
     }
@@ -326,27 +328,43 @@ impl SolcRange {
         }
     }
 
+    /// Addition is a shift of every interval in the set, so each exclusion
+    /// (the hole between two intervals) is shifted along with the bounds
     pub fn add_dyn(self, other: ContextVarNode, loc: Loc) -> Self {
         Self {
             min: self.min + Elem::Dynamic(Dynamic::new(other.into(), loc)),
             max: self.max + Elem::Dynamic(Dynamic::new(other.into(), loc)),
-            exclusions: self.exclusions,
+            exclusions: self
+                .exclusions
+                .into_iter()
+                .map(|excl| excl + Elem::Dynamic(Dynamic::new(other.into(), loc)))
+                .collect(),
         }
     }
 
+    /// Subtraction is a shift of every interval in the set, so each exclusion
+    /// is shifted along with the bounds
     pub fn sub_dyn(self, other: ContextVarNode, loc: Loc) -> Self {
         Self {
             min: self.min - Elem::Dynamic(Dynamic::new(other.into(), loc)),
             max: self.max - Elem::Dynamic(Dynamic::new(other.into(), loc)),
-            exclusions: self.exclusions,
+            exclusions: self
+                .exclusions
+                .into_iter()
+                .map(|excl| excl - Elem::Dynamic(Dynamic::new(other.into(), loc)))
+                .collect(),
         }
     }
 
+    /// Multiplying by zero maps every value to zero, and inside of `unchecked` blocks the
+    /// product wraps, so the product of an excluded value may equal the product of an included
+    /// one. The exclusions are dropped as they are no longer guaranteed to be holes in the
+    /// resulting set
     pub fn mul_dyn(self, other: ContextVarNode, loc: Loc) -> Self {
         Self {
             min: self.min * Elem::Dynamic(Dynamic::new(other.into(), loc)),
             max: self.max * Elem::Dynamic(Dynamic::new(other.into(), loc)),
-            exclusions: self.exclusions,
+            exclusions: vec![],
         }
     }
 
@@ -382,12 +400,14 @@ impl SolcRange {
         }
     }
 
+    /// Division is not injective (i.e. `5 / 2 == 4 / 2`), so neighboring intervals
+    /// are merged and the exclusions are dropped
     pub fn div_dyn(self, other: ContextVarNode, loc: Loc) -> Self {
         let elem = Elem::Dynamic(Dynamic::new(other.into(), loc));
         Self {
             min: self.min / elem.clone(),
             max: self.max / elem,
-            exclusions: self.exclusions,
+            exclusions: vec![],
         }
     }
 
@@ -407,15 +427,46 @@ impl SolcRange {
         }
     }
 
+    /// Modulo wraps every interval into `[0, other - 1]`, so the exclusions of the
+    /// original set say nothing about the result and are dropped
     pub fn mod_dyn(self, other: ContextVarNode, loc: Loc) -> Self {
         let elem = Elem::Dynamic(Dynamic::new(other.into(), loc));
         Self {
             min: Elem::from(Concrete::from(U256::zero())),
             max: elem.clone() - Elem::from(Concrete::from(U256::from(1))).cast(elem),
-            exclusions: self.exclusions,
+            exclusions: vec![],
         }
     }
 
+    /// Normalizes the interval set represented by this range: exclusions that evaluate to the
+    /// same value are coalesced and exclusions that fall outside of `[min, max]` are removed,
+    /// as they no longer split the range into disjoint intervals
+    pub fn normalize_exclusions(&mut self, analyzer: &impl GraphLike) {
+        let min = self.evaled_range_min(analyzer);
+        let max = self.evaled_range_max(analyzer);
+        let mut seen: Vec<Elem<Concrete>> = vec![];
+        self.exclusions = std::mem::take(&mut self.exclusions)
+            .into_iter()
+            .filter(|excl| {
+                let excl_min = excl.minimize(analyzer);
+                let excl_max = excl.maximize(analyzer);
+                if excl_min.range_ord(&excl_max) != Some(std::cmp::Ordering::Equal) {
+                    // not a single point, keep it as is
+                    return true;
+                }
+
+                if seen.iter().any(|s| s.range_eq(&excl_min)) {
+                    return false;
+                }
+
+                let in_bounds = !matches!(excl_min.range_ord(&min), Some(std::cmp::Ordering::Less))
+                    && !matches!(excl_min.range_ord(&max), Some(std::cmp::Ordering::Greater));
+                seen.push(excl_min);
+                in_bounds
+            })
+            .collect();
+    }
+
     pub fn min_dyn(self, other: ContextVarNode, loc: Loc) -> Self {
         Self {
             min: self.min.min(Elem::Dynamic(Dynamic::new(other.into(), loc))),
@@ -539,9 +590,18 @@ pub trait RangeEval<E, T: RangeElem<E>>: Range<E, ElemTy = T> {
     fn unsat(&self, analyzer: &impl AnalyzerLike) -> bool {
         !self.sat(analyzer)
     }
+    /// Whether `other` is fully contained within this range. Returns false if the bounds
+    /// can't be compared, i.e. they depend on unresolved dynamic elements
     fn contains(&self, other: &Self, analyzer: &impl AnalyzerLike) -> bool;
     fn contains_elem(&self, other: &T, analyzer: &impl AnalyzerLike) -> bool;
+    /// Whether this range and `other` share at least one value. Returns true unless the ranges
+    /// are provably disjoint, as callers use disjointness to prune contexts
     fn overlaps(&self, other: &Self, analyzer: &impl AnalyzerLike) -> bool;
+    /// Whether this range is fully contained within `other`. Returns false if the bounds
+    /// can't be compared
+    fn is_subset_of(&self, other: &Self, analyzer: &impl AnalyzerLike) -> bool {
+        other.contains(self, analyzer)
+    }
 }
 
 impl RangeEval<Concrete, Elem<Concrete>> for SolcRange {
@@ -599,18 +659,19 @@ impl RangeEval<Concrete, Elem<Concrete>> for SolcRange {
     }
 
     fn overlaps(&self, other: &Self, analyzer: &impl AnalyzerLike) -> bool {
-        let min_contains = matches!(
-            self.evaled_range_min(analyzer)
+        // the ranges are disjoint if one ends before the other starts
+        let self_below = matches!(
+            self.evaled_range_max(analyzer)
                 .range_ord(&other.evaled_range_min(analyzer)),
-            Some(std::cmp::Ordering::Less) | Some(std::cmp::Ordering::Equal)
+            Some(std::cmp::Ordering::Less)
         );
 
-        let max_contains = matches!(
-            self.evaled_range_max(analyzer)
+        let self_above = matches!(
+            self.evaled_range_min(analyzer)
                 .range_ord(&other.evaled_range_max(analyzer)),
-            Some(std::cmp::Ordering::Greater) | Some(std::cmp::Ordering::Equal)
+            Some(std::cmp::Ordering::Greater)
         );
 
-        min_contains || max_contains
+        !self_below && !self_above
     }
 }
@@ -5,6 +5,7 @@ use crate::Concrete;
 use ethers_core::types::H256;
 use ethers_core::types::I256;
 use ethers_core::types::U256;
+use ethers_core::types::U512;
 use std::collections::BTreeMap;
 
 pub trait RangeAdd<T, Rhs = Self> {
@@ -52,7 +53,7 @@ impl RangeAdd<Concrete> for RangeConcrete<Concrete> {
                         } else {
                             I256::from_raw(U256::from(1u8) << U256::from(*lhs_size - 1)) - 1.into()
                         };
-                        let min = max * I256::from(-1i32);
+                        let min = I256::from(-1i32) - max;
                         Some(Elem::Concrete(RangeConcrete {
                             val: Concrete::Int(*lhs_size, l.saturating_add(*r).max(min)),
                             loc: self.loc,
@@ -131,7 +132,7 @@ impl RangeSub<Concrete> for RangeConcrete<Concrete> {
                         I256::from_raw(U256::from(1u8) << U256::from(*lhs_size - 1)) - 1.into()
                     };
 
-                    let min = max * I256::from(-1i32);
+                    let min = I256::from(-1i32) - max;
 
                     Some(Elem::Concrete(RangeConcrete {
                         val: Concrete::Int(
@@ -172,10 +173,15 @@ impl RangeMul<Concrete> for RangeConcrete<Concrete> {
     fn range_mul(&self, other: &Self) -> Option<Elem<Concrete>> {
         match (self.val.into_u256(), other.val.into_u256()) {
             (Some(lhs_val), Some(rhs_val)) => {
-                let max = Concrete::max(&self.val).unwrap();
-                let res = lhs_val
-                    .saturating_mul(rhs_val)
-                    .min(max.into_u256().unwrap());
+                let max = Concrete::max(&self.val).unwrap().into_u256().unwrap();
+                // the product of two 256 bit values always fits in 512 bits, so compare the exact
+                // product against the type maximum
+                let res = lhs_val.full_mul(rhs_val);
+                let res = if res > U512::from(max) {
+                    max
+                } else {
+                    U256::try_from(res).unwrap()
+                };
                 Some(Elem::Concrete(RangeConcrete {
                     val: self.val.u256_as_original(res),
                     loc: self.loc,
@@ -189,7 +195,7 @@ impl RangeMul<Concrete> for RangeConcrete<Concrete> {
                     } else {
                         I256::from_raw(U256::from(1u8) << U256::from(*lhs_size - 1)) - 1.into()
                     };
-                    let min = max * I256::from(-1i32);
+                    let min = I256::from(-1i32) - max;
                     Some(Elem::Concrete(RangeConcrete {
                         val: Concrete::Int(
                             *lhs_size,
@@ -766,7 +772,7 @@ impl RangeShift<Concrete> for RangeConcrete<Concrete> {
                         I256::from_raw(U256::from(1u8) << U256::from(*lhs_size - 1)) - 1.into()
                     };
 
-                    let min = max * I256::from(-1i32);
+                    let min = I256::from(-1i32) - max;
                     let (abs, is_min) = neg_v.overflowing_abs();
                     if is_min {
                         if val > &U256::zero() {
@@ -832,7 +838,7 @@ impl RangeShift<Concrete> for RangeConcrete<Concrete> {
                         } else {
                             I256::from_raw(U256::from(1u8) << U256::from(*lhs_size - 1)) - 1.into()
                         };
-                        let min = max * I256::from(-1i32);
+                        let min = I256::from(-1i32) - max;
 
                         let (abs, is_min) = neg_v.overflowing_abs();
                         let bits = if is_min {
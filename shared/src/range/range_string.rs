@@ -47,6 +47,44 @@ pub trait ToRangeString {
     fn to_range_string(&self, maximize: bool, analyzer: &impl GraphLike) -> RangeElemString;
 }
 
+impl Elem<Concrete> {
+    /// Converts a range element to a string where concrete values are rendered in full
+    /// decimal and dynamic values are kept symbolic (i.e. `len(arr)` for an array length)
+    pub fn to_decimal_string(&self, maximize: bool, analyzer: &impl GraphLike) -> String {
+        match self {
+            Elem::Concrete(c) => c.val.as_string(),
+            Elem::Dynamic(Dynamic { idx, .. }) => {
+                let as_var = ContextVarNode::from(*idx);
+                let name = as_var.display_name(analyzer);
+                match name.strip_suffix(".length") {
+                    Some(arr) if as_var.is_len_var(analyzer) => format!("len({arr})"),
+                    _ => name,
+                }
+            }
+            Elem::Expr(expr) if !matches!(expr.op, RangeOp::Cast) => {
+                let lhs_str = match *expr.lhs {
+                    Elem::Expr(_) => {
+                        format!("({})", expr.lhs.to_decimal_string(maximize, analyzer))
+                    }
+                    _ => expr.lhs.to_decimal_string(maximize, analyzer),
+                };
+                let rhs_str = match *expr.rhs {
+                    Elem::Expr(_) => {
+                        format!("({})", expr.rhs.to_decimal_string(maximize, analyzer))
+                    }
+                    _ => expr.rhs.to_decimal_string(maximize, analyzer),
+                };
+                if matches!(expr.op, RangeOp::Min | RangeOp::Max) {
+                    format!("{}({}, {})", expr.op.to_string(), lhs_str, rhs_str)
+                } else {
+                    format!("{} {} {}", lhs_str, expr.op.to_string(), rhs_str)
+                }
+            }
+            _ => self.to_range_string(maximize, analyzer).s,
+        }
+    }
+}
+
 impl ToRangeString for Elem<Concrete> {
     fn def_string(&self, analyzer: &impl GraphLike) -> RangeElemString {
         match self {
@@ -370,7 +370,7 @@ pub fn builtin_fns_inputs(
             vec![],
             vec![FunctionReturn {
                 loc: Loc::Builtin,
-                ty: analyzer.builtin_or_add(Builtin::Uint(64)),
+                ty: analyzer.builtin_or_add(Builtin::Uint(256)),
                 storage: None,
                 name: None,
             }],
@@ -379,7 +379,7 @@ pub fn builtin_fns_inputs(
             "blockhash",
             vec![FunctionParam {
                 loc: Loc::Builtin,
-                ty: analyzer.builtin_or_add(Builtin::Uint(64)),
+                ty: analyzer.builtin_or_add(Builtin::Uint(256)),
                 order: 0,
                 storage: Some(StorageLocation::Memory(Loc::Implicit)),
                 name: None,
@@ -0,0 +1,128 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+    Edge,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use solang_parser::pt::{FunctionTy, Loc};
+use std::collections::BTreeMap;
+
+/// A public or external function that changes state without checking who called it. This is a
+/// heuristic, so it may flag functions that are meant to be callable by anyone
+#[derive(Debug, Clone)]
+pub struct MissingAccessControlAnalysis {
+    pub ctx: ContextNode,
+    pub func_span: LocStrSpan,
+    /// Locations of the state changes and a description of each
+    pub state_changes: Vec<(LocStrSpan, String)>,
+}
+
+impl ReportDisplay for MissingAccessControlAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Missing Access Control", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "{} changes state without checking msg.sender (low confidence)",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        let mut labels = vec![Label::new(self.func_span.clone())
+            .with_message(
+                "No require on msg.sender or tx.origin and no access control modifier"
+                    .fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)];
+        labels.extend(self.state_changes.iter().map(|(span, change)| {
+            Label::new(span.clone())
+                .with_message(change.fg(Color::Yellow))
+                .with_color(Color::Yellow)
+        }));
+        labels
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.func_span
+    }
+}
+
+impl<T> MissingAccessControlAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait MissingAccessControlAnalyzer: Search + AnalyzerLike + Sized {
+    /// Checks whether a public or external function writes to storage or calls a state changing
+    /// external function without a require involving `msg.sender` or `tx.origin` and without an
+    /// `only*` modifier. Returns `None` if the function isn't reachable from outside, changes no
+    /// state or has some form of access control
+    fn missing_access_control(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Option<MissingAccessControlAnalysis> {
+        let func = ctx.associated_fn(self);
+        if !func.is_public_or_ext(self) || !matches!(func.underlying(self).ty, FunctionTy::Function)
+        {
+            return None;
+        }
+
+        if func
+            .modifiers(self)
+            .iter()
+            .any(|modifier| modifier.name(self).starts_with("only"))
+        {
+            return None;
+        }
+
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        if ctxs.iter().any(|ctx| {
+            !ctx.underlying(self)
+                .findings_of(FindingKind::SenderCheck)
+                .is_empty()
+        }) {
+            return None;
+        }
+
+        let mut state_changes: Vec<_> = self
+            .search_children(ctx.into(), &Edge::Context(ContextEdge::StorageAssign))
+            .into_iter()
+            .map(ContextVarNode::from)
+            .map(|var| {
+                (
+                    LocStrSpan::new(
+                        file_mapping,
+                        var.underlying(self).loc.unwrap_or(Loc::Implicit),
+                    ),
+                    format!("Writes to {}", var.display_name(self)),
+                )
+            })
+            .collect();
+
+        state_changes.extend(ctxs.iter().filter_map(|subctx| {
+            let ext_fn = subctx.underlying(self).ext_fn_call?;
+            if ext_fn.is_view_or_pure(self) {
+                None
+            } else {
+                Some((
+                    LocStrSpan::new(file_mapping, subctx.underlying(self).loc),
+                    format!("Calls {}", ext_fn.name(self)),
+                ))
+            }
+        }));
+
+        if state_changes.is_empty() {
+            return None;
+        }
+
+        Some(MissingAccessControlAnalysis {
+            ctx,
+            func_span: LocStrSpan::new(file_mapping, func.underlying(self).loc),
+            state_changes,
+        })
+    }
+}
@@ -0,0 +1,132 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// A `transfer` or `send` out of the contract whose amount may exceed the contract's balance
+#[derive(Debug, Clone)]
+pub struct OverTransferAnalysis {
+    pub ctx: ContextNode,
+    pub transfer_span: LocStrSpan,
+}
+
+impl ReportDisplay for OverTransferAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Over Transfer", Color::Red)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Possible transfer of more than the balance in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.transfer_span.clone())
+            .with_message("The amount may exceed address(this).balance".fg(Color::Red))
+            .with_color(Color::Red)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.transfer_span
+    }
+}
+
+impl<T> OverTransferAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait OverTransferAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all transfers and sends in the context (and its subcontexts) whose amount may exceed
+    /// the contract's balance
+    fn over_transfers(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<OverTransferAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|transfer_ctx| {
+                transfer_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::OverTransfer)
+                    .iter()
+                    .map(ContextFinding::loc)
+                    .map(|loc| OverTransferAnalysis {
+                        ctx: transfer_ctx,
+                        transfer_span: LocStrSpan::new(file_mapping, loc),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+/// A strict equality comparison against the contract's balance, like
+/// `address(this).balance == x`, which anyone can break by force-sending ether
+#[derive(Debug, Clone)]
+pub struct StrictBalanceAnalysis {
+    pub ctx: ContextNode,
+    pub cmp_span: LocStrSpan,
+}
+
+impl ReportDisplay for StrictBalanceAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Strict Balance Equality", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Strict equality check on the contract balance in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.cmp_span.clone())
+            .with_message(
+                "The balance can be increased by force-sending ether, consider using `>=`"
+                    .fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.cmp_span
+    }
+}
+
+impl<T> StrictBalanceAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait StrictBalanceAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all strict equality comparisons against the contract's balance in the context (and
+    /// its subcontexts)
+    fn strict_balance_checks(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<StrictBalanceAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|cmp_ctx| {
+                cmp_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::StrictBalanceCheck)
+                    .iter()
+                    .map(ContextFinding::loc)
+                    .map(|loc| StrictBalanceAnalysis {
+                        ctx: cmp_ctx,
+                        cmp_span: LocStrSpan::new(file_mapping, loc),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
@@ -1,11 +1,11 @@
-use crate::analyzers::{LocStrSpan, ReportConfig, ReportDisplay};
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportConfig, ReportDisplay};
 use shared::{
     analyzer::{AnalyzerLike, Search},
     context::*,
     range::{range_string::*, Range, RangeEval, SolcRange},
 };
 
-use ariadne::{Cache, Color, Config, Fmt, Label, Report, ReportKind, Span};
+use ariadne::{Color, Config, Fmt, Label, Report, ReportKind, Span};
 use solang_parser::pt::{CodeLocation, StorageLocation};
 use std::collections::{BTreeMap, BTreeSet};
 
@@ -126,6 +126,9 @@ impl ReportDisplay for BoundAnalysis {
     fn report_kind(&self) -> ReportKind {
         ReportKind::Custom("Bounds", Color::Cyan)
     }
+    fn severity(&self) -> Severity {
+        Severity::Advice
+    }
     fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
         format!(
             "Bounds for {} in {}:",
@@ -319,6 +322,10 @@ impl ReportDisplay for BoundAnalysis {
         labels
     }
 
+    fn report_span(&self) -> &LocStrSpan {
+        &self.var_def.0
+    }
+
     fn reports(&self, analyzer: &(impl AnalyzerLike + Search)) -> Vec<Report<LocStrSpan>> {
         let mut report = Report::build(
             self.report_kind(),
@@ -356,28 +363,6 @@ impl ReportDisplay for BoundAnalysis {
 
         reports
     }
-
-    fn print_reports(
-        &self,
-        mut src: &mut impl Cache<String>,
-        analyzer: &(impl AnalyzerLike + Search),
-    ) {
-        let reports = self.reports(analyzer);
-        reports.into_iter().for_each(|report| {
-            report.print(&mut src).unwrap();
-        });
-    }
-
-    fn eprint_reports(
-        &self,
-        mut src: &mut impl Cache<String>,
-        analyzer: &(impl AnalyzerLike + Search),
-    ) {
-        let reports = self.reports(analyzer);
-        reports.into_iter().for_each(|report| {
-            report.eprint(&mut src).unwrap();
-        });
-    }
 }
 
 impl<T> BoundAnalyzer for T where T: Search + AnalyzerLike + Sized {}
@@ -586,6 +571,10 @@ impl<'a> ReportDisplay for FunctionVarsBoundAnalysis<'a> {
         vec![]
     }
 
+    fn report_span(&self) -> &LocStrSpan {
+        &self.ctx_loc
+    }
+
     fn reports(&self, analyzer: &(impl AnalyzerLike + Search)) -> Vec<Report<LocStrSpan>> {
         let mut report = Report::build(
             self.report_kind(),
@@ -891,28 +880,6 @@ impl<'a> ReportDisplay for FunctionVarsBoundAnalysis<'a> {
 
         reports
     }
-
-    fn print_reports(
-        &self,
-        mut src: &mut impl Cache<String>,
-        analyzer: &(impl AnalyzerLike + Search),
-    ) {
-        let reports = &self.reports(analyzer);
-        for report in reports.iter() {
-            report.print(&mut src).unwrap();
-        }
-    }
-
-    fn eprint_reports(
-        &self,
-        mut src: &mut impl Cache<String>,
-        analyzer: &(impl AnalyzerLike + Search),
-    ) {
-        let reports = &self.reports(analyzer);
-        reports.iter().for_each(|report| {
-            report.eprint(&mut src).unwrap();
-        });
-    }
 }
 
 impl<T> FunctionVarsBoundAnalyzer for T where T: BoundAnalyzer + Search + AnalyzerLike + Sized {}
@@ -934,7 +901,7 @@ pub trait FunctionVarsBoundAnalyzer: BoundAnalyzer + Search + AnalyzerLike + Siz
                     .iter()
                     .flat_map(|p| p.returning_child_list(self))
                     .collect();
-                let mut vars = ctx.vars(self);
+                let mut vars = ctx.vars_sorted(self);
                 vars.sort_by_key(|a| a.name(self));
                 vars.dedup_by(|a, b| a.name(self) == b.name(self));
                 (
@@ -0,0 +1,71 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// A branch that was merged instead of forked because its function exceeded the branch budget
+#[derive(Debug, Clone)]
+pub struct MergedBranchAnalysis {
+    pub ctx: ContextNode,
+    pub branch_span: LocStrSpan,
+}
+
+impl ReportDisplay for MergedBranchAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Branch Budget", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Branch budget exceeded in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.branch_span.clone())
+            .with_message(
+                "Branches were merged or a loop was skipped, so variables modified in them lost precision"
+                    .fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.branch_span
+    }
+}
+
+impl<T> BranchBudgetAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait BranchBudgetAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all branches in the context (and its subcontexts) that were merged instead of
+    /// forked because the function exceeded the maximum number of subcontexts
+    fn merged_branches(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<MergedBranchAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|branch_ctx| {
+                branch_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::MergedBranch)
+                    .iter()
+                    .map(ContextFinding::loc)
+                    .map(|loc| MergedBranchAnalysis {
+                        ctx: branch_ctx,
+                        branch_span: LocStrSpan::new(file_mapping, loc),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
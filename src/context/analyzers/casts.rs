@@ -0,0 +1,82 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// A narrowing cast of a variable whose range may not fit in the cast type
+#[derive(Debug, Clone)]
+pub struct UnsafeCastAnalysis {
+    pub ctx: ContextNode,
+    pub cast_span: LocStrSpan,
+    pub var_name: String,
+    pub var_range: Option<String>,
+}
+
+impl ReportDisplay for UnsafeCastAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Unsafe cast", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Potentially truncating cast in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        let msg = if let Some(range) = &self.var_range {
+            format!(
+                "\"{}\" has range {range}, which may not fit in the cast type",
+                self.var_name
+            )
+        } else {
+            format!("\"{}\" may not fit in the cast type", self.var_name)
+        };
+        vec![Label::new(self.cast_span.clone())
+            .with_message(msg.fg(Color::Yellow))
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.cast_span
+    }
+}
+
+impl<T> CastAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait CastAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all narrowing casts in the context (and its subcontexts) of variables whose range
+    /// isn't already bounded within the cast type
+    fn unsafe_casts(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<UnsafeCastAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|cast_ctx| {
+                cast_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::UnsafeCast)
+                    .iter()
+                    .filter_map(|finding| match finding {
+                        ContextFinding::UnsafeCast(loc, var) => Some(UnsafeCastAnalysis {
+                            ctx: cast_ctx,
+                            cast_span: LocStrSpan::new(file_mapping, *loc),
+                            var_name: var.display_name(self),
+                            var_range: var.range_string(self),
+                        }),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,299 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+    nodes::{FunctionNode, VarNode},
+    Edge,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use petgraph::{visit::EdgeRef, Direction};
+use solang_parser::pt::{Expression, Loc, Statement};
+use std::collections::BTreeMap;
+
+/// An external call followed by writes to storage, breaking the checks-effects-interactions
+/// pattern
+#[derive(Debug, Clone)]
+pub struct CeiAnalysis {
+    pub ctx: ContextNode,
+    pub call_span: LocStrSpan,
+    /// The name of the called external function, or of the call made without one, i.e.
+    /// `transfer`
+    pub call_name: String,
+    /// Locations of storage writes after the call and the name of the written variable
+    pub storage_writes: Vec<(LocStrSpan, String)>,
+}
+
+impl ReportDisplay for CeiAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Checks-Effects-Interactions", Color::Red)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Storage written after an external call in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        let mut labels = vec![Label::new(self.call_span.clone())
+            .with_message(
+                format!("Calls external function \"{}\"", self.call_name).fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)];
+
+        labels.extend(self.storage_writes.iter().map(|(span, name)| {
+            Label::new(span.clone())
+                .with_message(
+                    format!("Writes to storage variable \"{name}\" after the call").fg(Color::Red),
+                )
+                .with_color(Color::Red)
+        }));
+        labels
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.call_span
+    }
+}
+
+/// The (file, start) of a location, used to order locations within a function
+fn loc_start(loc: Loc) -> Option<(usize, usize)> {
+    match loc {
+        Loc::File(file, start, _) => Some((file, start)),
+        _ => None,
+    }
+}
+
+/// Whether the location falls within the span of the other location
+fn loc_within(loc: Loc, span: Loc) -> bool {
+    match (loc, span) {
+        (Loc::File(file, start, end), Loc::File(span_file, span_start, span_end)) => {
+            file == span_file && start >= span_start && end <= span_end
+        }
+        _ => false,
+    }
+}
+
+/// The name of the variable assigned by a statement like `locked = true;`
+fn assigned_name(stmt: &Statement) -> Option<&str> {
+    match stmt {
+        Statement::Expression(_, Expression::Assign(_, lhs, _)) => match &**lhs {
+            Expression::Variable(ident) => Some(&ident.name),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether the statement is a modifier's `_;`, where the modified function's body runs
+fn is_placeholder(stmt: &Statement) -> bool {
+    matches!(stmt, Statement::Expression(_, Expression::Variable(ident)) if ident.name == "_")
+}
+
+/// Whether the expression reads the named variable, looking through the operators used in
+/// guard conditions
+fn mentions(expr: &Expression, name: &str) -> bool {
+    match expr {
+        Expression::Variable(ident) => ident.name == name,
+        Expression::Parenthesis(_, inner) | Expression::Not(_, inner) => mentions(inner, name),
+        Expression::Equal(_, lhs, rhs)
+        | Expression::NotEqual(_, lhs, rhs)
+        | Expression::Less(_, lhs, rhs)
+        | Expression::More(_, lhs, rhs)
+        | Expression::LessEqual(_, lhs, rhs)
+        | Expression::MoreEqual(_, lhs, rhs)
+        | Expression::And(_, lhs, rhs)
+        | Expression::Or(_, lhs, rhs) => mentions(lhs, name) || mentions(rhs, name),
+        _ => false,
+    }
+}
+
+/// Whether the statement checks a condition on the named variable, i.e. `require(!locked)` or
+/// `if (locked) revert();`
+fn checks_var(stmt: &Statement, name: &str) -> bool {
+    match stmt {
+        Statement::Expression(_, Expression::FunctionCall(_, func, args)) => {
+            matches!(&**func, Expression::Variable(ident) if ident.name == "require")
+                && args.first().map_or(false, |cond| mentions(cond, name))
+        }
+        Statement::If(_, cond, ..) => mentions(cond, name),
+        _ => false,
+    }
+}
+
+impl<T> CeiAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait CeiAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds external calls in the function, including `transfer`s and `send`s of ether, that
+    /// are followed by storage writes on the same path. Only calls and writes made directly in the function body are ordered, so writes
+    /// made by internal calls are not considered. A function with no violations follows the
+    /// checks-effects-interactions pattern
+    fn cei_violations(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<CeiAnalysis> {
+        // reentering is what makes a write after a call exploitable, so guarded functions are
+        // safe even if they don't follow checks-effects-interactions
+        if self.is_reentrancy_guarded(ctx.associated_fn(self)) {
+            return vec![];
+        }
+        let func_loc = ctx.associated_fn(self).underlying(self).loc;
+
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        let writes: Vec<(ContextNode, Loc, String)> = ctxs
+            .iter()
+            .flat_map(|write_ctx| {
+                self.graph()
+                    .edges_directed((*write_ctx).into(), Direction::Incoming)
+                    .filter(|edge| *edge.weight() == Edge::Context(ContextEdge::StorageAssign))
+                    .map(|edge| ContextVarNode::from(edge.source()))
+                    .filter_map(|var| {
+                        let loc = var.underlying(self).loc?;
+                        if loc_within(loc, func_loc) {
+                            Some((*write_ctx, loc, var.display_name(self)))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // each call is the context it is reported in, the context it was made from, its location
+        // and the name of what was called. Function calls get their own context while calls
+        // without a function, like `to.transfer(amount)`, are made in the context itself
+        let mut calls: Vec<(ContextNode, ContextNode, Loc, String)> = vec![];
+        ctxs.iter().for_each(|call_ctx| {
+            let underlying = call_ctx.underlying(self);
+            if let (Some(ext_fn), Some(caller)) = (underlying.ext_fn_call, underlying.parent_ctx) {
+                if !ext_fn.is_view_or_pure(self) {
+                    calls.push((*call_ctx, caller, underlying.loc, ext_fn.name(self)));
+                }
+            }
+            calls.extend(
+                underlying
+                    .findings_of(FindingKind::RawCall)
+                    .iter()
+                    .filter_map(|finding| match finding {
+                        ContextFinding::RawCall(loc, name) => {
+                            Some((*call_ctx, *call_ctx, *loc, name.clone()))
+                        }
+                        _ => None,
+                    }),
+            );
+        });
+
+        calls
+            .into_iter()
+            .filter_map(|(call_ctx, caller, call_loc, call_name)| {
+                if !loc_within(call_loc, func_loc) {
+                    return None;
+                }
+
+                // a write is on the same path as the call if it was made in the calling context or
+                // one of its descendants, or in a context the call was made from
+                let storage_writes: Vec<_> = writes
+                    .iter()
+                    .filter(|(write_ctx, loc, _)| {
+                        let same_path = *write_ctx == caller
+                            || write_ctx.parent_list(self).contains(&caller)
+                            || caller.parent_list(self).contains(write_ctx);
+                        same_path && loc_start(*loc) > loc_start(call_loc)
+                    })
+                    .map(|(_, loc, name)| (LocStrSpan::new(file_mapping, *loc), name.clone()))
+                    .collect();
+
+                if storage_writes.is_empty() {
+                    None
+                } else {
+                    Some(CeiAnalysis {
+                        ctx: call_ctx,
+                        call_span: LocStrSpan::new(file_mapping, call_loc),
+                        call_name,
+                        storage_writes,
+                    })
+                }
+            })
+            .collect()
+    }
+
+    /// Whether any of the function's modifiers is a reentrancy guard
+    fn is_reentrancy_guarded(&self, func: FunctionNode) -> bool {
+        func.modifiers(self)
+            .into_iter()
+            .any(|modifier| self.is_reentrancy_guard(modifier))
+    }
+
+    /// Whether the modifier is a mutex on a storage variable, like the classic
+    /// `nonReentrant`: before `_` it checks the variable and sets it, and after `_` it resets it.
+    /// Calls to internal functions around `_` are followed one level, as OpenZeppelin's
+    /// `_nonReentrantBefore(); _; _nonReentrantAfter();` does the checks and writes in them
+    fn is_reentrancy_guard(&self, modifier: FunctionNode) -> bool {
+        let statements = match &modifier.underlying(self).body {
+            Some(Statement::Block { statements, .. }) => statements,
+            _ => return false,
+        };
+        let placeholder = match statements.iter().position(is_placeholder) {
+            Some(placeholder) => placeholder,
+            None => return false,
+        };
+
+        let con = modifier.contract(self);
+        let funcs = con.map(|con| con.funcs(self)).unwrap_or_default();
+        let before = self.inline_internal_calls(&statements[..placeholder], &funcs);
+        let after = self.inline_internal_calls(&statements[placeholder + 1..], &funcs);
+
+        let storage_vars = con
+            .map(|con| {
+                self.search_children(con.into(), &Edge::Var)
+                    .into_iter()
+                    .map(|var| VarNode::from(var).name(self))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        before.iter().enumerate().any(|(i, stmt)| {
+            assigned_name(stmt).map_or(false, |name| {
+                storage_vars.iter().any(|var| var == name)
+                    && before[..i].iter().any(|check| checks_var(check, name))
+                    && after.iter().any(|reset| assigned_name(reset) == Some(name))
+            })
+        })
+    }
+
+    /// Replaces argument-less calls to the given functions, like `_nonReentrantBefore();`, with
+    /// the statements of the called function's body. Calls in the inlined bodies are kept as is
+    fn inline_internal_calls(
+        &self,
+        statements: &[Statement],
+        funcs: &[FunctionNode],
+    ) -> Vec<Statement> {
+        statements
+            .iter()
+            .flat_map(|stmt| {
+                let called = match stmt {
+                    Statement::Expression(_, Expression::FunctionCall(_, func_expr, args))
+                        if args.is_empty() =>
+                    {
+                        match &**func_expr {
+                            Expression::Variable(ident) => funcs
+                                .iter()
+                                .find(|func| func.name(self) == format!("{}()", ident.name)),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                match called.and_then(|func| func.underlying(self).body.as_ref()) {
+                    Some(Statement::Block { statements, .. }) => statements.clone(),
+                    _ => vec![stmt.clone()],
+                }
+            })
+            .collect()
+    }
+}
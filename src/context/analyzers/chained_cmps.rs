@@ -0,0 +1,73 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// A comparison with a boolean operand, like `a < b < c`, which compares the result of `a < b`
+/// instead of chaining
+#[derive(Debug, Clone)]
+pub struct ChainedComparisonAnalysis {
+    pub ctx: ContextNode,
+    pub cmp_span: LocStrSpan,
+}
+
+impl ReportDisplay for ChainedComparisonAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Chained Comparison", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Comparison of a boolean comparison result in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.cmp_span.clone())
+            .with_message(
+                "Comparisons don't chain, this compares the result of the inner comparison, \
+                 use && to combine comparisons instead"
+                    .fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.cmp_span
+    }
+}
+
+impl<T> ChainedComparisonAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait ChainedComparisonAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all comparisons in the context (and its subcontexts) that compare a boolean
+    /// comparison result, like `a < b < c`
+    fn chained_comparisons(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<ChainedComparisonAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|cmp_ctx| {
+                cmp_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::ChainedComparison)
+                    .iter()
+                    .map(ContextFinding::loc)
+                    .map(|loc| ChainedComparisonAnalysis {
+                        ctx: cmp_ctx,
+                        cmp_span: LocStrSpan::new(file_mapping, loc),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
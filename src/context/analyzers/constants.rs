@@ -0,0 +1,79 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+    range::range_string::ToRangeString,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// An arithmetic or bitwise operation on a varying operand that always produces the same value,
+/// like `x * 0`, which often indicates a logic bug
+#[derive(Debug, Clone)]
+pub struct ConstantResultAnalysis {
+    pub ctx: ContextNode,
+    pub op_span: LocStrSpan,
+    pub expr: String,
+    pub constant: String,
+}
+
+impl ReportDisplay for ConstantResultAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Constant Result", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Expression always evaluates to {} in {}",
+            self.constant,
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.op_span.clone())
+            .with_message(
+                format!("\"{}\" is always {}", self.expr, self.constant).fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.op_span
+    }
+}
+
+impl<T> ConstantResultAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait ConstantResultAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all arithmetic and bitwise operations in the context (and its subcontexts) whose
+    /// result is a single value even though an operand can vary
+    fn constant_results(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<ConstantResultAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|op_ctx| {
+                op_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::ConstantResult)
+                    .iter()
+                    .filter_map(|finding| match finding {
+                        ContextFinding::ConstantResult(loc, var) => Some(ConstantResultAnalysis {
+                            ctx: op_ctx,
+                            op_span: LocStrSpan::new(file_mapping, *loc),
+                            expr: var.display_name(self),
+                            constant: var.evaled_range_min(self)?.to_range_string(false, self).s,
+                        }),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
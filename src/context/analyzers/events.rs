@@ -0,0 +1,89 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+    nodes::VarType,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// An argument of an emitted event that may not fit the type of its event parameter
+#[derive(Debug, Clone)]
+pub struct EventArgAnalysis {
+    pub ctx: ContextNode,
+    pub arg_span: LocStrSpan,
+    pub arg_name: String,
+    pub arg_range: Option<String>,
+    pub param_ty: String,
+}
+
+impl ReportDisplay for EventArgAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Event argument mismatch", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Event argument may not fit its parameter type in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        let msg = if let Some(range) = &self.arg_range {
+            format!(
+                "\"{}\" has range {range}, which may not fit in {}",
+                self.arg_name, self.param_ty
+            )
+        } else {
+            format!("\"{}\" may not fit in {}", self.arg_name, self.param_ty)
+        };
+        vec![Label::new(self.arg_span.clone())
+            .with_message(msg.fg(Color::Yellow))
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.arg_span
+    }
+}
+
+impl<T> EventArgAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait EventArgAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all arguments of emitted events in the context (and its subcontexts) that may not
+    /// fit the type of their event parameter
+    fn event_arg_mismatches(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<EventArgAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|emit_ctx| {
+                emit_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::EventArgMismatch)
+                    .iter()
+                    .filter_map(|finding| match finding {
+                        ContextFinding::EventArgMismatch(loc, var, param) => {
+                            Some(EventArgAnalysis {
+                                ctx: emit_ctx,
+                                arg_span: LocStrSpan::new(file_mapping, *loc),
+                                arg_name: var.display_name(self),
+                                arg_range: var.range_string(self),
+                                param_ty: VarType::try_from_idx(self, param.underlying(self).ty)
+                                    .map(|ty| ty.as_string(self))
+                                    .unwrap_or_else(|| "<unknown>".to_string()),
+                            })
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,234 @@
+use crate::analyzers::{registry::AnalyzerRegistry, ContextAnalyzer, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+    nodes::FunctionNode,
+    Node,
+};
+
+use ariadne::Report;
+use serde::Serialize;
+use solang_parser::pt::Loc;
+use std::collections::BTreeMap;
+
+/// The version of the JSON schema emitted by `reports_to_json`. Bump this when fields are
+/// renamed, removed or change meaning
+pub const REPORTS_JSON_VERSION: usize = 1;
+
+/// The severity of a finding, as set by its analysis through `ReportDisplay::severity`. Ordered
+/// from least to most severe
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Advice,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Advice => "advice",
+        }
+    }
+
+    /// Parses a severity from its name as returned by `as_str`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "error" => Some(Severity::Error),
+            "warning" => Some(Severity::Warning),
+            "advice" => Some(Severity::Advice),
+            _ => None,
+        }
+    }
+}
+
+/// A single finding from an analyzer, with the reports used to display it
+pub struct Finding {
+    /// The primary location of the finding, used for sorting and deduplication
+    pub span: LocStrSpan,
+    pub msg: String,
+    pub severity: Severity,
+    /// The name of the lint that produced the finding, set when run through an
+    /// `AnalyzerRegistry`
+    pub rule_id: String,
+    pub reports: Vec<Report<LocStrSpan>>,
+}
+
+impl Finding {
+    pub fn new(
+        display: &impl ReportDisplay,
+        span: LocStrSpan,
+        analyzer: &(impl AnalyzerLike + Search),
+    ) -> Self {
+        Self {
+            span,
+            msg: display.msg(analyzer),
+            severity: display.severity(),
+            rule_id: String::new(),
+            reports: display.reports(analyzer),
+        }
+    }
+}
+
+/// Keeps only the findings at least as severe as `min_severity`
+pub fn filter_findings(findings: Vec<Finding>, min_severity: Severity) -> Vec<Finding> {
+    findings
+        .into_iter()
+        .filter(|finding| finding.severity >= min_severity)
+        .collect()
+}
+
+/// Returns the reports of the findings at least as severe as `min_severity`
+pub fn filter_reports(findings: Vec<Finding>, min_severity: Severity) -> Vec<Report<LocStrSpan>> {
+    filter_findings(findings, min_severity)
+        .into_iter()
+        .flat_map(|finding| finding.reports)
+        .collect()
+}
+
+/// Removes the findings produced by any of the given rules
+pub fn suppress_rules(findings: Vec<Finding>, rule_ids: &[String]) -> Vec<Finding> {
+    findings
+        .into_iter()
+        .filter(|finding| !rule_ids.contains(&finding.rule_id))
+        .collect()
+}
+
+impl<T> FindingsAnalyzer for T where T: ContextAnalyzer + Sized {}
+pub trait FindingsAnalyzer: ContextAnalyzer + Sized {
+    /// Runs the built-in lints on every analyzed function, returning the reports of all
+    /// findings sorted by source location. The bounds lint is registered but disabled by
+    /// default, so use `run_analyzers` with it enabled to include bounds
+    fn run_all_analyzers(&self, file_mapping: &BTreeMap<usize, String>) -> Vec<Report<LocStrSpan>> {
+        self.run_analyzers(file_mapping, &AnalyzerRegistry::default())
+    }
+
+    /// Runs the enabled lints of the registry on every analyzed function, returning the reports
+    /// of all findings sorted by source location. Findings with the same location and message
+    /// are only reported once
+    fn run_analyzers(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        registry: &AnalyzerRegistry<Self>,
+    ) -> Vec<Report<LocStrSpan>> {
+        self.findings(file_mapping, registry)
+            .into_iter()
+            .flat_map(|finding| finding.reports)
+            .collect()
+    }
+
+    /// Runs the enabled lints of the registry on every analyzed function, returning all findings
+    /// sorted by source location. Findings with the same location and message are only
+    /// returned once
+    fn findings(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        registry: &AnalyzerRegistry<Self>,
+    ) -> Vec<Finding> {
+        let ctxs: Vec<ContextNode> = self
+            .graph()
+            .node_indices()
+            .filter(|idx| matches!(self.node(*idx), Node::Function(_)))
+            .filter_map(|idx| FunctionNode::from(idx).maybe_body_ctx(self))
+            .collect();
+
+        let mut findings: Vec<Finding> = ctxs
+            .into_iter()
+            .flat_map(|ctx| {
+                registry
+                    .enabled()
+                    .flat_map(|lint| {
+                        let mut findings = lint.run(self, file_mapping, ctx);
+                        findings
+                            .iter_mut()
+                            .for_each(|finding| finding.rule_id = lint.name().to_string());
+                        findings
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        findings.sort_by(|a, b| (&a.span, &a.msg).cmp(&(&b.span, &b.msg)));
+        findings.dedup_by(|a, b| a.span == b.span && a.msg == b.msg);
+        findings
+    }
+
+    /// Formats findings as a pretty printed, versioned JSON document for consumption by CI
+    /// tools. `sources` maps file names to their source code and is used to convert locations
+    /// into 1-indexed lines and columns (the end column is exclusive). Positions in files
+    /// missing from `sources` are reported as 0
+    fn reports_to_json(&self, findings: &[Finding], sources: &BTreeMap<String, String>) -> String {
+        let findings = findings
+            .iter()
+            .map(|finding| {
+                let (start, end) = match finding.span.1 {
+                    Loc::File(_, start, end) => (start, end),
+                    _ => (0, 0),
+                };
+                let src = sources.get(&finding.span.0);
+                let (start_line, start_col) = src.map(|src| line_col(src, start)).unwrap_or((0, 0));
+                let (end_line, end_col) = src.map(|src| line_col(src, end)).unwrap_or((0, 0));
+                JsonFinding {
+                    rule_id: &finding.rule_id,
+                    severity: finding.severity.as_str(),
+                    message: strip_ansi(&finding.msg),
+                    file: &finding.span.0,
+                    start_line,
+                    start_col,
+                    end_line,
+                    end_col,
+                }
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&JsonReports {
+            schema_version: REPORTS_JSON_VERSION,
+            findings,
+        })
+        .expect("Findings should always serialize")
+    }
+}
+
+/// The document emitted by `reports_to_json`
+#[derive(Serialize)]
+struct JsonReports<'a> {
+    schema_version: usize,
+    findings: Vec<JsonFinding<'a>>,
+}
+
+/// A finding as emitted by `reports_to_json`
+#[derive(Serialize)]
+struct JsonFinding<'a> {
+    rule_id: &'a str,
+    severity: &'static str,
+    message: String,
+    file: &'a str,
+    start_line: usize,
+    start_col: usize,
+    end_line: usize,
+    end_col: usize,
+}
+
+/// Converts a byte offset into a 1-indexed line and column
+fn line_col(src: &str, offset: usize) -> (usize, usize) {
+    let before = src.get(..offset).unwrap_or(src);
+    let line = before.matches('\n').count() + 1;
+    let col = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+    (line, col)
+}
+
+/// Removes the terminal color codes added to report messages
+fn strip_ansi(s: &str) -> String {
+    let mut stripped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            chars.by_ref().find(|c| *c == 'm');
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
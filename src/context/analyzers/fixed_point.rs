@@ -0,0 +1,82 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+    Edge,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use solang_parser::pt::Loc;
+use std::collections::BTreeMap;
+
+/// A `fixed` or `ufixed` variable. Fixed point math isn't analyzed, so the variable's range is
+/// only a placeholder covering its underlying integer
+#[derive(Debug, Clone)]
+pub struct FixedPointAnalysis {
+    pub ctx: ContextNode,
+    pub var_span: LocStrSpan,
+    pub var_name: String,
+    pub ty: String,
+}
+
+impl ReportDisplay for FixedPointAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Advice
+    }
+    fn severity(&self) -> Severity {
+        Severity::Advice
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Fixed point analysis is approximate for {} in {}",
+            self.var_name.clone().fg(Color::Green),
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.var_span.clone())
+            .with_message(
+                format!(
+                    "{} is treated as its full underlying integer range",
+                    self.ty
+                )
+                .fg(Color::Blue),
+            )
+            .with_color(Color::Blue)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.var_span
+    }
+}
+
+impl<T> FixedPointAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait FixedPointAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all `fixed` and `ufixed` variables declared in the context (and its subcontexts),
+    /// whose ranges are placeholders rather than analyzed values
+    fn fixed_point_vars(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<FixedPointAnalysis> {
+        let mut vars: Vec<_> = self
+            .search_children(ctx.into(), &Edge::Context(ContextEdge::Variable))
+            .into_iter()
+            .map(ContextVarNode::from)
+            .filter(|var| var.is_fixed_point(self) && var.previous_version(self).is_none())
+            .map(|var| FixedPointAnalysis {
+                ctx,
+                var_span: LocStrSpan::new(
+                    file_mapping,
+                    var.underlying(self).loc.unwrap_or(Loc::Implicit),
+                ),
+                var_name: var.display_name(self),
+                ty: var.underlying(self).ty.as_string(self),
+            })
+            .collect();
+        vars.sort_by(|a, b| a.var_span.cmp(&b.var_span));
+        vars.dedup_by(|a, b| a.var_span == b.var_span);
+        vars
+    }
+}
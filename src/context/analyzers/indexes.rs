@@ -0,0 +1,71 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// An array index by a ternary, like `arr[c ? i : j]`, where some branch may index past the end
+/// of the array
+#[derive(Debug, Clone)]
+pub struct OutOfBoundsIndexAnalysis {
+    pub ctx: ContextNode,
+    pub index_span: LocStrSpan,
+}
+
+impl ReportDisplay for OutOfBoundsIndexAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Out of Bounds", Color::Red)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Possible out of bounds index in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.index_span.clone())
+            .with_message(
+                "A branch of the index reaches past the end of the array, reverting".fg(Color::Red),
+            )
+            .with_color(Color::Red)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.index_span
+    }
+}
+
+impl<T> OutOfBoundsIndexAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait OutOfBoundsIndexAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all ternary array indexes in the context (and its subcontexts) with a branch that may
+    /// be out of bounds
+    fn out_of_bounds_indexes(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<OutOfBoundsIndexAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|index_ctx| {
+                index_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::OutOfBoundsIndex)
+                    .iter()
+                    .map(ContextFinding::loc)
+                    .map(|loc| OutOfBoundsIndexAnalysis {
+                        ctx: index_ctx,
+                        index_span: LocStrSpan::new(file_mapping, loc),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
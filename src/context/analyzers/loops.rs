@@ -0,0 +1,206 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// A loop whose condition is always true and whose body has no way of exiting the loop
+#[derive(Debug, Clone)]
+pub struct InfiniteLoopAnalysis {
+    pub ctx: ContextNode,
+    pub loop_span: LocStrSpan,
+}
+
+impl ReportDisplay for InfiniteLoopAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Infinite loop", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Potentially infinite loop in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.loop_span.clone())
+            .with_message(
+                "Loop condition is always true and the loop body never breaks, returns or reverts"
+                    .fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.loop_span
+    }
+}
+
+/// A loop bounded by the length of a dynamic array that nothing caps, whose gas cost grows as
+/// the array does
+#[derive(Debug, Clone)]
+pub struct UnboundedLoopAnalysis {
+    pub ctx: ContextNode,
+    pub loop_span: LocStrSpan,
+}
+
+impl ReportDisplay for UnboundedLoopAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Unbounded loop", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Loop over an uncapped dynamic array in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.loop_span.clone())
+            .with_message(
+                "Loop is bounded by an array length that can grow until the loop runs out of gas"
+                    .fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.loop_span
+    }
+}
+
+/// A for loop whose counter is incremented past the maximum of its type before the loop
+/// condition fails, so the increment reverts (or wraps when unchecked) instead of ending the loop
+#[derive(Debug, Clone)]
+pub struct LoopCounterOverflowAnalysis {
+    pub ctx: ContextNode,
+    pub increment_span: LocStrSpan,
+    pub counter_name: String,
+    pub counter_ty: String,
+}
+
+impl ReportDisplay for LoopCounterOverflowAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Loop counter overflow", Color::Red)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Loop counter overflows in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.increment_span.clone())
+            .with_message(
+                format!(
+                    "The loop condition holds for \"{}\" at the maximum of {}, so this increment overflows",
+                    self.counter_name, self.counter_ty
+                )
+                .fg(Color::Red),
+            )
+            .with_color(Color::Red)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.increment_span
+    }
+}
+
+impl<T> InfiniteLoopAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait InfiniteLoopAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all loops in the context (and its subcontexts) whose condition is always true
+    /// and whose body has no `break`, `return` or revert
+    fn infinite_loops(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<InfiniteLoopAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .filter_map(|loop_ctx| {
+                let loc = loop_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::InfiniteLoop)
+                    .first()?
+                    .loc();
+                Some(InfiniteLoopAnalysis {
+                    ctx: loop_ctx,
+                    loop_span: LocStrSpan::new(file_mapping, loc),
+                })
+            })
+            .collect()
+    }
+}
+
+impl<T> UnboundedLoopAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait UnboundedLoopAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all loops in the context (and its subcontexts) bounded by the length of a dynamic
+    /// array that no prior `require` caps
+    fn unbounded_loops(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<UnboundedLoopAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .filter_map(|loop_ctx| {
+                let loc = loop_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::UnboundedLoop)
+                    .first()?
+                    .loc();
+                Some(UnboundedLoopAnalysis {
+                    ctx: loop_ctx,
+                    loop_span: LocStrSpan::new(file_mapping, loc),
+                })
+            })
+            .collect()
+    }
+}
+
+impl<T> LoopCounterOverflowAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait LoopCounterOverflowAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all for loops in the context (and its subcontexts) whose counter increment
+    /// overflows before the loop condition can end the loop
+    fn loop_counter_overflows(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<LoopCounterOverflowAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .filter_map(|loop_ctx| {
+                let (loc, counter) = match loop_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::LoopCounterOverflow)
+                    .first()?
+                {
+                    ContextFinding::LoopCounterOverflow(loc, counter) => (*loc, *counter),
+                    _ => return None,
+                };
+                Some(LoopCounterOverflowAnalysis {
+                    ctx: loop_ctx,
+                    increment_span: LocStrSpan::new(file_mapping, loc),
+                    counter_name: counter.display_name(self),
+                    counter_ty: counter.underlying(self).ty.as_string(self),
+                })
+            })
+            .collect()
+    }
+}
@@ -1,18 +1,109 @@
+pub mod access_control;
+use access_control::*;
+pub mod balances;
+use balances::*;
 pub mod bounds;
 use bounds::*;
+pub mod branches;
+use branches::*;
+pub mod casts;
+use casts::*;
+pub mod cei;
+use cei::*;
+pub mod chained_cmps;
+use chained_cmps::*;
+pub mod constants;
+use constants::*;
+pub mod events;
+use events::*;
+pub mod findings;
+use findings::Severity;
+pub mod fixed_point;
+use fixed_point::*;
+pub mod indexes;
+use indexes::*;
+pub mod loops;
+use loops::*;
+pub mod mutability;
+use mutability::*;
+pub mod packed_hashes;
+use packed_hashes::*;
+pub mod precision;
+use precision::*;
+pub mod recursion;
+use recursion::*;
+pub mod registry;
+pub mod requires;
+use requires::*;
+pub mod taint;
+use taint::*;
+pub mod underflows;
+use underflows::*;
+pub mod unused_returns;
+use unused_returns::*;
 
 use crate::AnalyzerLike;
-use ariadne::{Cache, Label, Report, ReportKind, Span};
+use ariadne::{Cache, Config, Label, Report, ReportKind, Span};
 use shared::analyzer::Search;
 use solang_parser::pt::Loc;
 use std::collections::BTreeMap;
 
 pub trait ContextAnalyzer:
-    AnalyzerLike + Search + BoundAnalyzer + FunctionVarsBoundAnalyzer
+    AnalyzerLike
+    + Search
+    + BoundAnalyzer
+    + FunctionVarsBoundAnalyzer
+    + InfiniteLoopAnalyzer
+    + UnboundedLoopAnalyzer
+    + LoopCounterOverflowAnalyzer
+    + MutabilityAnalyzer
+    + CastAnalyzer
+    + EventArgAnalyzer
+    + RecursionAnalyzer
+    + BranchBudgetAnalyzer
+    + CeiAnalyzer
+    + FailableRequireAnalyzer
+    + UnusedReturnAnalyzer
+    + PackedHashAnalyzer
+    + UnderflowAnalyzer
+    + ChainedComparisonAnalyzer
+    + PrecisionLossAnalyzer
+    + OutOfBoundsIndexAnalyzer
+    + OverTransferAnalyzer
+    + StrictBalanceAnalyzer
+    + MissingAccessControlAnalyzer
+    + FixedPointAnalyzer
+    + ConstantResultAnalyzer
+    + TaintAnalyzer
 {
 }
 impl<T> ContextAnalyzer for T where
-    T: AnalyzerLike + Search + BoundAnalyzer + FunctionVarsBoundAnalyzer
+    T: AnalyzerLike
+        + Search
+        + BoundAnalyzer
+        + FunctionVarsBoundAnalyzer
+        + InfiniteLoopAnalyzer
+        + UnboundedLoopAnalyzer
+        + LoopCounterOverflowAnalyzer
+        + MutabilityAnalyzer
+        + CastAnalyzer
+        + EventArgAnalyzer
+        + RecursionAnalyzer
+        + BranchBudgetAnalyzer
+        + CeiAnalyzer
+        + FailableRequireAnalyzer
+        + UnusedReturnAnalyzer
+        + PackedHashAnalyzer
+        + UnderflowAnalyzer
+        + ChainedComparisonAnalyzer
+        + PrecisionLossAnalyzer
+        + OutOfBoundsIndexAnalyzer
+        + OverTransferAnalyzer
+        + StrictBalanceAnalyzer
+        + MissingAccessControlAnalyzer
+        + FixedPointAnalyzer
+        + ConstantResultAnalyzer
+        + TaintAnalyzer
 {
 }
 
@@ -150,9 +241,54 @@ impl Default for ReportConfig {
 
 pub trait ReportDisplay {
     fn report_kind(&self) -> ReportKind;
+    /// How severe a finding of this report is, used to filter findings
+    fn severity(&self) -> Severity;
     fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String;
     fn labels(&self, analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>>;
-    fn reports(&self, analyzer: &(impl AnalyzerLike + Search)) -> Vec<Report<LocStrSpan>>;
-    fn print_reports(&self, src: &mut impl Cache<String>, analyzer: &(impl AnalyzerLike + Search));
-    fn eprint_reports(&self, src: &mut impl Cache<String>, analyzer: &(impl AnalyzerLike + Search));
+    /// Where the report built by the default `reports` starts
+    fn report_span(&self) -> &LocStrSpan;
+    /// A note added to the bottom of the report built by the default `reports`
+    fn note(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Option<String> {
+        None
+    }
+
+    fn reports(&self, analyzer: &(impl AnalyzerLike + Search)) -> Vec<Report<LocStrSpan>> {
+        let span = self.report_span();
+        let mut report = Report::build(self.report_kind(), span.source(), span.start())
+            .with_message(self.msg(analyzer))
+            .with_config(
+                Config::default()
+                    .with_cross_gap(false)
+                    .with_underlines(true)
+                    .with_tab_width(4),
+            );
+
+        report.add_labels(self.labels(analyzer));
+        if let Some(note) = self.note(analyzer) {
+            report.set_note(note);
+        }
+        vec![report.finish()]
+    }
+
+    fn print_reports(
+        &self,
+        mut src: &mut impl Cache<String>,
+        analyzer: &(impl AnalyzerLike + Search),
+    ) {
+        let reports = self.reports(analyzer);
+        reports.into_iter().for_each(|report| {
+            report.print(&mut src).unwrap();
+        });
+    }
+
+    fn eprint_reports(
+        &self,
+        mut src: &mut impl Cache<String>,
+        analyzer: &(impl AnalyzerLike + Search),
+    ) {
+        let reports = self.reports(analyzer);
+        reports.into_iter().for_each(|report| {
+            report.eprint(&mut src).unwrap();
+        });
+    }
 }
@@ -0,0 +1,170 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+    Edge, Node,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use solang_parser::pt::{Loc, Mutability};
+use std::collections::BTreeMap;
+
+/// Violations of a function's declared state mutability
+#[derive(Debug, Clone)]
+pub struct MutabilityAnalysis {
+    pub ctx: ContextNode,
+    pub func_span: LocStrSpan,
+    pub mutability: String,
+    /// Locations of storage writes and the name of the written variable
+    pub storage_writes: Vec<(LocStrSpan, String)>,
+    /// Locations of storage reads and the name of the read variable. Only populated for `pure` functions
+    pub storage_reads: Vec<(LocStrSpan, String)>,
+    /// Locations of calls to external functions that may modify state and the name of the called function
+    pub state_changing_calls: Vec<(LocStrSpan, String)>,
+}
+
+impl MutabilityAnalysis {
+    /// Whether the function broke its declared state mutability
+    pub fn has_violations(&self) -> bool {
+        !self.storage_writes.is_empty()
+            || !self.storage_reads.is_empty()
+            || !self.state_changing_calls.is_empty()
+    }
+}
+
+impl ReportDisplay for MutabilityAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Mutability", Color::Red)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "State mutability violations in {} declared as {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan),
+            self.mutability.clone().fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        let mut labels: Vec<_> = self
+            .storage_writes
+            .iter()
+            .map(|(span, name)| {
+                Label::new(span.clone())
+                    .with_message(format!("Writes to storage variable \"{name}\"").fg(Color::Red))
+                    .with_color(Color::Red)
+            })
+            .collect();
+
+        labels.extend(self.storage_reads.iter().map(|(span, name)| {
+            Label::new(span.clone())
+                .with_message(format!("Reads storage variable \"{name}\"").fg(Color::Yellow))
+                .with_color(Color::Yellow)
+        }));
+
+        labels.extend(self.state_changing_calls.iter().map(|(span, name)| {
+            Label::new(span.clone())
+                .with_message(
+                    format!("Calls state changing external function \"{name}\"").fg(Color::Red),
+                )
+                .with_color(Color::Red)
+        }));
+        labels
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.func_span
+    }
+}
+
+impl<T> MutabilityAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait MutabilityAnalyzer: Search + AnalyzerLike + Sized {
+    /// Checks that a `view` or `pure` function doesn't write to storage or call state changing
+    /// external functions, and that a `pure` function doesn't read storage. Returns `None` if the
+    /// function isn't `view` or `pure` or if it has no violations
+    fn mutability_violations(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Option<MutabilityAnalysis> {
+        let func = ctx.associated_fn(self);
+        let (is_pure, mutability) = match func.mutability(self)? {
+            Mutability::Pure(_) => (true, "pure"),
+            Mutability::View(_) => (false, "view"),
+            Mutability::Constant(_) => (false, "constant"),
+            Mutability::Payable(_) => return None,
+        };
+
+        let storage_writes = self
+            .search_children(ctx.into(), &Edge::Context(ContextEdge::StorageAssign))
+            .into_iter()
+            .map(ContextVarNode::from)
+            .map(|var| {
+                (
+                    LocStrSpan::new(
+                        file_mapping,
+                        var.underlying(self).loc.unwrap_or(Loc::Implicit),
+                    ),
+                    var.display_name(self),
+                )
+            })
+            .collect();
+
+        let storage_reads = if is_pure {
+            let mut reads: Vec<_> = self
+                .search_children(ctx.into(), &Edge::Context(ContextEdge::Variable))
+                .into_iter()
+                .map(ContextVarNode::from)
+                .filter(|var| var.is_storage(self))
+                .map(|var| {
+                    (
+                        LocStrSpan::new(
+                            file_mapping,
+                            var.underlying(self).loc.unwrap_or(Loc::Implicit),
+                        ),
+                        var.display_name(self),
+                    )
+                })
+                .collect();
+            reads.sort_by(|a, b| a.1.cmp(&b.1));
+            reads.dedup_by(|a, b| a.1 == b.1);
+            reads
+        } else {
+            vec![]
+        };
+
+        let state_changing_calls = ctx
+            .subcontexts(self)
+            .into_iter()
+            .filter(|subctx| matches!(self.node(*subctx), Node::Context(_)))
+            .filter_map(|subctx| {
+                let ext_fn = subctx.underlying(self).ext_fn_call?;
+                if ext_fn.is_view_or_pure(self) {
+                    None
+                } else {
+                    Some((
+                        LocStrSpan::new(file_mapping, subctx.underlying(self).loc),
+                        ext_fn.name(self),
+                    ))
+                }
+            })
+            .collect();
+
+        let analysis = MutabilityAnalysis {
+            ctx,
+            func_span: LocStrSpan::new(file_mapping, func.underlying(self).loc),
+            mutability: mutability.to_string(),
+            storage_writes,
+            storage_reads,
+            state_changing_calls,
+        };
+
+        if analysis.has_violations() {
+            Some(analysis)
+        } else {
+            None
+        }
+    }
+}
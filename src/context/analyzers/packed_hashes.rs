@@ -0,0 +1,73 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// A hash of an `abi.encodePacked` of multiple dynamically sized values, whose boundaries are
+/// ambiguous so different inputs can produce the same hash
+#[derive(Debug, Clone)]
+pub struct PackedHashCollisionAnalysis {
+    pub ctx: ContextNode,
+    pub encode_span: LocStrSpan,
+}
+
+impl ReportDisplay for PackedHashCollisionAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Hash Collision", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Hash of packed dynamic values can collide in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.encode_span.clone())
+            .with_message(
+                "Multiple dynamically sized values are packed without their lengths, use \
+                 abi.encode instead"
+                    .fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.encode_span
+    }
+}
+
+impl<T> PackedHashAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait PackedHashAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all `keccak256(abi.encodePacked(..))` in the context (and its subcontexts) where
+    /// two or more of the packed values are dynamically sized
+    fn packed_hash_collisions(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<PackedHashCollisionAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|hash_ctx| {
+                hash_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::PackedHashCollision)
+                    .iter()
+                    .map(ContextFinding::loc)
+                    .map(|loc| PackedHashCollisionAnalysis {
+                        ctx: hash_ctx,
+                        encode_span: LocStrSpan::new(file_mapping, loc),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
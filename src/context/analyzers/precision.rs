@@ -0,0 +1,72 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// A multiplication of a division result, like `a / b * c`, which loses the remainder of the
+/// division before multiplying
+#[derive(Debug, Clone)]
+pub struct DivideBeforeMultiplyAnalysis {
+    pub ctx: ContextNode,
+    pub mul_span: LocStrSpan,
+}
+
+impl ReportDisplay for DivideBeforeMultiplyAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Precision Loss", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Division before multiplication in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.mul_span.clone())
+            .with_message(
+                "The division truncates before multiplying, multiply first to keep precision"
+                    .fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.mul_span
+    }
+}
+
+impl<T> PrecisionLossAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait PrecisionLossAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all multiplications in the context (and its subcontexts) with an operand that is
+    /// the result of a division that may truncate
+    fn divide_before_multiply(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<DivideBeforeMultiplyAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|mul_ctx| {
+                mul_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::DivideBeforeMultiply)
+                    .iter()
+                    .map(ContextFinding::loc)
+                    .map(|loc| DivideBeforeMultiplyAnalysis {
+                        ctx: mul_ctx,
+                        mul_span: LocStrSpan::new(file_mapping, loc),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
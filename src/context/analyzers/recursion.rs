@@ -0,0 +1,70 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// A recursive call that exceeded the maximum call depth and so was approximated
+#[derive(Debug, Clone)]
+pub struct RecursionAnalysis {
+    pub ctx: ContextNode,
+    pub call_span: LocStrSpan,
+}
+
+impl ReportDisplay for RecursionAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Recursion", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Recursion depth limit reached in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.call_span.clone())
+            .with_message(
+                "Recursive call was not inlined, its returns are the full range of their types"
+                    .fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.call_span
+    }
+}
+
+impl<T> RecursionAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait RecursionAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all recursive calls in the context (and its subcontexts) that exceeded the maximum
+    /// call depth and whose returns were approximated
+    fn approximated_recursion(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<RecursionAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .filter_map(|call_ctx| {
+                let loc = call_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::RecursionLimited)
+                    .first()?
+                    .loc();
+                Some(RecursionAnalysis {
+                    ctx: call_ctx,
+                    call_span: LocStrSpan::new(file_mapping, loc),
+                })
+            })
+            .collect()
+    }
+}
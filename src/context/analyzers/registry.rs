@@ -0,0 +1,236 @@
+use crate::analyzers::{findings::Finding, ContextAnalyzer, ReportConfig};
+use shared::context::ContextNode;
+
+use std::collections::BTreeMap;
+
+/// A check run on a function's body context, producing findings
+pub trait ContextLint<T> {
+    /// The name used to enable or disable the lint in an `AnalyzerRegistry`
+    fn name(&self) -> &str;
+    fn run(
+        &self,
+        analyzer: &T,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<Finding>;
+}
+
+/// A set of lints, each of which can be enabled or disabled by name
+pub struct AnalyzerRegistry<T> {
+    lints: Vec<(Box<dyn ContextLint<T>>, bool)>,
+}
+
+impl<T> AnalyzerRegistry<T> {
+    /// Creates a registry with no lints
+    pub fn empty() -> Self {
+        Self { lints: vec![] }
+    }
+
+    /// Adds an enabled lint to the registry, replacing any lint with the same name
+    pub fn register(&mut self, lint: impl ContextLint<T> + 'static) {
+        self.lints
+            .retain(|(existing, _)| existing.name() != lint.name());
+        self.lints.push((Box::new(lint), true));
+    }
+
+    /// Enables the named lint, returning false if no such lint is registered
+    pub fn enable(&mut self, name: &str) -> bool {
+        self.set_enabled(name, true)
+    }
+
+    /// Disables the named lint, returning false if no such lint is registered
+    pub fn disable(&mut self, name: &str) -> bool {
+        self.set_enabled(name, false)
+    }
+
+    fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        match self.lints.iter_mut().find(|(lint, _)| lint.name() == name) {
+            Some((_, is_enabled)) => {
+                *is_enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.lints
+            .iter()
+            .any(|(lint, enabled)| *enabled && lint.name() == name)
+    }
+
+    /// The names of all registered lints, in registration order
+    pub fn names(&self) -> Vec<&str> {
+        self.lints.iter().map(|(lint, _)| lint.name()).collect()
+    }
+
+    /// The enabled lints, in registration order
+    pub fn enabled(&self) -> impl Iterator<Item = &dyn ContextLint<T>> {
+        self.lints
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(lint, _)| lint.as_ref())
+    }
+}
+
+/// A lint backed by a function, which is how the built-in analyses are registered
+pub struct FnLint<T> {
+    pub name: &'static str,
+    pub run: fn(&T, &BTreeMap<usize, String>, ContextNode) -> Vec<Finding>,
+}
+
+impl<T> ContextLint<T> for FnLint<T> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn run(
+        &self,
+        analyzer: &T,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<Finding> {
+        (self.run)(analyzer, file_mapping, ctx)
+    }
+}
+
+/// A `FnLint` running one of the analyses of `ContextAnalyzer` on the registry's analyzer type `T`,
+/// with each finding located at the given span of its analysis
+macro_rules! fn_lint {
+    ($name:literal, $analysis:ident, $span:ident) => {
+        FnLint {
+            name: $name,
+            run: |analyzer: &T, file_mapping, ctx| {
+                analyzer
+                    .$analysis(file_mapping, ctx)
+                    .iter()
+                    .map(|analysis| Finding::new(analysis, analysis.$span.clone(), analyzer))
+                    .collect()
+            },
+        }
+    };
+}
+
+impl<T: ContextAnalyzer> Default for AnalyzerRegistry<T> {
+    /// A registry with all built-in lints enabled
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        // flags loops that can never exit
+        registry.register(fn_lint!("infinite-loop", infinite_loops, loop_span));
+        // flags loops over dynamic arrays whose length nothing caps
+        registry.register(fn_lint!("unbounded-loop", unbounded_loops, loop_span));
+        // flags for loops whose counter increment overflows before the loop condition fails
+        registry.register(fn_lint!(
+            "loop-counter-overflow",
+            loop_counter_overflows,
+            increment_span
+        ));
+        // flags view and pure functions that read or write state they shouldn't
+        registry.register(fn_lint!("mutability", mutability_violations, func_span));
+        // flags casts that may truncate their input
+        registry.register(fn_lint!("unsafe-cast", unsafe_casts, cast_span));
+        // flags arguments of emitted events that may not fit the type of their event parameter
+        registry.register(fn_lint!(
+            "event-arg-mismatch",
+            event_arg_mismatches,
+            arg_span
+        ));
+        // flags recursive calls that were approximated due to the call depth limit
+        registry.register(fn_lint!("recursion", approximated_recursion, call_span));
+        // flags branches that were merged due to the branch budget
+        registry.register(fn_lint!("branch-budget", merged_branches, branch_span));
+        // flags storage writes that follow an external call
+        registry.register(fn_lint!("cei", cei_violations, call_span));
+        // flags requires that can revert. Only produces findings when requires are treated strictly
+        registry.register(fn_lint!(
+            "failable-require",
+            failable_requires,
+            require_span
+        ));
+        // flags asserts that can fail, as an error since a failing assert is a bug
+        registry.register(fn_lint!("failable-assert", failable_asserts, require_span));
+        // flags requires whose condition always holds, i.e. duplicates of an earlier check
+        registry.register(fn_lint!(
+            "redundant-require",
+            redundant_requires,
+            require_span
+        ));
+        // flags function calls made as statements whose returns are ignored
+        registry.register(fn_lint!("unused-return", unused_returns, call_span));
+        // flags hashes of packed encodings with multiple dynamically sized values
+        registry.register(fn_lint!(
+            "packed-hash-collision",
+            packed_hash_collisions,
+            encode_span
+        ));
+        // flags checked unsigned subtractions that can underflow
+        registry.register(fn_lint!("underflow", possible_underflows, sub_span));
+        // flags comparisons of boolean comparison results, like `a < b < c`
+        registry.register(fn_lint!(
+            "chained-comparison",
+            chained_comparisons,
+            cmp_span
+        ));
+        // flags multiplications of division results, like `a / b * c`
+        registry.register(fn_lint!(
+            "divide-before-multiply",
+            divide_before_multiply,
+            mul_span
+        ));
+        // flags ternary array indexes, like `arr[c ? i : j]`, with a branch that may be out of
+        // bounds
+        registry.register(fn_lint!(
+            "index-out-of-bounds",
+            out_of_bounds_indexes,
+            index_span
+        ));
+        // flags `transfer`s and `send`s whose amount may exceed the contract's balance
+        registry.register(fn_lint!("over-transfer", over_transfers, transfer_span));
+        // flags strict equality comparisons against the contract's balance, which can be broken by
+        // force-sending ether
+        registry.register(fn_lint!(
+            "strict-balance-equality",
+            strict_balance_checks,
+            cmp_span
+        ));
+        // flags public and external functions that change state without any check on who called
+        // them. This is a heuristic, so its findings are only warnings
+        registry.register(fn_lint!(
+            "missing-access-control",
+            missing_access_control,
+            func_span
+        ));
+        // notes `fixed` and `ufixed` variables, whose ranges are only placeholders
+        registry.register(fn_lint!("fixed-point", fixed_point_vars, var_span));
+        // flags arithmetic and bitwise operations that always produce the same value, like `x * 0`
+        registry.register(fn_lint!("constant-result", constant_results, op_span));
+        // flags sinks, like the target of a `delegatecall`, that receive untrusted input
+        registry.register(fn_lint!("tainted-sink", tainted_sinks, sink_span));
+        // the bounds of every variable describe the function rather than a problem in it, so
+        // they are only collected once enabled, as the cli does
+        registry.register(BoundsLint::default());
+        registry.disable("bounds");
+        registry
+    }
+}
+
+/// Reports the bounds of the variables of each function, displayed with the report config
+#[derive(Default)]
+pub struct BoundsLint {
+    pub report_config: ReportConfig,
+}
+impl<T: ContextAnalyzer> ContextLint<T> for BoundsLint {
+    fn name(&self) -> &str {
+        "bounds"
+    }
+
+    fn run(
+        &self,
+        analyzer: &T,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<Finding> {
+        let analysis = analyzer.bounds_for_all(file_mapping, ctx, self.report_config);
+        vec![Finding::new(&analysis, analysis.ctx_loc.clone(), analyzer)]
+    }
+}
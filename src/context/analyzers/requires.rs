@@ -0,0 +1,173 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A `require` whose condition can fail, found when requires are treated strictly, or an
+/// `assert` whose condition can fail. A failing `assert` panics, which is always a bug
+#[derive(Debug, Clone)]
+pub struct FailableRequireAnalysis {
+    pub ctx: ContextNode,
+    pub require_span: LocStrSpan,
+    pub is_assert: bool,
+}
+
+impl FailableRequireAnalysis {
+    fn color(&self) -> Color {
+        if self.is_assert {
+            Color::Red
+        } else {
+            Color::Yellow
+        }
+    }
+}
+
+impl ReportDisplay for FailableRequireAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        if self.is_assert {
+            ReportKind::Custom("Failable Assert", self.color())
+        } else {
+            ReportKind::Custom("Failable Require", self.color())
+        }
+    }
+    fn severity(&self) -> Severity {
+        if self.is_assert {
+            Severity::Error
+        } else {
+            Severity::Warning
+        }
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "{} can revert in {}",
+            if self.is_assert { "Assert" } else { "Require" },
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.require_span.clone())
+            .with_message("The condition can be false for some inputs".fg(self.color()))
+            .with_color(self.color())]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.require_span
+    }
+}
+
+/// A `require` whose condition always holds given the ranges at that point, i.e. a duplicate of
+/// or a condition implied by an earlier check, which makes it dead code
+#[derive(Debug, Clone)]
+pub struct RedundantRequireAnalysis {
+    pub ctx: ContextNode,
+    pub require_span: LocStrSpan,
+}
+
+impl ReportDisplay for RedundantRequireAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Redundant Require", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Require can never revert in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.require_span.clone())
+            .with_message("The condition is already guaranteed here".fg(Color::Yellow))
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.require_span
+    }
+}
+
+impl<T> FailableRequireAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait FailableRequireAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all `require` statements in the context (and its subcontexts) whose condition can
+    /// fail. Empty unless the analyzer treats requires strictly
+    fn failable_requires(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<FailableRequireAnalysis> {
+        self.failable_conditions(file_mapping, ctx, false)
+    }
+
+    /// Finds all `assert` statements in the context (and its subcontexts) whose condition can fail
+    fn failable_asserts(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<FailableRequireAnalysis> {
+        self.failable_conditions(file_mapping, ctx, true)
+    }
+
+    /// Finds all `require` statements in the context (and its subcontexts) whose condition
+    /// always holds. A require reached by multiple paths is reported once
+    fn redundant_requires(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<RedundantRequireAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        let mut seen = BTreeSet::new();
+        ctxs.into_iter()
+            .flat_map(|require_ctx| {
+                require_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::RedundantRequire)
+                    .iter()
+                    .map(ContextFinding::loc)
+                    .map(move |loc| (require_ctx, loc))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|(_, loc)| seen.insert(*loc))
+            .map(|(require_ctx, loc)| RedundantRequireAnalysis {
+                ctx: require_ctx,
+                require_span: LocStrSpan::new(file_mapping, loc),
+            })
+            .collect()
+    }
+
+    fn failable_conditions(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+        asserts: bool,
+    ) -> Vec<FailableRequireAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|require_ctx| {
+                let kind = if asserts {
+                    FindingKind::FailableAssert
+                } else {
+                    FindingKind::FailableRequire
+                };
+                require_ctx
+                    .underlying(self)
+                    .findings_of(kind)
+                    .iter()
+                    .map(|finding| FailableRequireAnalysis {
+                        ctx: require_ctx,
+                        require_span: LocStrSpan::new(file_mapping, finding.loc()),
+                        is_assert: asserts,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
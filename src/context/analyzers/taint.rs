@@ -0,0 +1,88 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// A sink that receives a value derived from input an attacker may control
+#[derive(Debug, Clone)]
+pub struct TaintAnalysis {
+    pub ctx: ContextNode,
+    pub sink_span: LocStrSpan,
+    pub sink: TaintSink,
+    pub var_name: String,
+    pub source: String,
+}
+
+impl ReportDisplay for TaintAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Tainted sink", Color::Red)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Untrusted input reaches a {} in {}",
+            self.sink.as_str(),
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.sink_span.clone())
+            .with_message(
+                format!(
+                    "\"{}\" is used as the {} and derives from {}",
+                    self.var_name,
+                    self.sink.as_str(),
+                    self.source
+                )
+                .fg(Color::Red),
+            )
+            .with_color(Color::Red)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.sink_span
+    }
+}
+
+impl<T> TaintAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait TaintAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all sinks in the context (and its subcontexts) that receive a value derived from a
+    /// parameter of an external function, `msg.data` or the returndata of an external call
+    fn tainted_sinks(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<TaintAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|sink_ctx| {
+                sink_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::TaintedSink)
+                    .iter()
+                    .filter_map(|finding| match finding {
+                        ContextFinding::TaintedSink(loc, sink, var) => Some(TaintAnalysis {
+                            ctx: sink_ctx,
+                            sink_span: LocStrSpan::new(file_mapping, *loc),
+                            sink: *sink,
+                            var_name: var.display_name(self),
+                            source: var
+                                .metadata::<Taint>(self)
+                                .map(|taint| taint.source.clone())
+                                .unwrap_or_else(|| "untrusted input".to_string()),
+                        }),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,91 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// A checked unsigned subtraction whose rhs can be larger than its lhs, which reverts
+#[derive(Debug, Clone)]
+pub struct UnderflowAnalysis {
+    pub ctx: ContextNode,
+    pub sub_span: LocStrSpan,
+    pub lhs_name: String,
+    pub rhs_name: String,
+    /// The conditions of the branches leading to the subtraction, if it is inside of a branch
+    pub path_condition: Option<String>,
+}
+
+impl ReportDisplay for UnderflowAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Underflow", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Subtraction can underflow in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn note(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Option<String> {
+        self.path_condition
+            .as_ref()
+            .map(|path_condition| format!("Only when {path_condition}"))
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.sub_span.clone())
+            .with_message(
+                format!(
+                    "\"{}\" can be larger than \"{}\", consider checking that \"{} >= {}\" first",
+                    self.rhs_name, self.lhs_name, self.lhs_name, self.rhs_name
+                )
+                .fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.sub_span
+    }
+}
+
+impl<T> UnderflowAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait UnderflowAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all checked unsigned subtractions in the context (and its subcontexts) whose rhs
+    /// maximum is larger than their lhs minimum
+    fn possible_underflows(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<UnderflowAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|sub_ctx| {
+                sub_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::PossibleUnderflow)
+                    .iter()
+                    .filter_map(|finding| match finding {
+                        ContextFinding::PossibleUnderflow(loc, lhs, rhs) => {
+                            Some(UnderflowAnalysis {
+                                ctx: sub_ctx,
+                                sub_span: LocStrSpan::new(file_mapping, *loc),
+                                lhs_name: lhs.display_name(self),
+                                rhs_name: rhs.display_name(self),
+                                path_condition: sub_ctx.path_condition(self),
+                            })
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,88 @@
+use crate::analyzers::{findings::Severity, LocStrSpan, ReportDisplay};
+use shared::{
+    analyzer::{AnalyzerLike, AsDotStr, Search},
+    context::*,
+};
+
+use ariadne::{Color, Fmt, Label, ReportKind};
+use std::collections::BTreeMap;
+
+/// A function call made as a statement whose returns are ignored
+#[derive(Debug, Clone)]
+pub struct UnusedReturnAnalysis {
+    pub ctx: ContextNode,
+    pub call_span: LocStrSpan,
+    /// The name of the called function
+    pub call_name: String,
+    /// The types of the ignored returns
+    pub return_tys: Vec<String>,
+}
+
+impl ReportDisplay for UnusedReturnAnalysis {
+    fn report_kind(&self) -> ReportKind {
+        ReportKind::Custom("Unused Return", Color::Yellow)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
+        format!(
+            "Return value of a call is ignored in {}",
+            format!("function {}", self.ctx.associated_fn_name(analyzer)).fg(Color::Cyan)
+        )
+    }
+
+    fn labels(&self, _analyzer: &(impl AnalyzerLike + Search)) -> Vec<Label<LocStrSpan>> {
+        vec![Label::new(self.call_span.clone())
+            .with_message(
+                format!(
+                    "\"{}\" returns ({}), which is never used",
+                    self.call_name,
+                    self.return_tys.join(", ")
+                )
+                .fg(Color::Yellow),
+            )
+            .with_color(Color::Yellow)]
+    }
+
+    fn report_span(&self) -> &LocStrSpan {
+        &self.call_span
+    }
+}
+
+impl<T> UnusedReturnAnalyzer for T where T: Search + AnalyzerLike + Sized {}
+pub trait UnusedReturnAnalyzer: Search + AnalyzerLike + Sized {
+    /// Finds all function calls made as statements in the context (and its subcontexts) whose
+    /// returns are never bound to a variable or otherwise used. Calls to functions that return
+    /// nothing are not reported
+    fn unused_returns(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Vec<UnusedReturnAnalysis> {
+        let ctxs = ctx.self_and_subcontexts(self);
+
+        ctxs.into_iter()
+            .flat_map(|call_ctx| {
+                call_ctx
+                    .underlying(self)
+                    .findings_of(FindingKind::UnusedReturn)
+                    .iter()
+                    .filter_map(|finding| match finding {
+                        ContextFinding::UnusedReturn(loc, func) => Some(UnusedReturnAnalysis {
+                            ctx: call_ctx,
+                            call_span: LocStrSpan::new(file_mapping, *loc),
+                            call_name: func.name(self),
+                            return_tys: func
+                                .returns(self)
+                                .iter()
+                                .map(|ret| ret.as_dot_str(self))
+                                .collect(),
+                        }),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
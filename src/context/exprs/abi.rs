@@ -0,0 +1,212 @@
+use crate::{context::ContextBuilder, ExprRet};
+use shared::{analyzer::AnalyzerLike, context::*, nodes::*, range::elem_ty::Elem, Edge, Node};
+use solang_parser::pt::{Expression, Identifier, Loc};
+
+impl<T> Abi for T where T: AnalyzerLike<Expr = Expression> + Sized {}
+
+pub trait Abi: AnalyzerLike<Expr = Expression> + Sized {
+    /// Handles a call to a member of `abi`, i.e. `abi.decode(data, (uint256, bool))`
+    fn abi_call(
+        &mut self,
+        loc: Loc,
+        func_name: &str,
+        input_exprs: &[Expression],
+        ctx: ContextNode,
+    ) -> ExprRet {
+        match func_name {
+            "decode" => {
+                // nothing is known about the encoded data, but it may have side effects
+                if let ExprRet::CtxKilled = self.parse_ctx_expr(&input_exprs[0], ctx) {
+                    return ExprRet::CtxKilled;
+                }
+                self.abi_decode_tys(loc, &input_exprs[1], ctx)
+            }
+            "encodePacked" => self.abi_encode_packed(loc, input_exprs, ctx).0,
+            "encode" | "encodeWithSelector" | "encodeWithSignature" => {
+                for expr in input_exprs.iter() {
+                    if let ExprRet::CtxKilled = self.parse_ctx_expr(expr, ctx) {
+                        return ExprRet::CtxKilled;
+                    }
+                }
+                self.abi_encoded_bytes(loc, func_name, ctx)
+            }
+            "encodeCall" => {
+                // the first input is the called function, only the arguments tuple is evaluated
+                let args = match input_exprs.get(1) {
+                    Some(Expression::List(_, params)) => params
+                        .iter()
+                        .filter_map(|(_, param)| param.as_ref().map(|param| param.ty.clone()))
+                        .collect::<Vec<_>>(),
+                    Some(arg) => vec![arg.clone()],
+                    None => vec![],
+                };
+                for expr in args.iter() {
+                    if let ExprRet::CtxKilled = self.parse_ctx_expr(expr, ctx) {
+                        return ExprRet::CtxKilled;
+                    }
+                }
+                self.abi_encoded_bytes(loc, func_name, ctx)
+            }
+            e => {
+                self.add_diagnostic(loc, format!("Unsupported member abi.{e} was skipped"));
+                ExprRet::Multi(vec![])
+            }
+        }
+    }
+
+    /// Handles `abi.encodePacked`, returning the encoded bytes and the number of dynamically
+    /// sized arguments, which are packed without their lengths
+    fn abi_encode_packed(
+        &mut self,
+        loc: Loc,
+        input_exprs: &[Expression],
+        ctx: ContextNode,
+    ) -> (ExprRet, usize) {
+        let mut dyn_args = 0;
+        // the encoding is known if every argument is constant
+        let mut packed = Some(vec![]);
+        for expr in input_exprs.iter() {
+            match self.parse_ctx_expr(expr, ctx) {
+                ExprRet::CtxKilled => return (ExprRet::CtxKilled, dyn_args),
+                ExprRet::Single((_ctx, var)) | ExprRet::SingleLiteral((_ctx, var)) => {
+                    let var = ContextVarNode::from(var);
+                    let is_dyn = match var.ty(self) {
+                        VarType::Concrete(cnode) => cnode.underlying(self).as_builtin().is_dyn(),
+                        ty => ty.is_dyn_builtin(self),
+                    };
+                    if is_dyn {
+                        dyn_args += 1;
+                    }
+                    packed = packed.and_then(|mut packed: Vec<u8>| {
+                        packed.extend(self.concrete_value(var)?.packed_bytes()?);
+                        Some(packed)
+                    });
+                }
+                _ => packed = None,
+            }
+        }
+        let ret = match packed {
+            Some(packed) => self.concrete_var(loc, Concrete::DynBytes(packed), ctx),
+            None => self.abi_encoded_bytes(loc, "encodePacked", ctx),
+        };
+        (ret, dyn_args)
+    }
+
+    /// The value of a variable that is a literal or whose range is a single concrete value
+    fn concrete_value(&self, var: ContextVarNode) -> Option<Concrete> {
+        if var.is_concrete(self) {
+            Some(var.as_concrete(self))
+        } else if let Some(Elem::Concrete(c)) = self.const_value(var) {
+            Some(c.val)
+        } else {
+            None
+        }
+    }
+
+    /// Creates a variable for a known concrete value
+    fn concrete_var(&mut self, loc: Loc, concrete: Concrete, ctx: ContextNode) -> ExprRet {
+        let concrete_node = ConcreteNode::from(self.add_node(Node::Concrete(concrete)));
+        let var = ContextVar::new_from_concrete(loc, concrete_node, self);
+        let node = self.add_node(Node::ContextVar(var));
+        self.add_edge(node, ctx, Edge::Context(ContextEdge::Variable));
+        ExprRet::Single((ctx, node))
+    }
+
+    /// Creates a variable for the unconstrained bytes returned by an `abi` encoding function
+    fn abi_encoded_bytes(&mut self, loc: Loc, func_name: &str, ctx: ContextNode) -> ExprRet {
+        let bn = self.builtin_or_add(Builtin::DynamicBytes);
+        let mut var = ContextVar::new_from_builtin(loc, bn.into(), self);
+        var.name = format!("tmp{}(abi.{func_name}())", ctx.new_tmp(self));
+        var.display_name = format!("abi.{func_name}()");
+        var.is_tmp = true;
+        var.is_symbolic = true;
+        let node = self.add_node(Node::ContextVar(var));
+        self.add_edge(node, ctx, Edge::Context(ContextEdge::Variable));
+        ExprRet::Single((ctx, node))
+    }
+
+    /// Parses the input of a hash function. Hashing an `abi.encodePacked` of multiple
+    /// dynamically sized values is recorded, as the boundaries between the values are ambiguous
+    /// and different inputs can collide, i.e. `abi.encodePacked("a", "bc")` and
+    /// `abi.encodePacked("ab", "c")`
+    fn parse_hash_input(&mut self, input_expr: &Expression, ctx: ContextNode) -> ExprRet {
+        if let Expression::FunctionCall(loc, func_expr, args) = input_expr {
+            if let Expression::MemberAccess(_, member_expr, ident) = &**func_expr {
+                if let Expression::Variable(Identifier { name, .. }) = &**member_expr {
+                    if name == "abi" && ident.name == "encodePacked" {
+                        let (ret, dyn_args) = self.abi_encode_packed(*loc, args, ctx);
+                        if dyn_args > 1 {
+                            ctx.underlying_mut(self)
+                                .add_finding(ContextFinding::PackedHashCollision(*loc));
+                        }
+                        return ret;
+                    }
+                }
+            }
+        }
+        self.parse_ctx_expr(input_expr, ctx)
+    }
+
+    /// Creates a variable with the full range of its type for each type in the type tuple of
+    /// `abi.decode`, recursing into nested tuples
+    fn abi_decode_tys(&mut self, loc: Loc, ty_expr: &Expression, ctx: ContextNode) -> ExprRet {
+        match ty_expr {
+            Expression::Parenthesis(_loc, inner) => self.abi_decode_tys(loc, inner, ctx),
+            Expression::List(_loc, params) => ExprRet::Multi(
+                params
+                    .iter()
+                    .filter_map(|(_loc, param)| param.as_ref())
+                    .map(|param| self.abi_decode_tys(loc, &param.ty, ctx))
+                    .collect(),
+            ),
+            ty_expr => {
+                let (ty_ctx, ty) = self.parse_ctx_expr(ty_expr, ctx).expect_single();
+                let ty = VarType::try_from_idx(self, ty).expect("Not a known type");
+                let tmp_num = ctx.new_tmp(self);
+                let var = ContextVar {
+                    loc: Some(loc),
+                    name: format!("tmp{tmp_num}"),
+                    display_name: format!("tmp{tmp_num}"),
+                    storage: None,
+                    is_tmp: true,
+                    is_symbolic: true,
+                    tmp_of: None,
+                    ty: ty.clone(),
+                    metadata: Default::default(),
+                };
+                let node = self.add_node(Node::ContextVar(var));
+                self.add_edge(node, ty_ctx, Edge::Context(ContextEdge::Variable));
+                if let VarType::User(TypeNode::Struct(struct_node)) = ty {
+                    self.abi_decode_fields(loc, node.into(), struct_node, ty_ctx);
+                }
+                ExprRet::Single((ty_ctx, node))
+            }
+        }
+    }
+
+    /// Creates a variable with the full range of its type for each field of a struct decoded by
+    /// `abi.decode`, recursing into nested structs
+    fn abi_decode_fields(
+        &mut self,
+        loc: Loc,
+        decoded: ContextVarNode,
+        struct_node: StructNode,
+        ctx: ContextNode,
+    ) {
+        struct_node.fields(self).into_iter().for_each(|field| {
+            let parent_var = decoded.underlying(self).clone();
+            let field = field.underlying(self).clone();
+            if let Some(field_cvar) =
+                ContextVar::maybe_new_from_field(self, loc, &parent_var, field)
+            {
+                let field_ty = field_cvar.ty.clone();
+                let field_node = self.add_node(Node::ContextVar(field_cvar));
+                self.add_edge(field_node, decoded, Edge::Context(ContextEdge::AttrAccess));
+                self.add_edge(field_node, ctx, Edge::Context(ContextEdge::Variable));
+                if let VarType::User(TypeNode::Struct(inner)) = field_ty {
+                    self.abi_decode_fields(loc, field_node.into(), inner, ctx);
+                }
+            }
+        });
+    }
+}
@@ -1,8 +1,22 @@
 use crate::{
-    context::exprs::{member_access::MemberAccess, require::Require},
-    Builtin, ContextBuilder, Edge, ExprRet, Node, VarType,
+    context::exprs::{
+        cond_op::CondOp,
+        member_access::MemberAccess,
+        require::{Require, RequireKind},
+    },
+    context::taint::TaintTracker,
+    Builtin, Concrete, ContextBuilder, Edge, ExprRet, Node, NodeIdx, VarType,
 };
-use shared::{analyzer::AnalyzerLike, context::*, range::elem::RangeOp};
+use ethers_core::types::U256;
+use shared::{
+    analyzer::AnalyzerLike,
+    context::*,
+    range::{
+        elem::{RangeElem, RangeOp},
+        elem_ty::{Dynamic, Elem},
+    },
+};
+use std::cmp::Ordering;
 
 use solang_parser::pt::{Expression, Loc};
 
@@ -52,6 +66,10 @@ pub trait Array: AnalyzerLike<Expr = Expression> + Sized {
                 let parent = ContextVarNode::from(parent).first_version(self);
                 let len_var = self.tmp_length(parent, ctx, loc).latest_version(self);
                 let index = index.latest_version(self);
+                // mappings are keyed by arbitrary values, only arrays are sensitive to their index
+                if let Some(Builtin::Array(_)) = parent.builtin(self) {
+                    self.check_taint_sink(ctx, loc, TaintSink::ArrayIndex, index);
+                }
                 let idx = self.advance_var_in_ctx(index, loc, ctx);
 
 
@@ -62,6 +80,7 @@ pub trait Array: AnalyzerLike<Expr = Expression> + Sized {
                     RangeOp::Gt,
                     RangeOp::Lt,
                     (RangeOp::Lte, RangeOp::Gte),
+                    RequireKind::Implicit,
                 );
 
                 let name = format!("{}[{}]", parent.name(self), index.name(self));
@@ -83,6 +102,7 @@ pub trait Array: AnalyzerLike<Expr = Expression> + Sized {
                         tmp_of: None,
                         is_symbolic: true,
                         ty: parent.ty(self).clone().array_underlying_ty(self),
+                        metadata: Default::default(),
                     };
 
                     let idx_node = self.add_node(Node::ContextVar(index_var));
@@ -93,7 +113,301 @@ pub trait Array: AnalyzerLike<Expr = Expression> + Sized {
                     ExprRet::Single((ctx, idx_node))
                 }
             }
+            (ExprRet::Single((ctx, parent)), ExprRet::Fork(world1, world2)) => {
+                // each branch of a ternary index is bounds checked in its own fork, where an
+                // always out of bounds branch only kills that fork, so also check their union
+                let mut indices = world1.fork_leaves();
+                indices.extend(world2.fork_leaves());
+                self.check_merged_index(loc, ctx, parent.into(), indices);
+
+                let in_branch = |world: &ExprRet| match world {
+                    ExprRet::Single((fork_ctx, _)) | ExprRet::SingleLiteral((fork_ctx, _)) => {
+                        ExprRet::Single((*fork_ctx, parent))
+                    }
+                    _ => ExprRet::Single((ctx, parent)),
+                };
+                ExprRet::Fork(
+                    Box::new(self.index_into_array_inner(loc, in_branch(&world1), *world1)),
+                    Box::new(self.index_into_array_inner(loc, in_branch(&world2), *world2)),
+                )
+            }
             e => panic!("Expected single expr evaluation of index expression, but was: {e:?}. This is a bug. Please report it at github.com/nascentxyz/pyrometer."),
         }
     }
+
+    /// Records the index if the union of the ranges of a ternary's indices reaches the largest
+    /// length the array can have. Arrays with an unbounded length are skipped
+    fn check_merged_index(
+        &mut self,
+        loc: Loc,
+        ctx: ContextNode,
+        parent: ContextVarNode,
+        indices: Vec<NodeIdx>,
+    ) {
+        let indices: Vec<ContextVarNode> = indices
+            .into_iter()
+            .map(|index| ContextVarNode::from(index).latest_version(self))
+            .collect();
+        if let Some((_, index_max)) = self.union_bounds(&indices) {
+            let parent = parent.first_version(self);
+            let len_var = self.tmp_length(parent, ctx, loc).latest_version(self);
+            if let Some(len_max) = len_var.evaled_range_max(self) {
+                let unbounded = Elem::from(Concrete::from(U256::MAX));
+                if len_max.range_ord(&unbounded) == Some(Ordering::Less)
+                    && matches!(
+                        index_max.range_ord(&len_max),
+                        Some(Ordering::Greater) | Some(Ordering::Equal)
+                    )
+                {
+                    ctx.underlying_mut(self)
+                        .add_finding(ContextFinding::OutOfBoundsIndex(loc));
+                }
+            }
+        }
+    }
+
+    /// Slices an array (i.e. `arr[start:end]`), creating a new array variable with a length of `end - start`.
+    /// A missing start defaults to 0 and a missing end defaults to the length of the array.
+    fn slice_array(
+        &mut self,
+        loc: Loc,
+        arr_expr: &Expression,
+        maybe_start: &Option<Box<Expression>>,
+        maybe_end: &Option<Box<Expression>>,
+        ctx: ContextNode,
+    ) -> ExprRet {
+        let arr_paths = self.parse_ctx_expr(arr_expr, ctx);
+        let start_paths = maybe_start
+            .as_ref()
+            .map(|start_expr| self.parse_ctx_expr(start_expr, ctx));
+        let end_paths = maybe_end
+            .as_ref()
+            .map(|end_expr| self.parse_ctx_expr(end_expr, ctx));
+
+        self.slice_array_inner(loc, arr_paths, start_paths, end_paths)
+    }
+
+    fn slice_array_inner(
+        &mut self,
+        loc: Loc,
+        arr_paths: ExprRet,
+        start_paths: Option<ExprRet>,
+        end_paths: Option<ExprRet>,
+    ) -> ExprRet {
+        let in_branch = |ctx: ContextNode, arr: NodeIdx, world: &ExprRet| match world {
+            ExprRet::Single((fork_ctx, _)) | ExprRet::SingleLiteral((fork_ctx, _)) => {
+                ExprRet::Single((*fork_ctx, arr))
+            }
+            _ => ExprRet::Single((ctx, arr)),
+        };
+        match (arr_paths, start_paths, end_paths) {
+            (ExprRet::CtxKilled, _, _)
+            | (_, Some(ExprRet::CtxKilled), _)
+            | (_, _, Some(ExprRet::CtxKilled)) => ExprRet::CtxKilled,
+            // each branch of a ternary array (i.e. `(c ? a : b)[1:]`) is sliced in its own fork
+            (ExprRet::Fork(world1, world2), start_paths, end_paths) => ExprRet::Fork(
+                Box::new(self.slice_array_inner(
+                    loc,
+                    *world1,
+                    start_paths.clone(),
+                    end_paths.clone(),
+                )),
+                Box::new(self.slice_array_inner(loc, *world2, start_paths, end_paths)),
+            ),
+            (ExprRet::Multi(arrs), start_paths, end_paths) => ExprRet::Multi(
+                arrs.into_iter()
+                    .map(|arr_paths| {
+                        self.slice_array_inner(
+                            loc,
+                            arr_paths,
+                            start_paths.clone(),
+                            end_paths.clone(),
+                        )
+                    })
+                    .collect(),
+            ),
+            // each branch of a ternary bound is sliced in its own fork
+            (
+                ExprRet::Single((ctx, arr)) | ExprRet::SingleLiteral((ctx, arr)),
+                Some(ExprRet::Fork(world1, world2)),
+                end_paths,
+            ) => ExprRet::Fork(
+                Box::new(self.slice_array_inner(
+                    loc,
+                    in_branch(ctx, arr, &world1),
+                    Some(*world1),
+                    end_paths.clone(),
+                )),
+                Box::new(self.slice_array_inner(
+                    loc,
+                    in_branch(ctx, arr, &world2),
+                    Some(*world2),
+                    end_paths,
+                )),
+            ),
+            (
+                ExprRet::Single((ctx, arr)) | ExprRet::SingleLiteral((ctx, arr)),
+                start_paths,
+                Some(ExprRet::Fork(world1, world2)),
+            ) => ExprRet::Fork(
+                Box::new(self.slice_array_inner(
+                    loc,
+                    in_branch(ctx, arr, &world1),
+                    start_paths.clone(),
+                    Some(*world1),
+                )),
+                Box::new(self.slice_array_inner(
+                    loc,
+                    in_branch(ctx, arr, &world2),
+                    start_paths,
+                    Some(*world2),
+                )),
+            ),
+            (
+                ExprRet::Single((ctx, arr)) | ExprRet::SingleLiteral((ctx, arr)),
+                Some(ExprRet::Multi(starts)),
+                end_paths,
+            ) => ExprRet::Multi(
+                starts
+                    .into_iter()
+                    .map(|start_paths| {
+                        self.slice_array_inner(
+                            loc,
+                            in_branch(ctx, arr, &start_paths),
+                            Some(start_paths),
+                            end_paths.clone(),
+                        )
+                    })
+                    .collect(),
+            ),
+            (
+                ExprRet::Single((ctx, arr)) | ExprRet::SingleLiteral((ctx, arr)),
+                start_paths,
+                Some(ExprRet::Multi(ends)),
+            ) => ExprRet::Multi(
+                ends.into_iter()
+                    .map(|end_paths| {
+                        self.slice_array_inner(
+                            loc,
+                            in_branch(ctx, arr, &end_paths),
+                            start_paths.clone(),
+                            Some(end_paths),
+                        )
+                    })
+                    .collect(),
+            ),
+            (
+                ExprRet::Single((ctx, arr)) | ExprRet::SingleLiteral((ctx, arr)),
+                start_paths @ (None | Some(ExprRet::Single(_)) | Some(ExprRet::SingleLiteral(_))),
+                end_paths @ (None | Some(ExprRet::Single(_)) | Some(ExprRet::SingleLiteral(_))),
+            ) => self.slice_array_in_ctx(loc, ctx, arr.into(), start_paths, end_paths),
+        }
+    }
+
+    /// Slices the array in a single context, where the start and end are single expressions
+    /// (possibly from a parent of `ctx`)
+    fn slice_array_in_ctx(
+        &mut self,
+        loc: Loc,
+        ctx: ContextNode,
+        arr: ContextVarNode,
+        start_paths: Option<ExprRet>,
+        end_paths: Option<ExprRet>,
+    ) -> ExprRet {
+        let arr = arr.first_version(self);
+        let len_var = self.tmp_length(arr, ctx, loc).latest_version(self);
+
+        let has_start = start_paths.is_some();
+        let has_end = end_paths.is_some();
+        let start_paths = match start_paths {
+            Some(ExprRet::SingleLiteral((_, start))) => ExprRet::SingleLiteral((ctx, start)),
+            Some(start_paths) => ExprRet::Single((ctx, start_paths.expect_single().1)),
+            None => {
+                let zero = self.add_node(Node::Concrete(Concrete::from(U256::zero())));
+                let zero = self.add_node(Node::ContextVar(ContextVar::new_from_concrete(
+                    Loc::Implicit,
+                    zero.into(),
+                    self,
+                )));
+                ExprRet::SingleLiteral((ctx, zero))
+            }
+        };
+        let end_paths = match end_paths {
+            Some(ExprRet::SingleLiteral((_, end))) => ExprRet::SingleLiteral((ctx, end)),
+            Some(end_paths) => ExprRet::Single((ctx, end_paths.expect_single().1)),
+            None => ExprRet::Single((ctx, len_var.into())),
+        };
+
+        // start <= end
+        self.handle_require_inner(
+            loc,
+            &start_paths,
+            &end_paths,
+            RangeOp::Lte,
+            RangeOp::Gte,
+            (RangeOp::Gte, RangeOp::Lte),
+            RequireKind::Implicit,
+        );
+
+        // end <= arr.length
+        self.handle_require_inner(
+            loc,
+            &end_paths,
+            &ExprRet::Single((ctx, len_var.latest_version(self).into())),
+            RangeOp::Lte,
+            RangeOp::Gte,
+            (RangeOp::Gte, RangeOp::Lte),
+            RequireKind::Implicit,
+        );
+
+        if ctx.is_killed(self) {
+            return ExprRet::CtxKilled;
+        }
+
+        let (_, start) = start_paths.expect_single();
+        let (_, end) = end_paths.expect_single();
+        let start = ContextVarNode::from(start).latest_version(self);
+        let end = ContextVarNode::from(end).latest_version(self);
+
+        let slice_var = ContextVar {
+            loc: Some(loc),
+            name: format!(
+                "{}[{}:{}]",
+                arr.name(self),
+                start.name(self),
+                end.name(self)
+            ),
+            display_name: format!(
+                "{}[{}:{}]",
+                arr.display_name(self),
+                if has_start {
+                    start.display_name(self)
+                } else {
+                    String::new()
+                },
+                if has_end {
+                    end.display_name(self)
+                } else {
+                    String::new()
+                }
+            ),
+            storage: arr.storage(self).clone(),
+            is_tmp: false,
+            tmp_of: None,
+            is_symbolic: true,
+            ty: arr.ty(self).clone(),
+            metadata: Default::default(),
+        };
+        let slice_node = ContextVarNode::from(self.add_node(Node::ContextVar(slice_var)));
+        self.add_edge(slice_node, ctx, Edge::Context(ContextEdge::Variable));
+
+        // the length of the slice is end - start
+        let slice_len = self.tmp_length(slice_node, ctx, loc);
+        let len = Elem::Dynamic(Dynamic::new(end.into(), loc))
+            - Elem::Dynamic(Dynamic::new(start.into(), loc));
+        slice_len.set_range_min(self, len.clone());
+        slice_len.set_range_max(self, len);
+
+        ExprRet::Single((ctx, slice_node.latest_version(self).into()))
+    }
 }
@@ -1,5 +1,8 @@
-use crate::{context::ContextBuilder, ExprRet};
-use ethers_core::types::{I256, U256};
+use crate::{
+    context::{taint::TaintTracker, ContextBuilder},
+    ExprRet,
+};
+use ethers_core::types::{I256, U256, U512};
 use shared::range::elem::RangeElem;
 use shared::{
     analyzer::AnalyzerLike,
@@ -145,7 +148,9 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
     ) -> ExprRet {
         // println!("op: {:?}, {:?} {:?}", op, lhs_cvar.display_name(self), rhs_cvar.display_name(self));
         let new_lhs = if assign {
-            self.advance_var_in_ctx(lhs_cvar, loc, ctx)
+            let new_lhs = self.advance_var_in_ctx(lhs_cvar, loc, ctx);
+            self.mark_storage_write(new_lhs, ctx);
+            new_lhs
         } else {
             let mut new_lhs_underlying = ContextVar {
                 loc: Some(loc),
@@ -167,19 +172,38 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
                 is_symbolic: lhs_cvar.is_symbolic(self) || rhs_cvar.is_symbolic(self),
                 tmp_of: Some(TmpConstruction::new(lhs_cvar, op, Some(rhs_cvar))),
                 ty: lhs_cvar.underlying(self).ty.clone(),
+                metadata: Default::default(),
             };
 
             // will potentially mutate the ty from concrete to builtin with a concrete range
             new_lhs_underlying.ty.concrete_to_builtin(self);
 
+            // shifts and exponentiation take the type of the lhs regardless of the rhs
+            if !matches!(op, RangeOp::Shl | RangeOp::Shr | RangeOp::Exp) {
+                if let Some(wider_ty) = self.widened_ty(&new_lhs_underlying.ty, rhs_cvar) {
+                    new_lhs_underlying.ty = wider_ty;
+                }
+            }
+
             let new_var = self.add_node(Node::ContextVar(new_lhs_underlying));
             self.add_edge(new_var, ctx, Edge::Context(ContextEdge::Variable));
             ContextVarNode::from(new_var)
         };
+        self.propagate_taint(&[lhs_cvar, rhs_cvar], new_lhs);
 
         let mut new_rhs = rhs_cvar.latest_version(self);
-
-        // TODO: change to only hit this path if !uncheck
+        // before solidity 0.8.0 arithmetic wraps unless checked by the code itself, i.e. SafeMath
+        let explicitly_unchecked = ctx.underlying(self).unchecked;
+        let unchecked = explicitly_unchecked || !self.checked_arithmetic();
+        let may_underflow = matches!(op, RangeOp::Sub) && self.sub_may_underflow(lhs_cvar, new_rhs);
+
+        if matches!(op, RangeOp::Mul)
+            && !assign
+            && (self.truncating_div(lhs_cvar) || self.truncating_div(new_rhs))
+        {
+            ctx.underlying_mut(self)
+                .add_finding(ContextFinding::DivideBeforeMultiply(loc));
+        }
 
         // TODO: If one of lhs_cvar OR rhs_cvar are not symbolic,
         // apply the requirement on the symbolic expression side instead of
@@ -221,6 +245,7 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
                                 BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
                                 SolcRange::from(Concrete::Bool(true)),
                             ),
+                            metadata: Default::default(),
                         };
 
                         let cvar = ContextVarNode::from(self.add_node(Node::ContextVar(tmp_var)));
@@ -245,7 +270,7 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
                         }
                     }
                 }
-                RangeOp::Sub => {
+                RangeOp::Sub if !unchecked => {
                     let lhs_cvar = lhs_cvar.latest_version(self);
                     if lhs_cvar.is_const(self) {
                         if !lhs_cvar.is_int(self) {
@@ -253,11 +278,9 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
                                 lhs_cvar.evaled_range_max(self),
                                 rhs_cvar.evaled_range_min(self),
                             ) {
-                                if matches!(
-                                    lmax.range_ord(&rmin),
-                                    Some(std::cmp::Ordering::Less)
-                                        | Some(std::cmp::Ordering::Equal)
-                                ) {
+                                // equal operands subtract to zero, only a strictly smaller
+                                // lhs underflows
+                                if matches!(lmax.range_ord(&rmin), Some(std::cmp::Ordering::Less)) {
                                     ctx.kill(self, loc);
                                     return ExprRet::CtxKilled;
                                 }
@@ -297,27 +320,29 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
                                 BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
                                 SolcRange::from(Concrete::Bool(true)),
                             ),
+                            metadata: Default::default(),
                         };
 
                         let cvar = ContextVarNode::from(self.add_node(Node::ContextVar(tmp_var)));
                         ctx.add_ctx_dep(cvar, self);
                     }
                 }
-                RangeOp::Add => {
+                RangeOp::Add if !unchecked => {
                     let lhs_cvar = lhs_cvar.latest_version(self);
                     if lhs_cvar.is_symbolic(self) {
                         let tmp_lhs = self.advance_var_in_ctx(lhs_cvar, loc, ctx);
 
-                        // the new max is min(lhs.max, (2**256 - rhs.min))
+                        // the new max is min(lhs.max, (type(result).max - rhs.min))
+                        let (result_max, max_str) = self.result_max(new_lhs);
                         let max = Elem::min(
                             tmp_lhs.range_max(self).expect("No range max?"),
-                            Elem::from(Concrete::from(U256::MAX))
+                            Elem::from(result_max.clone())
                                 - Elem::Dynamic(Dynamic::new(rhs_cvar.into(), loc)),
                         );
 
                         tmp_lhs.set_range_max(self, max);
 
-                        let max_node = self.add_node(Node::Concrete(Concrete::from(U256::MAX)));
+                        let max_node = self.add_node(Node::Concrete(result_max));
                         let max_node = self.add_node(Node::ContextVar(
                             ContextVar::new_from_concrete(Loc::Implicit, max_node.into(), self),
                         ));
@@ -329,14 +354,16 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
                         let tmp_var = ContextVar {
                             loc: Some(loc),
                             name: format!(
-                                "tmp{}({} <= 2**256 - 1 - {})",
+                                "tmp{}({} <= {} - {})",
                                 ctx.new_tmp(self),
                                 tmp_lhs.name(self),
+                                max_str,
                                 new_rhs.name(self),
                             ),
                             display_name: format!(
-                                "({} <= 2**256 - 1 - {})",
+                                "({} <= {} - {})",
                                 tmp_lhs.display_name(self),
+                                max_str,
                                 new_rhs.display_name(self),
                             ),
                             storage: None,
@@ -351,21 +378,31 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
                                 BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
                                 SolcRange::from(Concrete::Bool(true)),
                             ),
+                            metadata: Default::default(),
                         };
 
                         let cvar = ContextVarNode::from(self.add_node(Node::ContextVar(tmp_var)));
                         ctx.add_ctx_dep(cvar, self);
                     }
                 }
-                RangeOp::Mul => {
+                RangeOp::Mul if !unchecked => {
                     let lhs_cvar = lhs_cvar.latest_version(self);
+                    if lhs_cvar.is_const(self)
+                        && new_rhs.is_const(self)
+                        && self.const_mul_overflows(lhs_cvar, new_rhs, new_lhs)
+                    {
+                        ctx.kill(self, loc);
+                        return ExprRet::CtxKilled;
+                    }
+
                     if lhs_cvar.is_symbolic(self) {
                         let tmp_lhs = self.advance_var_in_ctx(lhs_cvar, loc, ctx);
 
-                        // the new max is min(lhs.max, (2**256 / max(1, rhs.min)))
+                        // the new max is min(lhs.max, (type(result).max / max(1, rhs.min)))
+                        let (result_max, max_str) = self.result_max(new_lhs);
                         let max = Elem::min(
                             tmp_lhs.range_max(self).expect("No range max?"),
-                            Elem::from(Concrete::from(U256::MAX))
+                            Elem::from(result_max.clone())
                                 / Elem::max(
                                     Elem::from(Concrete::from(U256::from(1))),
                                     Elem::Dynamic(Dynamic::new(rhs_cvar.into(), loc)),
@@ -374,7 +411,7 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
 
                         tmp_lhs.set_range_max(self, max);
 
-                        let max_node = self.add_node(Node::Concrete(Concrete::from(U256::MAX)));
+                        let max_node = self.add_node(Node::Concrete(result_max));
                         let max_node = self.add_node(Node::ContextVar(
                             ContextVar::new_from_concrete(Loc::Implicit, max_node.into(), self),
                         ));
@@ -386,14 +423,16 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
                         let tmp_var = ContextVar {
                             loc: Some(loc),
                             name: format!(
-                                "tmp{}({} <= (2**256 - 1) / {})",
+                                "tmp{}({} <= ({}) / {})",
                                 ctx.new_tmp(self),
                                 tmp_lhs.name(self),
+                                max_str,
                                 new_rhs.name(self),
                             ),
                             display_name: format!(
-                                "({} <= (2**256 - 1) / {})",
+                                "({} <= ({}) / {})",
                                 tmp_lhs.display_name(self),
+                                max_str,
                                 new_rhs.display_name(self),
                             ),
                             storage: None,
@@ -408,6 +447,7 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
                                 BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
                                 SolcRange::from(Concrete::Bool(true)),
                             ),
+                            metadata: Default::default(),
                         };
 
                         let cvar = ContextVarNode::from(self.add_node(Node::ContextVar(tmp_var)));
@@ -457,6 +497,7 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
                                 BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
                                 SolcRange::from(Concrete::Bool(true)),
                             ),
+                            metadata: Default::default(),
                         };
 
                         let cvar = ContextVarNode::from(self.add_node(Node::ContextVar(tmp_var)));
@@ -468,6 +509,14 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
             }
         }
 
+        // wrapping by default is exactly when an unguarded subtraction is worth reporting, only an
+        // `unchecked` block opts out. SafeMath style `require(b <= a)` guards already keep
+        // `may_underflow` false
+        if may_underflow && !explicitly_unchecked {
+            ctx.underlying_mut(self)
+                .add_finding(ContextFinding::PossibleUnderflow(loc, lhs_cvar, new_rhs));
+        }
+
         let lhs_range = if let Some(lhs_range) = new_lhs.range(self) {
             lhs_range
         } else {
@@ -477,9 +526,23 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
         };
 
         let func = SolcRange::dyn_fn_from_op(op);
-        let new_range = func(lhs_range, new_rhs, loc);
+        let mut new_range = func(lhs_range, new_rhs, loc);
+        new_range.normalize_exclusions(self);
         new_lhs.set_range_min(self, new_range.range_min());
         new_lhs.set_range_max(self, new_range.range_max());
+        new_lhs.set_range_exclusions(self, new_range.range_exclusions());
+
+        if assign && matches!(op, RangeOp::Shl) {
+            self.mask_shl_assign(lhs_cvar, new_rhs, new_lhs);
+        }
+
+        if matches!(op, RangeOp::BitAnd) {
+            self.bound_bit_and(lhs_cvar, new_rhs, new_lhs);
+        }
+
+        if may_underflow && unchecked {
+            self.wrap_sub(lhs_cvar, new_rhs, new_lhs);
+        }
 
         // last ditch effort to prevent exponentiation from having a minimum of 1 instead of 0.
         // if the lhs is 0 check if the rhs is also 0, otherwise set minimum to 0.
@@ -504,6 +567,440 @@ pub trait BinOp: AnalyzerLike<Expr = Expression> + Sized {
                 }
             }
         }
+
+        if matches!(op, RangeOp::Exp) && unchecked {
+            self.wrap_exp(lhs_cvar, new_rhs, new_lhs);
+        }
+
+        if self.collapses_to_constant(op, lhs_cvar, new_rhs, new_lhs) {
+            ctx.underlying_mut(self)
+                .add_finding(ContextFinding::ConstantResult(loc, new_lhs));
+        }
         ExprRet::Single((ctx, new_lhs.into()))
     }
+
+    /// The type of a binary operation whose lhs has type `lhs_ty`. The narrower of two integer
+    /// operands is implicitly converted to the wider type, so if the rhs is wider, the result
+    /// takes its type and keeps the lhs range, cast to the wider type
+    fn widened_ty(&mut self, lhs_ty: &VarType, rhs_cvar: ContextVarNode) -> Option<VarType> {
+        let mut rhs_ty = rhs_cvar.underlying(self).ty.clone();
+        rhs_ty.concrete_to_builtin(self);
+        match (lhs_ty, &rhs_ty) {
+            (VarType::BuiltIn(lhs_bn, _), VarType::BuiltIn(rhs_bn, _))
+                if lhs_bn != rhs_bn
+                    && lhs_bn.num_size(self).is_some()
+                    && lhs_bn.implicitly_castable_to(rhs_bn, self) =>
+            {
+                let lhs_range = lhs_ty.range(self)?;
+                let to = SolcRange::try_from_builtin(rhs_bn.underlying(self))?.min;
+                Some(VarType::BuiltIn(
+                    *rhs_bn,
+                    Some(SolcRange {
+                        min: lhs_range.min.cast(to.clone()),
+                        max: lhs_range.max.cast(to),
+                        exclusions: lhs_range.exclusions,
+                    }),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// The maximum of an unsigned result type, used to bound the operands of checked arithmetic,
+    /// along with how to display it. Falls back to `type(uint256).max`
+    fn result_max(&self, result: ContextVarNode) -> (Concrete, String) {
+        match result.ty(self) {
+            VarType::BuiltIn(bn, _) => match bn.underlying(self) {
+                Builtin::Uint(256) => (Concrete::from(U256::MAX), "2**256 - 1".to_string()),
+                Builtin::Uint(size) => (
+                    Concrete::Uint(*size, (U256::from(1) << *size) - 1),
+                    format!("2**{size} - 1"),
+                ),
+                _ => (Concrete::from(U256::MAX), "2**256 - 1".to_string()),
+            },
+            _ => (Concrete::from(U256::MAX), "2**256 - 1".to_string()),
+        }
+    }
+
+    /// Whether the product of two constant unsigned operands exceeds the maximum of the result
+    /// type. The product is computed exactly in 512 bits so it can't wrap
+    fn const_mul_overflows(
+        &self,
+        lhs_cvar: ContextVarNode,
+        rhs_cvar: ContextVarNode,
+        result: ContextVarNode,
+    ) -> bool {
+        match (
+            lhs_cvar.evaled_range_min(self),
+            rhs_cvar.evaled_range_min(self),
+        ) {
+            (Some(Elem::Concrete(lhs)), Some(Elem::Concrete(rhs))) => match (lhs.val, rhs.val) {
+                (Concrete::Uint(_, lhs), Concrete::Uint(_, rhs)) => {
+                    let (max, _) = self.result_max(result);
+                    match max.into_u256() {
+                        Some(max) => lhs.full_mul(rhs) > U512::from(max),
+                        None => false,
+                    }
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether an unsigned subtraction can go below zero, i.e. the lhs minimum is less than the
+    /// rhs maximum. A lhs whose minimum was already bounded by the rhs, e.g. by a
+    /// `require(lhs >= rhs)`, can't underflow
+    fn sub_may_underflow(&self, lhs_cvar: ContextVarNode, rhs_cvar: ContextVarNode) -> bool {
+        let lhs_cvar = lhs_cvar.latest_version(self);
+        if lhs_cvar.is_int(self) {
+            return false;
+        }
+
+        let rhs_first = rhs_cvar.first_version(self);
+        if let Some(lhs_min) = lhs_cvar.range_min(self) {
+            if lhs_min
+                .dependent_on()
+                .iter()
+                .any(|dep| dep.first_version(self) == rhs_first)
+            {
+                return false;
+            }
+        }
+
+        match (
+            lhs_cvar.evaled_range_min(self),
+            rhs_cvar.evaled_range_max(self),
+        ) {
+            (Some(lmin), Some(rmax)) => {
+                matches!(lmin.range_ord(&rmax), Some(std::cmp::Ordering::Less))
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the variable is the result of a division that may truncate. A constant dividend
+    /// that is evenly divided by a constant divisor loses nothing
+    fn truncating_div(&self, cvar: ContextVarNode) -> bool {
+        let div = match cvar.underlying(self).tmp_of() {
+            Some(TmpConstruction {
+                lhs,
+                op: RangeOp::Div,
+                rhs: Some(rhs),
+            }) => (lhs, rhs),
+            _ => return false,
+        };
+
+        match (self.const_u256(div.0), self.const_u256(div.1)) {
+            (Some(dividend), Some(divisor)) if !divisor.is_zero() => {
+                !(dividend % divisor).is_zero()
+            }
+            _ => true,
+        }
+    }
+
+    /// The value of a variable whose range is a single nonnegative value
+    fn const_u256(&self, cvar: ContextVarNode) -> Option<U256> {
+        match (cvar.evaled_range_min(self), cvar.evaled_range_max(self)) {
+            (Some(Elem::Concrete(min)), Some(Elem::Concrete(max))) if min.val == max.val => {
+                min.val.into_u256()
+            }
+            _ => None,
+        }
+    }
+
+    /// Inside of an `unchecked` block an unsigned subtraction that goes below zero wraps around
+    /// to the top of the result type. Two constant operands wrap to exactly one value, otherwise
+    /// any value of the type is possible
+    fn wrap_sub(
+        &mut self,
+        lhs_cvar: ContextVarNode,
+        rhs_cvar: ContextVarNode,
+        new_lhs: ContextVarNode,
+    ) {
+        let (size, max) = match self.result_max(new_lhs) {
+            (Concrete::Uint(size, max), _) => (size, max),
+            _ => return,
+        };
+
+        let val = match (
+            lhs_cvar.evaled_range_min(self),
+            lhs_cvar.evaled_range_max(self),
+            rhs_cvar.evaled_range_min(self),
+            rhs_cvar.evaled_range_max(self),
+        ) {
+            (
+                Some(Elem::Concrete(lhs_min)),
+                Some(Elem::Concrete(lhs_max)),
+                Some(Elem::Concrete(rhs_min)),
+                Some(Elem::Concrete(rhs_max)),
+            ) if lhs_min.val == lhs_max.val && rhs_min.val == rhs_max.val => {
+                match (lhs_min.val.into_u256(), rhs_min.val.into_u256()) {
+                    (Some(lhs), Some(rhs)) => Some(lhs.overflowing_sub(rhs).0 & max),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        let (min, max) = match val {
+            Some(val) => (val, val),
+            None => (U256::zero(), max),
+        };
+        new_lhs.set_range_min(self, Elem::from(Concrete::Uint(size, min)));
+        new_lhs.set_range_max(self, Elem::from(Concrete::Uint(size, max)));
+        new_lhs.set_range_exclusions(self, vec![]);
+    }
+
+    /// Inside of an `unchecked` block an unsigned exponentiation that exceeds the result type
+    /// wraps mod 2^N instead of saturating at the type maximum. Two constant operands wrap to
+    /// exactly one value, otherwise if the largest result can exceed the type maximum any value
+    /// of the type is possible
+    fn wrap_exp(
+        &mut self,
+        lhs_cvar: ContextVarNode,
+        rhs_cvar: ContextVarNode,
+        new_lhs: ContextVarNode,
+    ) {
+        if lhs_cvar.latest_version(self).is_int(self) {
+            return;
+        }
+
+        let (size, max) = match self.result_max(new_lhs) {
+            (Concrete::Uint(size, max), _) => (size, max),
+            _ => return,
+        };
+
+        let (lmin, lmax, rmin, rmax) = match (
+            lhs_cvar.evaled_range_min(self),
+            lhs_cvar.evaled_range_max(self),
+            rhs_cvar.evaled_range_min(self),
+            rhs_cvar.evaled_range_max(self),
+        ) {
+            (
+                Some(Elem::Concrete(lhs_min)),
+                Some(Elem::Concrete(lhs_max)),
+                Some(Elem::Concrete(rhs_min)),
+                Some(Elem::Concrete(rhs_max)),
+            ) => match (
+                lhs_min.val.into_u256(),
+                lhs_max.val.into_u256(),
+                rhs_min.val.into_u256(),
+                rhs_max.val.into_u256(),
+            ) {
+                (Some(lmin), Some(lmax), Some(rmin), Some(rmax)) => (lmin, lmax, rmin, rmax),
+                _ => return,
+            },
+            _ => return,
+        };
+
+        if lmin == lmax && rmin == rmax {
+            let val = lmax.overflowing_pow(rmax).0 & max;
+            new_lhs.set_range_min(self, Elem::from(Concrete::Uint(size, val)));
+            new_lhs.set_range_max(self, Elem::from(Concrete::Uint(size, val)));
+            new_lhs.set_range_exclusions(self, vec![]);
+        } else if !matches!(lmax.checked_pow(rmax), Some(largest) if largest <= max) {
+            new_lhs.set_range_min(self, Elem::from(Concrete::Uint(size, U256::zero())));
+            new_lhs.set_range_max(self, Elem::from(Concrete::Uint(size, max)));
+            new_lhs.set_range_exclusions(self, vec![]);
+        }
+    }
+
+    /// Bits shifted past the width of the lhs type in a `<<=` are lost, so instead of saturating
+    /// at the type maximum the result is masked to the type width. Shifts are never checked, so
+    /// this applies inside and outside of `unchecked` blocks. A right shift can't grow the value
+    /// and so needs no masking
+    fn mask_shl_assign(
+        &mut self,
+        lhs_cvar: ContextVarNode,
+        rhs_cvar: ContextVarNode,
+        new_lhs: ContextVarNode,
+    ) {
+        let (lhs_min, lhs_max, rmin, rmax) = match (
+            lhs_cvar.evaled_range_min(self),
+            lhs_cvar.evaled_range_max(self),
+            rhs_cvar.evaled_range_min(self),
+            rhs_cvar.evaled_range_max(self),
+        ) {
+            (
+                Some(Elem::Concrete(lhs_min)),
+                Some(Elem::Concrete(lhs_max)),
+                Some(Elem::Concrete(rhs_min)),
+                Some(Elem::Concrete(rhs_max)),
+            ) => match (rhs_min.val.into_u256(), rhs_max.val.into_u256()) {
+                (Some(rmin), Some(rmax)) => (lhs_min.val, lhs_max.val, rmin, rmax),
+                _ => return,
+            },
+            _ => return,
+        };
+        let (size, lmin, lmax) = match (lhs_min, lhs_max) {
+            (Concrete::Uint(size, lmin), Concrete::Uint(_, lmax)) => (size, lmin, lmax),
+            (Concrete::Int(size, lmin), Concrete::Int(_, lmax)) => {
+                self.mask_signed_shl_assign(size, (lmin, lmax), (rmin, rmax), new_lhs);
+                return;
+            }
+            _ => return,
+        };
+
+        let mask = if size == 256 {
+            U256::MAX
+        } else {
+            (U256::from(1) << size) - 1
+        };
+
+        if lmin == lmax && rmin == rmax {
+            let val = if rmax >= U256::from(size) {
+                U256::zero()
+            } else {
+                (lmax << rmax) & mask
+            };
+            new_lhs.set_range_min(self, Elem::from(Concrete::Uint(size, val)));
+            new_lhs.set_range_max(self, Elem::from(Concrete::Uint(size, val)));
+            new_lhs.set_range_exclusions(self, vec![]);
+        } else if rmax >= U256::from(size)
+            || lmax.leading_zeros() < (256 - size as u32) + rmax.as_u32()
+        {
+            // some bits may be shifted out, so any value of the type is possible
+            new_lhs.set_range_min(self, Elem::from(Concrete::Uint(size, U256::zero())));
+            new_lhs.set_range_max(self, Elem::from(Concrete::Uint(size, mask)));
+            new_lhs.set_range_exclusions(self, vec![]);
+        }
+    }
+
+    /// A signed `<<=` keeps the low bits of the two's complement value, so the result is masked
+    /// to the type width and sign extended from its new top bit, i.e. `int8(-128) << 1 == 0` and
+    /// `int8(64) << 1 == -128`. A shift of a range that can leave the type can change the sign,
+    /// so any value of the type is possible
+    fn mask_signed_shl_assign(
+        &mut self,
+        size: u16,
+        (lmin, lmax): (I256, I256),
+        (rmin, rmax): (U256, U256),
+        new_lhs: ContextVarNode,
+    ) {
+        let mask = if size == 256 {
+            U256::MAX
+        } else {
+            (U256::from(1) << size) - 1
+        };
+        let sign_bit = U256::from(1) << (size - 1);
+        let ty_max = I256::from_raw(sign_bit - 1);
+        let ty_min = I256::from_raw(!(sign_bit - 1));
+
+        let (min, max) = if lmin == lmax && rmin == rmax {
+            let val = if rmax >= U256::from(size) {
+                I256::zero()
+            } else {
+                let raw = (lmax.into_raw() << rmax) & mask;
+                if raw & sign_bit == U256::zero() {
+                    I256::from_raw(raw)
+                } else {
+                    I256::from_raw(raw | !mask)
+                }
+            };
+            (val, val)
+        } else {
+            let fits = rmax < U256::from(size - 1)
+                && [lmin, lmax].iter().all(|bound| {
+                    matches!(
+                        bound.checked_mul(I256::from_raw(U256::from(1) << rmax)),
+                        Some(shifted) if shifted >= ty_min && shifted <= ty_max
+                    )
+                });
+            if fits {
+                return;
+            }
+            (ty_min, ty_max)
+        };
+        new_lhs.set_range_min(self, Elem::from(Concrete::Int(size, min)));
+        new_lhs.set_range_max(self, Elem::from(Concrete::Int(size, max)));
+        new_lhs.set_range_exclusions(self, vec![]);
+    }
+
+    /// The bounds of a `&` are evaluated bound by bound, i.e. `lhs.max & rhs.max`, which is only
+    /// the largest result when both operands are constant: for `x` in `[0, 0x100]`, `x & 0xff`
+    /// has a max bound of 0 but can be 0xff. Masking an unsigned value can only clear bits, so
+    /// the result is instead bounded by `[0, min(lhs.max, rhs.max)]`, which keeps a bitfield
+    /// extraction like `(x >> shift) & mask` within `[0, mask]`
+    fn bound_bit_and(
+        &mut self,
+        lhs_cvar: ContextVarNode,
+        rhs_cvar: ContextVarNode,
+        new_lhs: ContextVarNode,
+    ) {
+        let size = match self.result_max(new_lhs) {
+            (Concrete::Uint(size, _), _) => size,
+            _ => return,
+        };
+        let (lmin, lmax, rmin, rmax) = match (
+            lhs_cvar.evaled_range_min(self),
+            lhs_cvar.evaled_range_max(self),
+            rhs_cvar.evaled_range_min(self),
+            rhs_cvar.evaled_range_max(self),
+        ) {
+            (
+                Some(Elem::Concrete(lhs_min)),
+                Some(Elem::Concrete(lhs_max)),
+                Some(Elem::Concrete(rhs_min)),
+                Some(Elem::Concrete(rhs_max)),
+            ) => match (lhs_min.val, lhs_max.val, rhs_min.val, rhs_max.val) {
+                (
+                    Concrete::Uint(_, lmin),
+                    Concrete::Uint(_, lmax),
+                    Concrete::Uint(_, rmin),
+                    Concrete::Uint(_, rmax),
+                ) => (lmin, lmax, rmin, rmax),
+                _ => return,
+            },
+            _ => return,
+        };
+
+        if lmin == lmax && rmin == rmax {
+            // constant operands are already exact
+            return;
+        }
+
+        new_lhs.set_range_min(self, Elem::from(Concrete::Uint(size, U256::zero())));
+        new_lhs.set_range_max(self, Elem::from(Concrete::Uint(size, lmax.min(rmax))));
+        new_lhs.set_range_exclusions(self, vec![]);
+    }
+
+    /// Whether an arithmetic or bitwise operation always produces the same value even though
+    /// one of its operands can vary, like `x * 0` or `x & 0`, which usually indicates a logic
+    /// bug. Operations whose operands are all single values are intentional constants and
+    /// aren't counted
+    fn collapses_to_constant(
+        &self,
+        op: RangeOp,
+        lhs_cvar: ContextVarNode,
+        rhs_cvar: ContextVarNode,
+        new_lhs: ContextVarNode,
+    ) -> bool {
+        if !matches!(
+            op,
+            RangeOp::Add
+                | RangeOp::Sub
+                | RangeOp::Mul
+                | RangeOp::Div
+                | RangeOp::Mod
+                | RangeOp::Exp
+                | RangeOp::BitAnd
+                | RangeOp::BitOr
+                | RangeOp::Shl
+                | RangeOp::Shr
+        ) {
+            return false;
+        }
+
+        self.is_single_value(new_lhs)
+            && !(self.is_single_value(lhs_cvar) && self.is_single_value(rhs_cvar))
+    }
+
+    /// Whether the variable's range evaluates to a single concrete value
+    fn is_single_value(&self, cvar: ContextVarNode) -> bool {
+        match (cvar.evaled_range_min(self), cvar.evaled_range_max(self)) {
+            (Some(min @ Elem::Concrete(_)), Some(max)) => min.range_eq(&max),
+            _ => false,
+        }
+    }
 }
@@ -9,7 +9,7 @@ use shared::{
         elem_ty::{Elem, RangeConcrete, RangeExpr},
         Range, SolcRange,
     },
-    Node,
+    Edge, Node,
 };
 
 use solang_parser::pt::{Expression, Loc};
@@ -41,6 +41,7 @@ pub trait Cmp: AnalyzerLike<Expr = Expression> + Sized {
                         BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
                         Some(range),
                     ),
+                    metadata: Default::default(),
                 };
 
                 ExprRet::Single((ctx, self.add_node(Node::ContextVar(out_var))))
@@ -65,9 +66,52 @@ pub trait Cmp: AnalyzerLike<Expr = Expression> + Sized {
     ) -> ExprRet {
         let lhs_paths = self.parse_ctx_expr(lhs_expr, ctx);
         let rhs_paths = self.parse_ctx_expr(rhs_expr, ctx);
+        if self.is_chained_cmp(&lhs_paths, op, &rhs_paths) {
+            // the comparison is almost certainly not what was intended, so the result is left
+            // unconstrained
+            ctx.underlying_mut(self)
+                .add_finding(ContextFinding::ChainedComparison(loc));
+            let out_var = ContextVar {
+                loc: Some(loc),
+                name: format!("tmp_chained_cmp{}", ctx.new_tmp(self)),
+                display_name: "chained comparison".to_string(),
+                storage: None,
+                is_tmp: true,
+                is_symbolic: true,
+                tmp_of: None,
+                ty: VarType::BuiltIn(
+                    BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
+                    SolcRange::try_from_builtin(&Builtin::Bool),
+                ),
+                metadata: Default::default(),
+            };
+            let out_var = self.add_node(Node::ContextVar(out_var));
+            self.add_edge(out_var, ctx, Edge::Context(ContextEdge::Variable));
+            return ExprRet::Single((ctx, out_var));
+        }
         self.cmp_inner(loc, &lhs_paths, op, &rhs_paths)
     }
 
+    /// Whether a comparison has a boolean operand that can't be meant as one, i.e. a boolean
+    /// compared by order or to a non-boolean. Solidity doesn't chain comparisons, so
+    /// `a < b < c` compares the boolean `a < b` to `c`
+    fn is_chained_cmp(&self, lhs_paths: &ExprRet, op: RangeOp, rhs_paths: &ExprRet) -> bool {
+        match (lhs_paths, rhs_paths) {
+            (
+                ExprRet::Single((_, lhs)) | ExprRet::SingleLiteral((_, lhs)),
+                ExprRet::Single((_, rhs)) | ExprRet::SingleLiteral((_, rhs)),
+            ) => {
+                let lhs_bool = ContextVarNode::from(*lhs).is_bool(self);
+                let rhs_bool = ContextVarNode::from(*rhs).is_bool(self);
+                match op {
+                    RangeOp::Lt | RangeOp::Gt | RangeOp::Lte | RangeOp::Gte => lhs_bool || rhs_bool,
+                    _ => lhs_bool != rhs_bool,
+                }
+            }
+            _ => false,
+        }
+    }
+
     fn cmp_inner(
         &mut self,
         loc: Loc,
@@ -84,10 +128,21 @@ pub trait Cmp: AnalyzerLike<Expr = Expression> + Sized {
                 ContextVarNode::from(*rhs).literal_cast_from(&ContextVarNode::from(*lhs), self);
                 self.cmp_inner(loc, lhs_paths, op, &ExprRet::Single((*rhs_ctx, *rhs)))
             }
+            (ExprRet::SingleLiteral((lhs_ctx, lhs)), ExprRet::SingleLiteral((rhs_ctx, rhs))) => {
+                self.cmp_inner(
+                    loc,
+                    &ExprRet::Single((*lhs_ctx, *lhs)),
+                    op,
+                    &ExprRet::Single((*rhs_ctx, *rhs)),
+                )
+            }
             (ExprRet::Single((ctx, lhs)), ExprRet::Single((_rhs_ctx, rhs))) => {
                 let lhs_cvar = ContextVarNode::from(*lhs);
                 let rhs_cvar = ContextVarNode::from(*rhs);
-                let range = {
+                let range = if let Some(res) = self.const_cmp(lhs_cvar, op, rhs_cvar) {
+                    // a comparison of two constants is itself a constant
+                    res.into()
+                } else {
                     let elem = Elem::Expr(RangeExpr {
                         lhs: Box::new(Elem::Dynamic(Dynamic::new(lhs_cvar.into(), loc))),
                         op,
@@ -129,6 +184,7 @@ pub trait Cmp: AnalyzerLike<Expr = Expression> + Sized {
                         BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
                         Some(range),
                     ),
+                    metadata: Default::default(),
                 };
 
                 ExprRet::Single((*ctx, self.add_node(Node::ContextVar(out_var))))
@@ -190,6 +246,27 @@ pub trait Cmp: AnalyzerLike<Expr = Expression> + Sized {
         }
     }
 
+    /// The result of a comparison of two variables whose ranges are single constant values
+    fn const_cmp(
+        &self,
+        lhs_cvar: ContextVarNode,
+        op: RangeOp,
+        rhs_cvar: ContextVarNode,
+    ) -> Option<bool> {
+        let ord = self
+            .const_value(lhs_cvar)?
+            .range_ord(&self.const_value(rhs_cvar)?)?;
+        match op {
+            RangeOp::Lt => Some(ord == Ordering::Less),
+            RangeOp::Gt => Some(ord == Ordering::Greater),
+            RangeOp::Lte => Some(ord != Ordering::Greater),
+            RangeOp::Gte => Some(ord != Ordering::Less),
+            RangeOp::Eq => Some(ord == Ordering::Equal),
+            RangeOp::Neq => Some(ord != Ordering::Equal),
+            _ => None,
+        }
+    }
+
     fn not_eval(&self, _ctx: ContextNode, loc: Loc, lhs_cvar: ContextVarNode) -> SolcRange {
         if let Some(lhs_range) = lhs_cvar.range(self) {
             let lhs_min = lhs_range.evaled_range_min(self);
@@ -1,8 +1,19 @@
-use crate::{exprs::Require, AnalyzerLike, ContextBuilder, ExprRet};
-use shared::{context::*, Edge, Node, NodeIdx};
+use crate::{
+    exprs::{Require, RequireKind},
+    AnalyzerLike, ContextBuilder, ExprRet,
+};
+use shared::{
+    context::*,
+    nodes::Concrete,
+    range::{elem::RangeElem, elem_ty::Elem},
+    Edge, Node, NodeIdx,
+};
+use std::collections::{BTreeMap, BTreeSet};
 
 use solang_parser::pt::CodeLocation;
-use solang_parser::pt::{Expression, Loc, Statement};
+use solang_parser::pt::{
+    CatchClause, Expression, Loc, ParameterList, Statement, VariableDeclaration,
+};
 
 impl<T> CondOp for T where T: AnalyzerLike<Expr = Expression> + Require + Sized {}
 pub trait CondOp: AnalyzerLike<Expr = Expression> + Require + Sized {
@@ -14,34 +25,312 @@ pub trait CondOp: AnalyzerLike<Expr = Expression> + Require + Sized {
         false_stmt: &Option<Box<Statement>>,
         ctx: ContextNode,
     ) {
-        let true_subctx = ContextNode::from(self.add_node(Node::Context(Context::new_subctx(
-            ctx, loc, true, None, false, self, None,
-        ))));
-        ctx.add_fork(true_subctx, self);
-        let false_subctx = ContextNode::from(self.add_node(Node::Context(Context::new_subctx(
-            ctx, loc, true, None, false, self, None,
+        if self.branch_budget_exceeded(ctx) {
+            self.merged_cond_op_stmt(loc, if_expr, true_stmt, false_stmt, ctx);
+            return;
+        }
+
+        let (true_subctx, false_subctx) = self.fork_ctx(loc, ctx);
+
+        self.apply_branch_condition(true_stmt.loc(), if_expr.clone(), true, true_subctx);
+        self.parse_ctx_statement(true_stmt, false, Some(true_subctx));
+
+        if let Some(false_stmt) = false_stmt {
+            self.apply_branch_condition(false_stmt.loc(), if_expr.clone(), false, false_subctx);
+            self.parse_ctx_statement(false_stmt, false, Some(false_subctx));
+        }
+    }
+
+    /// Parses a `try` statement. Whether the call reverts is unknown, so the call and the success
+    /// clause are parsed in one fork and the catch clauses in the other. Multiple catch clauses
+    /// are joined within their fork
+    fn try_stmt(
+        &mut self,
+        loc: Loc,
+        try_expr: &Expression,
+        maybe_returns: &Option<(ParameterList, Box<Statement>)>,
+        clauses: &[CatchClause],
+        ctx: ContextNode,
+    ) {
+        if self.branch_budget_exceeded(ctx) {
+            self.merged_try_stmt(loc, try_expr, maybe_returns, clauses, ctx);
+            return;
+        }
+
+        let (success_subctx, catch_subctx) = self.fork_ctx(loc, ctx);
+        self.try_success_clause(loc, try_expr, maybe_returns, success_subctx);
+        match clauses {
+            [clause] => self.catch_clause(clause, catch_subctx),
+            clauses => {
+                let before = self.vars_before_branches(catch_subctx);
+                let branches: Vec<ContextNode> = clauses
+                    .iter()
+                    .map(|clause| {
+                        let subctx = self.branch_child(loc, catch_subctx);
+                        self.catch_clause(clause, subctx);
+                        subctx
+                    })
+                    .collect();
+                self.join_branches(loc, catch_subctx, &before, &branches);
+            }
+        }
+    }
+
+    /// Parses the clauses of a `try` statement in children of the context instead of forks, then
+    /// joins them back into the context, like `merged_cond_op_stmt`
+    fn merged_try_stmt(
+        &mut self,
+        loc: Loc,
+        try_expr: &Expression,
+        maybe_returns: &Option<(ParameterList, Box<Statement>)>,
+        clauses: &[CatchClause],
+        ctx: ContextNode,
+    ) {
+        ctx.underlying_mut(self)
+            .add_finding(ContextFinding::MergedBranch(loc));
+
+        let before = self.vars_before_branches(ctx);
+
+        let success_subctx = self.branch_child(loc, ctx);
+        self.try_success_clause(loc, try_expr, maybe_returns, success_subctx);
+        let mut branches = vec![success_subctx];
+        clauses.iter().for_each(|clause| {
+            let subctx = self.branch_child(loc, ctx);
+            self.catch_clause(clause, subctx);
+            branches.push(subctx);
+        });
+
+        self.join_branches(loc, ctx, &before, &branches);
+    }
+
+    /// Parses the call of a `try` statement, binding its returns, followed by the success block
+    fn try_success_clause(
+        &mut self,
+        loc: Loc,
+        try_expr: &Expression,
+        maybe_returns: &Option<(ParameterList, Box<Statement>)>,
+        ctx: ContextNode,
+    ) {
+        match maybe_returns {
+            Some((returns, block)) => {
+                let call = if returns.is_empty() {
+                    try_expr.clone()
+                } else {
+                    Expression::Assign(
+                        loc,
+                        Box::new(Expression::List(loc, returns.clone())),
+                        Box::new(try_expr.clone()),
+                    )
+                };
+                self.parse_ctx_statement(&Statement::Expression(loc, call), false, Some(ctx));
+                self.parse_ctx_statement(block, false, Some(ctx));
+            }
+            None => {
+                let call = Statement::Expression(loc, try_expr.clone());
+                self.parse_ctx_statement(&call, false, Some(ctx));
+            }
+        }
+    }
+
+    /// Declares the parameter of a catch clause, if it is named, and parses its block
+    fn catch_clause(&mut self, clause: &CatchClause, ctx: ContextNode) {
+        let (param, block) = match clause {
+            CatchClause::Simple(_loc, param, block) => (param.as_ref(), block),
+            CatchClause::Named(_loc, _name, param, block) => (Some(param), block),
+        };
+        if let Some(param) = param {
+            if param.name.is_some() {
+                let decl = VariableDeclaration {
+                    loc: param.loc,
+                    ty: param.ty.clone(),
+                    storage: param.storage.clone(),
+                    name: param.name.clone(),
+                };
+                let def = Statement::VariableDefinition(param.loc, decl, None);
+                self.parse_ctx_statement(&def, false, Some(ctx));
+            }
+        }
+        self.parse_ctx_statement(block, false, Some(ctx));
+    }
+
+    /// Applies the condition of a branch to the subcontext for the branch, recording the
+    /// constraints it added as part of the subcontext's path condition
+    fn apply_branch_condition(
+        &mut self,
+        loc: Loc,
+        cond: Expression,
+        holds: bool,
+        ctx: ContextNode,
+    ) {
+        let before: BTreeSet<String> = ctx.underlying(self).ctx_deps.keys().cloned().collect();
+        self.apply_condition(loc, cond, holds, ctx, RequireKind::Implicit);
+        let mut added: Vec<ContextVarNode> = ctx
+            .underlying(self)
+            .ctx_deps
+            .iter()
+            .filter(|(name, _)| !before.contains(*name))
+            .map(|(_, dep)| *dep)
+            .collect();
+        added.sort();
+        ctx.underlying_mut(self).path_conditions.extend(added);
+    }
+
+    /// Whether forking the context would take its function past the maximum number of subcontexts
+    fn branch_budget_exceeded(&self, ctx: ContextNode) -> bool {
+        let num_ctxs = ctx
+            .root(self)
+            .subcontexts(self)
+            .into_iter()
+            .filter(|subctx| matches!(self.node(*subctx), Node::Context(_)))
+            .count();
+        num_ctxs + 2 > self.max_branch_contexts()
+    }
+
+    /// Parses the branches of an if statement in children of the context instead of forks, then
+    /// joins them back into the context. Subsequent statements are parsed once rather than once
+    /// per branch, which loses precision, so the location is recorded
+    fn merged_cond_op_stmt(
+        &mut self,
+        loc: Loc,
+        if_expr: &Expression,
+        true_stmt: &Statement,
+        false_stmt: &Option<Box<Statement>>,
+        ctx: ContextNode,
+    ) {
+        ctx.underlying_mut(self)
+            .add_finding(ContextFinding::MergedBranch(loc));
+
+        let before = self.vars_before_branches(ctx);
+
+        let true_subctx = self.branch_child(loc, ctx);
+        self.apply_branch_condition(true_stmt.loc(), if_expr.clone(), true, true_subctx);
+        self.parse_ctx_statement(true_stmt, false, Some(true_subctx));
+
+        // without an else branch the false branch leaves everything as it was, but the
+        // condition being false may still be impossible
+        let false_subctx = self.branch_child(loc, ctx);
+        match false_stmt {
+            Some(false_stmt) => {
+                self.apply_branch_condition(false_stmt.loc(), if_expr.clone(), false, false_subctx);
+                self.parse_ctx_statement(false_stmt, false, Some(false_subctx));
+            }
+            None => self.apply_branch_condition(loc, if_expr.clone(), false, false_subctx),
+        }
+
+        self.join_branches(loc, ctx, &before, &[true_subctx, false_subctx]);
+    }
+
+    /// The latest version of each variable visible in the context before it branches, including
+    /// those of the contexts it is itself a branch of
+    fn vars_before_branches(&self, ctx: ContextNode) -> BTreeMap<String, ContextVarNode> {
+        let mut ctxs = vec![ctx];
+        let mut curr = ctx;
+        while !curr.is_call(self) {
+            match curr.parent(self) {
+                Some(parent) => {
+                    ctxs.push(parent);
+                    curr = parent;
+                }
+                None => break,
+            }
+        }
+        ctxs.into_iter()
+            .rev()
+            .flat_map(|ctx| ctx.vars(self))
+            .map(|var| (var.name(self), var.latest_version(self)))
+            .collect()
+    }
+
+    /// Creates a non-forking child of the context for a branch that will later be joined
+    fn branch_child(&mut self, loc: Loc, ctx: ContextNode) -> ContextNode {
+        let subctx = ContextNode::from(self.add_node(Node::Context(Context::new_subctx(
+            ctx, loc, false, None, false, self, None,
         ))));
-        ctx.add_fork(false_subctx, self);
-        let ctx_fork = self.add_node(Node::ContextFork);
-        self.add_edge(ctx_fork, ctx, Edge::Context(ContextEdge::ContextFork));
-        self.add_edge(
-            NodeIdx::from(true_subctx.0),
-            ctx_fork,
-            Edge::Context(ContextEdge::Subcontext),
-        );
+        ctx.add_child(subctx, self);
+        let ctx_fork = self.add_node(Node::FunctionCall);
+        self.add_edge(ctx_fork, ctx, Edge::Context(ContextEdge::Subcontext));
         self.add_edge(
-            NodeIdx::from(false_subctx.0),
+            NodeIdx::from(subctx.0),
             ctx_fork,
             Edge::Context(ContextEdge::Subcontext),
         );
+        subctx
+    }
 
-        self.true_fork_if_cvar(true_stmt.loc(), if_expr.clone(), true_subctx);
-        self.parse_ctx_statement(true_stmt, false, Some(true_subctx));
+    /// Joins branches back into the context they were created from. Each variable modified in a
+    /// branch gets a new version in the context whose range is the union of its ranges at the end
+    /// of each branch. A branch that didn't modify the variable contributes its range from before
+    /// the branches, and a killed branch contributes nothing. If every branch was killed, so is
+    /// the context
+    fn join_branches(
+        &mut self,
+        loc: Loc,
+        ctx: ContextNode,
+        before: &BTreeMap<String, ContextVarNode>,
+        branches: &[ContextNode],
+    ) {
+        let live: Vec<ContextNode> = branches
+            .iter()
+            .copied()
+            .filter(|branch| !branch.is_killed(self))
+            .collect();
+        if live.is_empty() {
+            ctx.kill(self, loc);
+            return;
+        }
 
-        if let Some(false_stmt) = false_stmt {
-            self.false_fork_if_cvar(false_stmt.loc(), if_expr.clone(), false_subctx);
-            self.parse_ctx_statement(false_stmt, false, Some(false_subctx));
+        let mut names: Vec<String> = branches
+            .iter()
+            .flat_map(|branch| branch.local_vars(self))
+            .map(|var| var.name(self))
+            .filter(|name| before.contains_key(name))
+            .collect();
+        names.sort();
+        names.dedup();
+
+        names.into_iter().for_each(|name| {
+            let orig = before[&name];
+            let outcomes: Vec<ContextVarNode> = live
+                .iter()
+                .map(|branch| branch.latest_var_by_name(self, &name).unwrap_or(orig))
+                .collect();
+
+            let new_var = self.advance_var_in_ctx(
+                ctx.latest_var_by_name(self, &name).unwrap_or(orig),
+                loc,
+                ctx,
+            );
+            let bounds = match self.union_bounds(&outcomes) {
+                Some(bounds) => Some(bounds),
+                None => new_var
+                    .underlying(self)
+                    .ty
+                    .default_range(self)
+                    .map(|r| (r.min, r.max)),
+            };
+            if let Some((min, max)) = bounds {
+                new_var.set_range_min(self, min);
+                new_var.set_range_max(self, max);
+            }
+        });
+    }
+
+    /// The smallest range containing the evaluated ranges of all of the variables, if they can
+    /// all be compared
+    fn union_bounds(&self, vars: &[ContextVarNode]) -> Option<(Elem<Concrete>, Elem<Concrete>)> {
+        let mut min = vars.first()?.evaled_range_min(self)?;
+        let mut max = vars.first()?.evaled_range_max(self)?;
+        for var in vars.iter().skip(1) {
+            let var_min = var.evaled_range_min(self)?;
+            if var_min.range_ord(&min)? == std::cmp::Ordering::Less {
+                min = var_min;
+            }
+            let var_max = var.evaled_range_max(self)?;
+            if var_max.range_ord(&max)? == std::cmp::Ordering::Greater {
+                max = var_max;
+            }
         }
+        Some((min, max))
     }
 
     /// When we have a conditional operator, we create a fork in the context. One side of the fork is
@@ -54,26 +343,7 @@ pub trait CondOp: AnalyzerLike<Expr = Expression> + Require + Sized {
         false_expr: &Expression,
         ctx: ContextNode,
     ) -> ExprRet {
-        let true_subctx = ContextNode::from(self.add_node(Node::Context(Context::new_subctx(
-            ctx, loc, true, None, false, self, None,
-        ))));
-        ctx.add_fork(true_subctx, self);
-        let false_subctx = ContextNode::from(self.add_node(Node::Context(Context::new_subctx(
-            ctx, loc, true, None, false, self, None,
-        ))));
-        ctx.add_fork(false_subctx, self);
-        let ctx_fork = self.add_node(Node::ContextFork);
-        self.add_edge(ctx_fork, ctx, Edge::Context(ContextEdge::ContextFork));
-        self.add_edge(
-            NodeIdx::from(true_subctx.0),
-            ctx_fork,
-            Edge::Context(ContextEdge::Subcontext),
-        );
-        self.add_edge(
-            NodeIdx::from(false_subctx.0),
-            ctx_fork,
-            Edge::Context(ContextEdge::Subcontext),
-        );
+        let (true_subctx, false_subctx) = self.fork_ctx(loc, ctx);
 
         let true_loc = true_expr.loc();
         let true_cvars = self.parse_ctx_expr(true_expr, true_subctx);
@@ -91,11 +361,11 @@ pub trait CondOp: AnalyzerLike<Expr = Expression> + Require + Sized {
             ExprRet::CtxKilled => {}
             ExprRet::Single((fork_ctx, _true_cvar))
             | ExprRet::SingleLiteral((fork_ctx, _true_cvar)) => {
-                self.true_fork_if_cvar(loc, if_expr.clone(), *fork_ctx);
+                self.apply_branch_condition(loc, if_expr.clone(), true, *fork_ctx);
             }
             ExprRet::Multi(ref true_paths) => true_paths.iter().take(1).for_each(|expr_ret| {
                 let (fork_ctx, _) = expr_ret.expect_single();
-                self.true_fork_if_cvar(loc, if_expr.clone(), fork_ctx);
+                self.apply_branch_condition(loc, if_expr.clone(), true, fork_ctx);
             }),
             ExprRet::Fork(true_paths, other_true_paths) => {
                 self.match_true(loc, true_paths, if_expr);
@@ -109,11 +379,11 @@ pub trait CondOp: AnalyzerLike<Expr = Expression> + Require + Sized {
             ExprRet::CtxKilled => {}
             ExprRet::Single((fork_ctx, _false_cvar))
             | ExprRet::SingleLiteral((fork_ctx, _false_cvar)) => {
-                self.false_fork_if_cvar(loc, if_expr.clone(), *fork_ctx);
+                self.apply_branch_condition(loc, if_expr.clone(), false, *fork_ctx);
             }
             ExprRet::Multi(ref false_paths) => false_paths.iter().take(1).for_each(|expr_ret| {
                 let (fork_ctx, _) = expr_ret.expect_single();
-                self.false_fork_if_cvar(loc, if_expr.clone(), fork_ctx);
+                self.apply_branch_condition(loc, if_expr.clone(), false, fork_ctx);
             }),
             ExprRet::Fork(false_paths, other_false_paths) => {
                 self.match_false(loc, false_paths, if_expr);
@@ -121,41 +391,4 @@ pub trait CondOp: AnalyzerLike<Expr = Expression> + Require + Sized {
             }
         }
     }
-
-    /// Creates the true_fork cvar (updates bounds assuming its true)
-    fn true_fork_if_cvar(&mut self, loc: Loc, if_expr: Expression, true_fork_ctx: ContextNode) {
-        let if_expr = match if_expr {
-            Expression::Equal(_loc, lhs, rhs) => Expression::Equal(loc, lhs, rhs),
-            Expression::And(_loc, lhs, rhs) => Expression::And(loc, lhs, rhs),
-            Expression::NotEqual(_loc, lhs, rhs) => Expression::NotEqual(loc, lhs, rhs),
-            Expression::Less(_loc, lhs, rhs) => Expression::Less(loc, lhs, rhs),
-            Expression::More(_loc, lhs, rhs) => Expression::More(loc, lhs, rhs),
-            Expression::MoreEqual(_loc, lhs, rhs) => Expression::MoreEqual(loc, lhs, rhs),
-            Expression::LessEqual(_loc, lhs, rhs) => Expression::LessEqual(loc, lhs, rhs),
-            Expression::Variable(ref ident) => {
-                let mut c = ident.clone();
-                c.loc = loc;
-                Expression::Variable(c)
-            }
-            e => todo!("Wasnt comparator: {:?}", e),
-        };
-        // println!("true fork if: {if_expr:?} {true_fork_ctx:?}");
-        self.handle_require(&[if_expr], true_fork_ctx)
-    }
-
-    /// Creates the false_fork cvar (inverts the expression and sets the bounds assuming its false)
-    fn false_fork_if_cvar(&mut self, loc: Loc, if_expr: Expression, false_fork_ctx: ContextNode) {
-        let inv_if_expr = match if_expr {
-            Expression::Equal(_loc, lhs, rhs) => Expression::NotEqual(loc, lhs, rhs),
-            Expression::NotEqual(_loc, lhs, rhs) => Expression::Equal(loc, lhs, rhs),
-            Expression::Less(_loc, lhs, rhs) => Expression::MoreEqual(loc, lhs, rhs),
-            Expression::More(_loc, lhs, rhs) => Expression::LessEqual(loc, lhs, rhs),
-            Expression::MoreEqual(_loc, lhs, rhs) => Expression::Less(loc, lhs, rhs),
-            Expression::LessEqual(_loc, lhs, rhs) => Expression::More(loc, lhs, rhs),
-            Expression::Variable(ref _ident) => Expression::Not(loc, Box::new(if_expr)),
-            e => todo!("Wasnt comparator: {:?}", e),
-        };
-        // println!("inverse if expr: {inv_if_expr:?}");
-        self.handle_require(&[inv_if_expr], false_fork_ctx)
-    }
 }
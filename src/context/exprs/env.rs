@@ -1,9 +1,37 @@
+use crate::context::exprs::require::{Require, RequireKind};
 use crate::context::func::FuncCaller;
-use crate::{context::ContextNode, AnalyzerLike, ExprRet};
-use solang_parser::pt::Expression;
+use crate::context::taint::TaintTracker;
+use crate::{context::ContextNode, AnalyzerLike, ContextBuilder, ExprRet};
+use ethers_core::types::U256;
+use shared::{
+    context::*,
+    nodes::{Builtin, Concrete},
+    range::{
+        elem::{RangeElem, RangeOp},
+        elem_ty::{Dynamic, Elem},
+    },
+    Edge, Node,
+};
+use solang_parser::pt::{Expression, Loc, Mutability, StorageLocation, Type};
+use std::cmp::Ordering;
 
 use solang_parser::pt::Identifier;
 
+/// Whether the expression is the current contract's address, i.e. `this`, `address(this)` or
+/// `payable(address(this))`
+pub fn is_self_address(expr: &Expression) -> bool {
+    match expr {
+        Expression::This(_) => true,
+        Expression::FunctionCall(_, func_expr, inputs) if inputs.len() == 1 => {
+            matches!(
+                &**func_expr,
+                Expression::Type(_, Type::Address | Type::AddressPayable | Type::Payable)
+            ) && is_self_address(&inputs[0])
+        }
+        _ => false,
+    }
+}
+
 impl<T> Env for T where T: AnalyzerLike<Expr = Expression> + Sized {}
 pub trait Env: AnalyzerLike<Expr = Expression> + Sized {
     fn env_variable(&mut self, ident: &Identifier, ctx: ContextNode) -> Option<ExprRet> {
@@ -20,7 +48,11 @@ pub trait Env: AnalyzerLike<Expr = Expression> + Sized {
 
                     // TODO: inherit the input changes as well
                     // println!("inheriting back from parent into modifier");
-                    self.inherit_storage_changes(ctx, mod_state.parent_ctx);
+                    self.inherit_storage_changes(
+                        ctx,
+                        mod_state.parent_ctx,
+                        &mod_state.renamed_inputs,
+                    );
 
                     self.modifier_inherit_return(ctx, mod_state.parent_ctx);
                     Some(res)
@@ -32,6 +64,203 @@ pub trait Env: AnalyzerLike<Expr = Expression> + Sized {
         }
     }
 
+    /// Gets the latest version of the contract's own balance, i.e. `address(this).balance`,
+    /// creating it if it hasn't been accessed yet. The balance is treated as storage so that
+    /// transfers made by called functions are inherited by the caller. In a payable function
+    /// the balance already includes `msg.value`
+    fn self_balance(&mut self, loc: Loc, ctx: ContextNode) -> ContextVarNode {
+        if let Some(balance) = ctx.var_by_name_or_recurse(self, "this.balance") {
+            return balance.latest_version(self);
+        }
+
+        let node = self.builtin_or_add(Builtin::Uint(256));
+        let mut var = ContextVar::new_from_builtin(loc, node.into(), self);
+        var.name = "this.balance".to_string();
+        var.display_name = "address(this).balance".to_string();
+        var.storage = Some(StorageLocation::Storage(loc));
+        var.is_tmp = false;
+        var.is_symbolic = true;
+        let balance = ContextVarNode::from(self.add_node(Node::ContextVar(var)));
+        self.add_edge(balance, ctx, Edge::Context(ContextEdge::Variable));
+
+        if matches!(
+            ctx.associated_fn(self).mutability(self),
+            Some(Mutability::Payable(_))
+        ) {
+            let msg_value = Expression::MemberAccess(
+                loc,
+                Box::new(Expression::Variable(Identifier {
+                    loc,
+                    name: "msg".to_string(),
+                })),
+                Identifier {
+                    loc,
+                    name: "value".to_string(),
+                },
+            );
+            let value = self.parse_ctx_expr(&msg_value, ctx);
+            self.handle_require_inner(
+                loc,
+                &ExprRet::Single((ctx, balance.into())),
+                &value,
+                RangeOp::Gte,
+                RangeOp::Lte,
+                (RangeOp::Lte, RangeOp::Gte),
+                RequireKind::Implicit,
+            );
+        }
+        balance.latest_version(self)
+    }
+
+    /// Handles `to.transfer(amount)` and `to.send(amount)`, which move `amount` out of the
+    /// contract's balance, and records the external call to `to` in the context. An amount that
+    /// may exceed the balance is recorded as well.
+    /// `transfer` reverts if the balance is insufficient while `send` returns false instead,
+    /// leaving the balance anywhere between its previous value and 0
+    fn transfer_out(
+        &mut self,
+        loc: Loc,
+        is_send: bool,
+        amount_expr: &Expression,
+        ctx: ContextNode,
+    ) -> ExprRet {
+        let amount = self.parse_ctx_expr(amount_expr, ctx);
+        self.match_transfer_out(loc, is_send, &amount)
+    }
+
+    /// Transfers each possible amount out of the balance of the context it was parsed in, i.e.
+    /// both branches of `to.transfer(c ? a : b)`
+    fn match_transfer_out(&mut self, loc: Loc, is_send: bool, amount: &ExprRet) -> ExprRet {
+        match amount {
+            ExprRet::CtxKilled => ExprRet::CtxKilled,
+            ExprRet::Single((ctx, amount)) | ExprRet::SingleLiteral((ctx, amount)) => {
+                let amount = ContextVarNode::from(*amount).latest_version(self);
+                self.transfer_amount_out(loc, is_send, amount, *ctx)
+            }
+            ExprRet::Multi(inner) => ExprRet::Multi(
+                inner
+                    .iter()
+                    .map(|expr| self.match_transfer_out(loc, is_send, expr))
+                    .collect(),
+            ),
+            ExprRet::Fork(w1, w2) => ExprRet::Fork(
+                Box::new(self.match_transfer_out(loc, is_send, w1)),
+                Box::new(self.match_transfer_out(loc, is_send, w2)),
+            ),
+        }
+    }
+
+    fn transfer_amount_out(
+        &mut self,
+        loc: Loc,
+        is_send: bool,
+        amount: ContextVarNode,
+        ctx: ContextNode,
+    ) -> ExprRet {
+        let balance = self.self_balance(loc, ctx);
+        let name = if is_send { "send" } else { "transfer" };
+        ctx.underlying_mut(self)
+            .add_finding(ContextFinding::RawCall(loc, name.to_string()));
+
+        if let (Some(amount_max), Some(balance_max)) = (
+            amount.evaled_range_max(self),
+            balance.evaled_range_max(self),
+        ) {
+            if amount_max.range_ord(&balance_max) == Some(Ordering::Greater) {
+                ctx.underlying_mut(self)
+                    .add_finding(ContextFinding::OverTransfer(loc));
+            }
+        }
+
+        if is_send {
+            let new_balance = self.advance_var_in_ctx(balance, loc, ctx);
+            new_balance.set_range_min(self, Elem::from(Concrete::from(U256::zero())));
+            new_balance.set_range_max(self, Elem::Dynamic(Dynamic::new(balance.into(), loc)));
+
+            let node = self.builtin_or_add(Builtin::Bool);
+            let mut var = ContextVar::new_from_builtin(loc, node.into(), self);
+            var.name = format!("tmp_send{}", ctx.new_tmp(self));
+            var.display_name = "send(..)".to_string();
+            let sent = self.add_node(Node::ContextVar(var));
+            self.add_edge(sent, ctx, Edge::Context(ContextEdge::Variable));
+            ExprRet::Single((ctx, sent))
+        } else {
+            self.handle_require_inner(
+                loc,
+                &ExprRet::Single((ctx, amount.into())),
+                &ExprRet::Single((ctx, balance.into())),
+                RangeOp::Lte,
+                RangeOp::Gte,
+                (RangeOp::Gte, RangeOp::Lte),
+                RequireKind::Implicit,
+            );
+            if ctx.is_killed(self) {
+                return ExprRet::CtxKilled;
+            }
+
+            let balance = balance.latest_version(self);
+            let amount = amount.latest_version(self);
+            let new_balance = self.advance_var_in_ctx(balance, loc, ctx);
+            let remaining = Elem::Dynamic(Dynamic::new(balance.into(), loc))
+                - Elem::Dynamic(Dynamic::new(amount.into(), loc));
+            new_balance.set_range_min(self, remaining.clone());
+            new_balance.set_range_max(self, remaining);
+            ExprRet::Multi(vec![])
+        }
+    }
+
+    /// Handles `target.call(data)`, `target.delegatecall(data)` and `target.staticcall(data)`,
+    /// optionally sending `value` along. Nothing is known about the called code, so the returned
+    /// success flag and returndata are unconstrained and the returndata is untrusted. Calls that
+    /// can change state are recorded in the context as external calls
+    fn low_level_call(
+        &mut self,
+        loc: Loc,
+        kind: &str,
+        target: ContextVarNode,
+        input_exprs: &[Expression],
+        value: Option<ContextVarNode>,
+        ctx: ContextNode,
+    ) -> ExprRet {
+        if kind == "delegatecall" {
+            self.check_taint_sink(ctx, loc, TaintSink::DelegatecallTarget, target);
+        }
+        if let Some(value) = value {
+            self.check_taint_sink(ctx, loc, TaintSink::CallValue, value);
+        }
+        for input_expr in input_exprs.iter() {
+            if let ExprRet::CtxKilled = self.parse_ctx_expr(input_expr, ctx) {
+                return ExprRet::CtxKilled;
+            }
+        }
+
+        // a staticcall can't change state, so it can't reenter in a way that matters
+        if kind != "staticcall" {
+            ctx.underlying_mut(self)
+                .add_finding(ContextFinding::RawCall(loc, kind.to_string()));
+        }
+
+        let node = self.builtin_or_add(Builtin::Bool);
+        let mut var = ContextVar::new_from_builtin(loc, node.into(), self);
+        var.name = format!("tmp_{kind}_success{}", ctx.new_tmp(self));
+        var.display_name = format!("{kind}(..).success");
+        let success = self.add_node(Node::ContextVar(var));
+        self.add_edge(success, ctx, Edge::Context(ContextEdge::Variable));
+
+        let node = self.builtin_or_add(Builtin::DynamicBytes);
+        let mut var = ContextVar::new_from_builtin(loc, node.into(), self);
+        var.name = format!("tmp_{kind}_returndata{}", ctx.new_tmp(self));
+        var.display_name = format!("{kind}(..).returndata");
+        let returndata = self.add_node(Node::ContextVar(var));
+        self.add_edge(returndata, ctx, Edge::Context(ContextEdge::Variable));
+        self.taint_source(returndata.into(), format!("returndata of {kind}"));
+
+        ExprRet::Multi(vec![
+            ExprRet::Single((ctx, success)),
+            ExprRet::Single((ctx, returndata)),
+        ])
+    }
+
     // fn gasleft(&mut self, ctx: ContextNode) -> ExprRet {
 
     // }
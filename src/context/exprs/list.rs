@@ -26,6 +26,7 @@ pub trait List: AnalyzerLike<Expr = Expression> + Sized {
                             is_symbolic: false,
                             tmp_of: None,
                             ty,
+                            metadata: Default::default(),
                         };
                         let input_node = self.add_node(Node::ContextVar(var));
                         self.add_edge(input_node, lhs_ctx, Edge::Context(ContextEdge::Variable));
@@ -50,6 +51,7 @@ pub trait List: AnalyzerLike<Expr = Expression> + Sized {
                                     is_symbolic: false,
                                     tmp_of: None,
                                     ty,
+                                    metadata: Default::default(),
                                 };
                                 let input_node =
                                     self.add_node(Node::ContextVar(new_lhs_underlying));
@@ -2,9 +2,9 @@ use crate::ExprRet;
 use ethers_core::types::H256;
 use ethers_core::types::I256;
 use shared::{
-    analyzer::AnalyzerLike,
+    analyzer::{AnalyzerLike, LiteralTy},
     context::*,
-    nodes::{Concrete, ConcreteNode},
+    nodes::{Builtin, Concrete, ConcreteNode},
     Edge, Node,
 };
 use solang_parser::pt::HexLiteral;
@@ -16,6 +16,15 @@ use std::str::FromStr;
 impl<T> Literal for T where T: AnalyzerLike + Sized {}
 
 pub trait Literal: AnalyzerLike + Sized {
+    /// The bit size of a number literal's type before any type from its context is applied,
+    /// as chosen by the analyzer's literal type policy
+    fn literal_size(&self, val: U256) -> u16 {
+        match self.literal_ty() {
+            LiteralTy::SmallestFitting => ((32 - (val.leading_zeros() / 8)) * 8) as u16,
+            LiteralTy::Full => 256,
+        }
+    }
+
     fn number_literal(
         &mut self,
         ctx: ContextNode,
@@ -24,15 +33,12 @@ pub trait Literal: AnalyzerLike + Sized {
         exponent: &str,
         negative: bool,
     ) -> ExprRet {
-        let int = U256::from_dec_str(integer).unwrap();
-        let val = if !exponent.is_empty() {
-            let exp = U256::from_dec_str(exponent).unwrap();
-            int.pow(exp)
-        } else {
-            int
+        let val = match Concrete::dec_literal_val(integer, exponent) {
+            Some(val) => val,
+            None => return self.unrepresentable_literal(ctx, loc, integer, exponent, negative),
         };
 
-        let size: u16 = ((32 - (val.leading_zeros() / 8)) * 8) as u16;
+        let size = self.literal_size(val);
         let concrete_node = if negative {
             let val = if val == U256::from(2).pow(255.into()) {
                 // no need to set upper bit
@@ -51,6 +57,41 @@ pub trait Literal: AnalyzerLike + Sized {
         ExprRet::SingleLiteral((ctx, node))
     }
 
+    /// A number literal that isn't an integer fitting in 256 bits, i.e. one with a negative
+    /// exponent, is recorded as a diagnostic and treated as an unconstrained value of its type
+    fn unrepresentable_literal(
+        &mut self,
+        ctx: ContextNode,
+        loc: Loc,
+        integer: &str,
+        exponent: &str,
+        negative: bool,
+    ) -> ExprRet {
+        let literal = if exponent.is_empty() {
+            integer.to_string()
+        } else {
+            format!("{integer}e{exponent}")
+        };
+        self.add_diagnostic(
+            loc,
+            format!(
+                "Number literal {literal} is not a 256 bit integer, its value is unconstrained"
+            ),
+        );
+        let ty = if negative {
+            self.builtin_or_add(Builtin::Int(256))
+        } else {
+            self.builtin_or_add(Builtin::Uint(256))
+        };
+        let mut var = ContextVar::new_from_builtin(loc, ty.into(), self);
+        var.name = format!("tmp{}({literal})", ctx.new_tmp(self));
+        var.display_name = literal;
+        var.is_symbolic = true;
+        let node = self.add_node(Node::ContextVar(var));
+        self.add_edge(node, ctx, Edge::Context(ContextEdge::Variable));
+        ExprRet::Single((ctx, node))
+    }
+
     fn hex_num_literal(
         &mut self,
         ctx: ContextNode,
@@ -59,7 +100,7 @@ pub trait Literal: AnalyzerLike + Sized {
         negative: bool,
     ) -> ExprRet {
         let val = U256::from_str_radix(integer, 16).unwrap();
-        let size: u16 = ((32 - (val.leading_zeros() / 8)) * 8) as u16;
+        let size = self.literal_size(val);
         let concrete_node = if negative {
             let val = I256::from(-1i32) * I256::from_raw(val);
             ConcreteNode::from(self.add_node(Node::Concrete(Concrete::Int(size, val))))
@@ -1,4 +1,11 @@
-use crate::{context::exprs::variable::Variable, ContextBuilder, ExprRet, NodeIdx};
+use crate::{
+    context::exprs::{
+        env::{is_self_address, Env},
+        variable::Variable,
+    },
+    context::taint::TaintTracker,
+    ContextBuilder, ExprRet, NodeIdx,
+};
 use shared::analyzer::Search;
 use shared::range::elem_ty::Dynamic;
 use shared::range::elem_ty::Elem;
@@ -29,7 +36,22 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
         if ident.name == "length" {
             return self.length(loc, member_expr, ctx);
         }
+        if ident.name == "balance" && is_self_address(member_expr) {
+            return ExprRet::Single((ctx, self.self_balance(loc, ctx).into()));
+        }
         let (_, member_idx) = self.parse_ctx_expr(member_expr, ctx).expect_single();
+        self.member_access_inner(loc, member_idx, ident, ctx)
+    }
+
+    /// Accesses a member of an already parsed expression. Used directly when the expression
+    /// has side effects, i.e. `a.b().c()`, so the call to `b` isn't made twice
+    fn member_access_inner(
+        &mut self,
+        loc: Loc,
+        member_idx: NodeIdx,
+        ident: &Identifier,
+        ctx: ContextNode,
+    ) -> ExprRet {
         match self.node(member_idx) {
             Node::ContextVar(cvar) => match &cvar.ty {
                 VarType::User(TypeNode::Struct(struct_node)) => {
@@ -63,6 +85,9 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
                     }
                 }
                 VarType::User(TypeNode::Contract(con_node)) => {
+                    if matches!(&*ident.name, "creationCode" | "runtimeCode") {
+                        return self.contract_code(loc, *con_node, ident, ctx);
+                    }
                     println!(
                         "funcs: {:?}, ident: {:?}",
                         con_node
@@ -88,9 +113,16 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
                             );
                             return ExprRet::Single((ctx, fn_node));
                         }
+                    } else if let Some(ty_idx) = con_node.user_type_by_name(self, &ident.name) {
+                        // a struct or enum defined in the contract, i.e. `Lib.Struct`
+                        if let Some(ty_cvar) = ContextVar::maybe_from_user_ty(self, loc, ty_idx) {
+                            let ty_node = self.add_node(Node::ContextVar(ty_cvar));
+                            self.add_edge(ty_node, ctx, Edge::Context(ContextEdge::Variable));
+                            return ExprRet::Single((ctx, ty_node));
+                        }
                     } else {
                         panic!(
-                            "No function with name {:?} in contract: {:?}",
+                            "No function or type with name {:?} in contract: {:?}",
                             ident.name,
                             con_node.name(self)
                         )
@@ -124,6 +156,7 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
                                 var.is_symbolic = true;
                                 let cvar = self.add_node(Node::ContextVar(var));
                                 self.add_edge(cvar, ctx, Edge::Context(ContextEdge::Variable));
+                                self.taint_source(cvar.into(), "msg.data".to_string());
                                 return ExprRet::Single((ctx, cvar));
                             }
                         }
@@ -250,7 +283,7 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
                 }
             }
             Node::Block(_b) => {
-                let name = format!("msg.{}", ident.name);
+                let name = format!("block.{}", ident.name);
                 if let Some(attr_var) = ctx.var_by_name_or_recurse(self, &name) {
                     return ExprRet::Single((ctx, attr_var.latest_version(self).into()));
                 } else {
@@ -446,6 +479,30 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
         ExprRet::Single((ctx, member_idx))
     }
 
+    /// Accesses `type(C).creationCode` or `type(C).runtimeCode`, the bytecode of a contract,
+    /// whose contents and length are unknown
+    fn contract_code(
+        &mut self,
+        loc: Loc,
+        con_node: ContractNode,
+        ident: &Identifier,
+        ctx: ContextNode,
+    ) -> ExprRet {
+        let name = format!("type({}).{}", con_node.name(self), ident.name);
+        if let Some(code) = ctx.var_by_name_or_recurse(self, &name) {
+            return ExprRet::Single((ctx, code.latest_version(self).into()));
+        }
+        let node = self.builtin_or_add(Builtin::DynamicBytes);
+        let mut var = ContextVar::new_from_builtin(loc, node.into(), self);
+        var.name = name.clone();
+        var.display_name = name;
+        var.is_tmp = false;
+        var.is_symbolic = true;
+        let cvar = self.add_node(Node::ContextVar(var));
+        self.add_edge(cvar, ctx, Edge::Context(ContextEdge::Variable));
+        ExprRet::Single((ctx, cvar))
+    }
+
     fn builin_member_access(
         &mut self,
         loc: Loc,
@@ -472,6 +529,9 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
                 Builtin::Rational => {
                     panic!("Unknown member access on rational: {:?}", ident.name)
                 }
+                Builtin::Fixed(..) | Builtin::Ufixed(..) => {
+                    panic!("Unknown member access on fixed point: {:?}", ident.name)
+                }
                 Builtin::DynamicBytes => {
                     panic!("Unknown member access on bytes[]: {:?}", ident.name)
                 }
@@ -514,7 +574,7 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
                             ExprRet::Single((ctx, cvar))
                         }
                         "min" => {
-                            let min = max * I256::from(-1i32);
+                            let min = I256::from(-1i32) - max;
                             let c = Concrete::from(min);
                             let node = self.add_node(Node::Concrete(c)).into();
                             let mut var = ContextVar::new_from_concrete(loc, node, self);
@@ -751,6 +811,7 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
                     BuiltInNode::from(self.builtin_or_add(Builtin::Uint(256))),
                     SolcRange::try_from_builtin(&Builtin::Uint(256)),
                 ),
+                metadata: Default::default(),
             };
             let len_node = self.add_node(Node::ContextVar(len_var));
 
@@ -804,7 +865,7 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
 
                             if let Some(mut rd) = max.maybe_range_dyn() {
                                 rd.len = Elem::Dynamic(Dynamic::new(new_len.into(), loc));
-                                next_arr.set_range_min(self, Elem::ConcreteDyn(Box::new(rd)))
+                                next_arr.set_range_max(self, Elem::ConcreteDyn(Box::new(rd)))
                             }
                         }
                     }
@@ -822,6 +883,7 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
                             BuiltInNode::from(self.builtin_or_add(Builtin::Uint(256))),
                             SolcRange::try_from_builtin(&Builtin::Uint(256)),
                         ),
+                        metadata: Default::default(),
                     };
                     let len_node = self.add_node(Node::ContextVar(len_var));
 
@@ -830,6 +892,16 @@ pub trait MemberAccess: AnalyzerLike<Expr = Expression> + Sized {
                             let min = r.evaled_range_min(self);
                             let max = r.evaled_range_max(self);
 
+                            // the array may already have a known length, i.e. if it was allocated
+                            // by `new bytes(n)`
+                            if let (Some(min_rd), Some(max_rd)) =
+                                (min.maybe_range_dyn(), max.maybe_range_dyn())
+                            {
+                                let len_cvar = ContextVarNode::from(len_node);
+                                len_cvar.set_range_min(self, min_rd.len);
+                                len_cvar.set_range_max(self, max_rd.len);
+                            }
+
                             if let Some(mut rd) = min.maybe_range_dyn() {
                                 rd.len = Elem::Dynamic(Dynamic::new(len_node, loc));
                                 next_arr.set_range_min(self, Elem::ConcreteDyn(Box::new(rd)));
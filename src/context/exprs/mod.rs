@@ -1,3 +1,4 @@
+mod abi;
 mod array;
 mod bin_op;
 mod cmp;
@@ -9,6 +10,7 @@ mod member_access;
 mod require;
 mod variable;
 
+pub use abi::*;
 pub use array::*;
 pub use bin_op::*;
 pub use cmp::*;
@@ -21,10 +23,20 @@ pub use require::*;
 pub use variable::*;
 
 pub trait ExprParser:
-    BinOp + Require + Variable + Literal + Array + MemberAccess + Cmp + CondOp + List + Env
+    BinOp + Require + Variable + Literal + Array + MemberAccess + Cmp + CondOp + List + Env + Abi
 {
 }
 impl<T> ExprParser for T where
-    T: BinOp + Require + Variable + Literal + Array + MemberAccess + Cmp + CondOp + List + Env
+    T: BinOp
+        + Require
+        + Variable
+        + Literal
+        + Array
+        + MemberAccess
+        + Cmp
+        + CondOp
+        + List
+        + Env
+        + Abi
 {
 }
@@ -11,17 +11,90 @@ use shared::{
         elem_ty::{Dynamic, Elem, RangeConcrete},
         Range, RangeEval, SolcRange,
     },
-    Edge,
+    Edge, NodeIdx,
 };
 
 use ethers_core::types::I256;
-use solang_parser::pt::{Expression, Loc};
+use solang_parser::pt::{CodeLocation, Expression, Loc};
 use std::cmp::Ordering;
 
+/// Where a required condition comes from, which determines whether it is reported if it can fail
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RequireKind {
+    /// A condition applied by the analyzer itself, i.e. a branch condition or an array bound
+    Implicit,
+    /// A user written `require`, which validates inputs and is expected to fail for some
+    Require,
+    /// A user written `assert`, which checks an invariant and failing is a bug
+    Assert,
+}
+
+/// Whether the expression is a condition that can be applied either way, i.e. a comparison, a
+/// variable or a combination of them
+fn is_condition(expr: &Expression) -> bool {
+    match expr {
+        Expression::Parenthesis(_loc, inner) | Expression::Not(_loc, inner) => is_condition(inner),
+        Expression::And(_loc, lhs, rhs) | Expression::Or(_loc, lhs, rhs) => {
+            is_condition(lhs) && is_condition(rhs)
+        }
+        Expression::Equal(..)
+        | Expression::NotEqual(..)
+        | Expression::Less(..)
+        | Expression::More(..)
+        | Expression::MoreEqual(..)
+        | Expression::LessEqual(..)
+        | Expression::Variable(..) => true,
+        _ => false,
+    }
+}
+
+/// Moves a condition to the location, leaving the locations of its operands as they were
+fn at_loc(loc: Loc, cond: Expression) -> Expression {
+    match cond {
+        Expression::Or(_loc, lhs, rhs) => Expression::Or(loc, lhs, rhs),
+        Expression::Equal(_loc, lhs, rhs) => Expression::Equal(loc, lhs, rhs),
+        Expression::NotEqual(_loc, lhs, rhs) => Expression::NotEqual(loc, lhs, rhs),
+        Expression::Less(_loc, lhs, rhs) => Expression::Less(loc, lhs, rhs),
+        Expression::More(_loc, lhs, rhs) => Expression::More(loc, lhs, rhs),
+        Expression::MoreEqual(_loc, lhs, rhs) => Expression::MoreEqual(loc, lhs, rhs),
+        Expression::LessEqual(_loc, lhs, rhs) => Expression::LessEqual(loc, lhs, rhs),
+        Expression::Variable(mut ident) => {
+            ident.loc = loc;
+            Expression::Variable(ident)
+        }
+        e => e,
+    }
+}
+
+/// Flips a comparison so that its sides can be swapped, i.e. `a < b` is `b > a`
+fn flip_cmp_op(op: RangeOp) -> RangeOp {
+    match op {
+        RangeOp::Lt => RangeOp::Gt,
+        RangeOp::Gt => RangeOp::Lt,
+        RangeOp::Lte => RangeOp::Gte,
+        RangeOp::Gte => RangeOp::Lte,
+        e => e,
+    }
+}
+
+/// The right hand side and recursion operations used when requiring a comparison
+fn require_ops(op: RangeOp) -> (RangeOp, (RangeOp, RangeOp)) {
+    match op {
+        RangeOp::Eq => (RangeOp::Neq, (RangeOp::Neq, RangeOp::Eq)),
+        RangeOp::Neq => (RangeOp::Eq, (RangeOp::Eq, RangeOp::Neq)),
+        RangeOp::Lt => (RangeOp::Gt, (RangeOp::Gte, RangeOp::Lte)),
+        RangeOp::Gt => (RangeOp::Lt, (RangeOp::Lte, RangeOp::Gte)),
+        RangeOp::Gte => (RangeOp::Lte, (RangeOp::Lte, RangeOp::Gte)),
+        RangeOp::Lte => (RangeOp::Gte, (RangeOp::Gte, RangeOp::Lte)),
+        e => unreachable!("Require with noncomparator op: {:?}", e),
+    }
+}
+
 impl<T> Require for T where T: Variable + BinOp + Sized + AnalyzerLike {}
 pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
-    /// Handles a require expression
-    fn handle_require(&mut self, inputs: &[Expression], ctx: ContextNode) {
+    /// Handles a require expression. `kind` marks whether it is a `require` or `assert` written by
+    /// the user (as opposed to a branch condition), which is reported if it can revert
+    fn handle_require(&mut self, inputs: &[Expression], ctx: ContextNode, kind: RequireKind) {
         match inputs.get(0).expect("No lhs input for require statement") {
             Expression::Equal(loc, lhs, rhs) => {
                 let lhs_paths = self.parse_ctx_expr(lhs, ctx);
@@ -34,7 +107,10 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     RangeOp::Eq,
                     RangeOp::Neq,
                     (RangeOp::Neq, RangeOp::Eq),
+                    kind,
                 );
+                self.record_sum_relation(lhs, &rhs_paths);
+                self.record_sum_relation(rhs, &lhs_paths);
             }
             Expression::NotEqual(loc, lhs, rhs) => {
                 let lhs_paths = self.parse_ctx_expr(lhs, ctx);
@@ -46,6 +122,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     RangeOp::Neq,
                     RangeOp::Eq,
                     (RangeOp::Eq, RangeOp::Neq),
+                    kind,
                 );
             }
             Expression::Less(loc, lhs, rhs) => {
@@ -58,6 +135,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     RangeOp::Lt,
                     RangeOp::Gt,
                     (RangeOp::Gte, RangeOp::Lte),
+                    kind,
                 );
             }
             Expression::More(loc, lhs, rhs) => {
@@ -70,6 +148,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     RangeOp::Gt,
                     RangeOp::Lt,
                     (RangeOp::Lte, RangeOp::Gte),
+                    kind,
                 );
             }
             Expression::MoreEqual(loc, lhs, rhs) => {
@@ -82,6 +161,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     RangeOp::Gte,
                     RangeOp::Lte,
                     (RangeOp::Lte, RangeOp::Gte),
+                    kind,
                 );
             }
             Expression::LessEqual(loc, lhs, rhs) => {
@@ -94,6 +174,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     RangeOp::Lte,
                     RangeOp::Gte,
                     (RangeOp::Gte, RangeOp::Lte),
+                    kind,
                 );
             }
             Expression::Variable(ident) => {
@@ -110,8 +191,16 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     RangeOp::Eq,
                     RangeOp::Neq,
                     (RangeOp::Neq, RangeOp::Eq),
+                    kind,
                 );
             }
+            // a negated variable is required to be false below, anything else is applied as a
+            // condition that doesn't hold
+            Expression::Not(loc, inner)
+                if !matches!(**inner, Expression::Variable(_)) && is_condition(inner) =>
+            {
+                self.apply_condition(*loc, *inner.clone(), false, ctx, kind);
+            }
             Expression::Not(loc, lhs) => {
                 // println!("was not in require");
                 let lhs_paths = self.parse_ctx_expr(lhs, ctx);
@@ -129,8 +218,13 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     RangeOp::Eq,
                     RangeOp::Neq,
                     (RangeOp::Neq, RangeOp::Eq),
+                    kind,
                 );
             }
+            Expression::And(loc, lhs, rhs) if is_condition(lhs) && is_condition(rhs) => {
+                let cond = Expression::And(*loc, lhs.clone(), rhs.clone());
+                self.apply_condition(*loc, cond, true, ctx, kind);
+            }
             Expression::And(loc, lhs, rhs) => {
                 let lhs_paths = self.cmp(*loc, lhs, RangeOp::And, rhs, ctx);
                 let cnode = ConcreteNode::from(self.add_node(Node::Concrete(Concrete::Bool(true))));
@@ -147,6 +241,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     RangeOp::Eq,
                     RangeOp::Neq,
                     (RangeOp::Neq, RangeOp::Eq),
+                    kind,
                 );
             }
             Expression::Or(loc, lhs, rhs) => {
@@ -164,10 +259,233 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     RangeOp::Eq,
                     RangeOp::Neq,
                     (RangeOp::Neq, RangeOp::Eq),
+                    kind,
                 );
             }
+            Expression::ConditionalOperator(loc, if_expr, true_expr, false_expr) => {
+                // the require holds on two paths: the condition holds and so does the true
+                // expression, or the condition doesn't hold and the false expression does
+                let (true_subctx, false_subctx) = self.fork_ctx(*loc, ctx);
+
+                self.apply_condition(
+                    *loc,
+                    *if_expr.clone(),
+                    true,
+                    true_subctx,
+                    RequireKind::Implicit,
+                );
+                if !true_subctx.is_killed(self) {
+                    self.handle_require(&[*true_expr.clone()], true_subctx, kind);
+                }
+
+                self.apply_condition(
+                    *loc,
+                    *if_expr.clone(),
+                    false,
+                    false_subctx,
+                    RequireKind::Implicit,
+                );
+                if !false_subctx.is_killed(self) {
+                    self.handle_require(&[*false_expr.clone()], false_subctx, kind);
+                }
+            }
+            Expression::Parenthesis(_loc, inner) => {
+                self.handle_require(&[*inner.clone()], ctx, kind);
+            }
+            Expression::BoolLiteral(loc, val) => {
+                if !val {
+                    ctx.kill(self, *loc);
+                }
+            }
             e => unreachable!("Require expr with noncomparator: {:?}", e),
         }
+
+        if !ctx.is_killed(self) && ctx.live_forks(self).is_empty() {
+            let loc = inputs[0].loc();
+            self.apply_sum_relations(loc, ctx);
+        }
+    }
+
+    /// Records a `lhs + rhs == total` relation if `sum` is the sum of two variables and `total`
+    /// is what it was required to equal
+    fn record_sum_relation(&mut self, sum: &Expression, total: &ExprRet) {
+        let (lhs, rhs) = match sum {
+            Expression::Add(_, lhs, rhs) => match (&**lhs, &**rhs) {
+                (Expression::Variable(lhs), Expression::Variable(rhs)) => {
+                    (lhs.name.clone(), rhs.name.clone())
+                }
+                _ => return,
+            },
+            _ => return,
+        };
+        if let ExprRet::Single((ctx, total)) | ExprRet::SingleLiteral((ctx, total)) = total {
+            if ctx.is_killed(self) {
+                return;
+            }
+            let relation = SumRelation {
+                lhs,
+                rhs,
+                total: ContextVarNode::from(*total),
+            };
+            ctx.underlying_mut(self).sum_relations.push(relation);
+        }
+    }
+
+    /// Narrows both sides of each of the context's sum relations by the ranges of the other
+    /// side and the total, i.e. with `a + b == 100` and `a >= 60`, `b` is narrowed to at most 40
+    fn apply_sum_relations(&mut self, loc: Loc, ctx: ContextNode) {
+        let relations = ctx.underlying(self).sum_relations.clone();
+        for relation in relations {
+            let total = ctx
+                .latest_var_by_name(self, &relation.total.name(self))
+                .unwrap_or_else(|| relation.total.latest_version(self));
+            let (lhs, rhs) = match (
+                ctx.latest_var_by_name(self, &relation.lhs),
+                ctx.latest_var_by_name(self, &relation.rhs),
+            ) {
+                (Some(lhs), Some(rhs)) => (lhs, rhs),
+                _ => continue,
+            };
+            self.narrow_by_sum(loc, ctx, lhs, rhs, total);
+            let lhs = lhs.latest_version(self);
+            self.narrow_by_sum(loc, ctx, rhs, lhs, total);
+            if ctx.is_killed(self) {
+                return;
+            }
+        }
+    }
+
+    /// Narrows `var` given `var + other == total` to
+    /// `[total.min - other.max, total.max - other.min]`, killing the context if that leaves no
+    /// possible values. Only done when all three are unsigned with concrete bounds
+    fn narrow_by_sum(
+        &mut self,
+        loc: Loc,
+        ctx: ContextNode,
+        var: ContextVarNode,
+        other: ContextVarNode,
+        total: ContextVarNode,
+    ) {
+        if [var, other, total].iter().any(|var| var.is_int(self)) {
+            return;
+        }
+        let bounds = |var: ContextVarNode| Some((var.min_u256(self)?, var.max_u256(self)?));
+        let ((var_min, var_max), (other_min, other_max), (total_min, total_max)) =
+            match (bounds(var), bounds(other), bounds(total)) {
+                (Some(var), Some(other), Some(total)) => (var, other, total),
+                _ => return,
+            };
+
+        let new_min = var_min.max(total_min.saturating_sub(other_max));
+        let new_max = match total_max.checked_sub(other_min) {
+            Some(max) => var_max.min(max),
+            None => {
+                ctx.kill(self, loc);
+                return;
+            }
+        };
+        if new_min > new_max {
+            ctx.kill(self, loc);
+            return;
+        }
+        if new_min == var_min && new_max == var_max {
+            return;
+        }
+
+        let new_var = self.advance_var_in_ctx(var, loc, ctx);
+        let cast_to = new_var.range_min(self).expect("No range minimum?");
+        new_var.set_range_min(
+            self,
+            Elem::from(Concrete::from(new_min)).cast(cast_to.clone()),
+        );
+        new_var.set_range_max(self, Elem::from(Concrete::from(new_max)).cast(cast_to));
+    }
+
+    /// Narrows the ranges in the context by assuming the condition holds, or that it doesn't if
+    /// `holds` is false. A conjunction that holds (or a disjunction that doesn't) constrains by
+    /// each side in turn and a negation flips `holds`, while any other condition is required
+    /// directly. Both `require` and branch conditions are applied this way so that they narrow
+    /// identically. The constraints are made at the given location
+    fn apply_condition(
+        &mut self,
+        loc: Loc,
+        cond: Expression,
+        holds: bool,
+        ctx: ContextNode,
+        kind: RequireKind,
+    ) {
+        match (cond, holds) {
+            (Expression::Parenthesis(_loc, inner), _) => {
+                self.apply_condition(loc, *inner, holds, ctx, kind);
+            }
+            (Expression::Not(_loc, inner), _) => {
+                self.apply_condition(loc, *inner, !holds, ctx, kind);
+            }
+            (Expression::And(_loc, lhs, rhs), true) | (Expression::Or(_loc, lhs, rhs), false) => {
+                self.apply_condition(loc, *lhs, holds, ctx, kind);
+                if !ctx.is_killed(self) {
+                    self.apply_condition(loc, *rhs, holds, ctx, kind);
+                }
+            }
+            (cond, true) => self.handle_require(&[at_loc(loc, cond)], ctx, kind),
+            (cond, false) => {
+                let inv_cond = self.inverse_expr(loc, cond);
+                self.handle_require(&[inv_cond], ctx, kind);
+            }
+        }
+    }
+
+    /// Forks the context into two subcontexts, returning the (true, false) subcontexts
+    fn fork_ctx(&mut self, loc: Loc, ctx: ContextNode) -> (ContextNode, ContextNode) {
+        let true_subctx = ContextNode::from(self.add_node(Node::Context(Context::new_subctx(
+            ctx, loc, true, None, false, self, None,
+        ))));
+        ctx.add_fork(true_subctx, self);
+        let false_subctx = ContextNode::from(self.add_node(Node::Context(Context::new_subctx(
+            ctx, loc, true, None, false, self, None,
+        ))));
+        ctx.add_fork(false_subctx, self);
+        let ctx_fork = self.add_node(Node::ContextFork);
+        self.add_edge(ctx_fork, ctx, Edge::Context(ContextEdge::ContextFork));
+        self.add_edge(
+            NodeIdx::from(true_subctx.0),
+            ctx_fork,
+            Edge::Context(ContextEdge::Subcontext),
+        );
+        self.add_edge(
+            NodeIdx::from(false_subctx.0),
+            ctx_fork,
+            Edge::Context(ContextEdge::Subcontext),
+        );
+        (true_subctx, false_subctx)
+    }
+
+    /// Inverts a boolean expression, i.e. `a < b` becomes `a >= b` and `a && b` becomes
+    /// `!a || !b`
+    fn inverse_expr(&self, loc: Loc, expr: Expression) -> Expression {
+        match expr {
+            Expression::Equal(_loc, lhs, rhs) => Expression::NotEqual(loc, lhs, rhs),
+            Expression::NotEqual(_loc, lhs, rhs) => Expression::Equal(loc, lhs, rhs),
+            Expression::Less(_loc, lhs, rhs) => Expression::MoreEqual(loc, lhs, rhs),
+            Expression::More(_loc, lhs, rhs) => Expression::LessEqual(loc, lhs, rhs),
+            Expression::MoreEqual(_loc, lhs, rhs) => Expression::Less(loc, lhs, rhs),
+            Expression::LessEqual(_loc, lhs, rhs) => Expression::More(loc, lhs, rhs),
+            Expression::Variable(ref _ident) => Expression::Not(loc, Box::new(expr)),
+            Expression::Parenthesis(_loc, inner) => self.inverse_expr(loc, *inner),
+            Expression::Not(_loc, inner) => *inner,
+            // De Morgan's laws
+            Expression::And(_loc, lhs, rhs) => Expression::Or(
+                loc,
+                Box::new(self.inverse_expr(loc, *lhs)),
+                Box::new(self.inverse_expr(loc, *rhs)),
+            ),
+            Expression::Or(_loc, lhs, rhs) => Expression::And(
+                loc,
+                Box::new(self.inverse_expr(loc, *lhs)),
+                Box::new(self.inverse_expr(loc, *rhs)),
+            ),
+            e => todo!("Wasnt comparator: {:?}", e),
+        }
     }
 
     fn handle_require_inner(
@@ -178,7 +496,16 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
         op: RangeOp,
         rhs_op: RangeOp,
         recursion_ops: (RangeOp, RangeOp),
+        kind: RequireKind,
     ) {
+        if self.is_chained_cmp(lhs_paths, op, rhs_paths) {
+            if let ExprRet::Single((ctx, _)) | ExprRet::SingleLiteral((ctx, _)) = lhs_paths {
+                ctx.underlying_mut(self)
+                    .add_finding(ContextFinding::ChainedComparison(loc));
+            }
+            return;
+        }
+
         match (lhs_paths, rhs_paths) {
             (_, ExprRet::CtxKilled) => {}
             (ExprRet::CtxKilled, _) => {}
@@ -191,6 +518,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     op,
                     rhs_op,
                     recursion_ops,
+                    kind,
                 )
             }
             (ExprRet::Single((_lhs_ctx, lhs)), ExprRet::SingleLiteral((rhs_ctx, rhs))) => {
@@ -202,29 +530,61 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     op,
                     rhs_op,
                     recursion_ops,
+                    kind,
                 )
             }
             (ExprRet::Single((lhs_ctx, lhs)), ExprRet::Single((rhs_ctx, rhs))) => {
                 let lhs_cvar = ContextVarNode::from(*lhs);
                 let rhs_cvar = ContextVarNode::from(*rhs);
+                // only equality is fragile, as ether forced into the contract can break it
+                if matches!(op, RangeOp::Eq)
+                    && (lhs_cvar.is_self_balance(self) || rhs_cvar.is_self_balance(self))
+                {
+                    lhs_ctx
+                        .underlying_mut(self)
+                        .add_finding(ContextFinding::StrictBalanceCheck(loc));
+                }
+                if lhs_cvar.involves_sender(self) || rhs_cvar.involves_sender(self) {
+                    lhs_ctx
+                        .underlying_mut(self)
+                        .add_finding(ContextFinding::SenderCheck(loc));
+                }
                 let new_lhs = self.advance_var_in_ctx(lhs_cvar, loc, *lhs_ctx);
                 let new_rhs = self.advance_var_in_ctx(rhs_cvar, loc, *lhs_ctx);
 
-                self.require(new_lhs, new_rhs, *lhs_ctx, loc, op, rhs_op, recursion_ops);
+                self.require(
+                    new_lhs,
+                    new_rhs,
+                    *lhs_ctx,
+                    loc,
+                    op,
+                    rhs_op,
+                    recursion_ops,
+                    kind,
+                );
                 if lhs_ctx != rhs_ctx {
                     let new_lhs = self.advance_var_in_ctx(lhs_cvar, loc, *rhs_ctx);
                     let new_rhs = self.advance_var_in_ctx(rhs_cvar, loc, *rhs_ctx);
-                    self.require(new_lhs, new_rhs, *rhs_ctx, loc, op, rhs_op, recursion_ops);
+                    self.require(
+                        new_lhs,
+                        new_rhs,
+                        *rhs_ctx,
+                        loc,
+                        op,
+                        rhs_op,
+                        recursion_ops,
+                        kind,
+                    );
                 }
             }
             (l @ ExprRet::Single((_lhs_ctx, _lhs)), ExprRet::Multi(rhs_sides)) => {
                 rhs_sides.iter().for_each(|expr_ret| {
-                    self.handle_require_inner(loc, l, expr_ret, op, rhs_op, recursion_ops)
+                    self.handle_require_inner(loc, l, expr_ret, op, rhs_op, recursion_ops, kind)
                 });
             }
             (ExprRet::Multi(lhs_sides), r @ ExprRet::Single(_)) => {
                 lhs_sides.iter().for_each(|expr_ret| {
-                    self.handle_require_inner(loc, expr_ret, r, op, rhs_op, recursion_ops)
+                    self.handle_require_inner(loc, expr_ret, r, op, rhs_op, recursion_ops, kind)
                 });
             }
             (ExprRet::Multi(lhs_sides), ExprRet::Multi(rhs_sides)) => {
@@ -239,6 +599,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                                 op,
                                 rhs_op,
                                 recursion_ops,
+                                kind,
                             )
                         },
                     );
@@ -251,23 +612,56 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                             op,
                             rhs_op,
                             recursion_ops,
+                            kind,
                         )
                     });
                 }
             }
             (ExprRet::Fork(lhs_world1, lhs_world2), ExprRet::Fork(rhs_world1, rhs_world2)) => {
-                self.handle_require_inner(loc, lhs_world1, rhs_world1, op, rhs_op, recursion_ops);
-                self.handle_require_inner(loc, lhs_world1, rhs_world2, op, rhs_op, recursion_ops);
-                self.handle_require_inner(loc, lhs_world2, rhs_world1, op, rhs_op, recursion_ops);
-                self.handle_require_inner(loc, lhs_world2, rhs_world2, op, rhs_op, recursion_ops);
+                self.handle_require_inner(
+                    loc,
+                    lhs_world1,
+                    rhs_world1,
+                    op,
+                    rhs_op,
+                    recursion_ops,
+                    kind,
+                );
+                self.handle_require_inner(
+                    loc,
+                    lhs_world1,
+                    rhs_world2,
+                    op,
+                    rhs_op,
+                    recursion_ops,
+                    kind,
+                );
+                self.handle_require_inner(
+                    loc,
+                    lhs_world2,
+                    rhs_world1,
+                    op,
+                    rhs_op,
+                    recursion_ops,
+                    kind,
+                );
+                self.handle_require_inner(
+                    loc,
+                    lhs_world2,
+                    rhs_world2,
+                    op,
+                    rhs_op,
+                    recursion_ops,
+                    kind,
+                );
             }
             (l @ ExprRet::Single(_), ExprRet::Fork(world1, world2)) => {
-                self.handle_require_inner(loc, l, world1, op, rhs_op, recursion_ops);
-                self.handle_require_inner(loc, l, world2, op, rhs_op, recursion_ops);
+                self.handle_require_inner(loc, l, world1, op, rhs_op, recursion_ops, kind);
+                self.handle_require_inner(loc, l, world2, op, rhs_op, recursion_ops, kind);
             }
             (m @ ExprRet::Multi(_), ExprRet::Fork(world1, world2)) => {
-                self.handle_require_inner(loc, m, world1, op, rhs_op, recursion_ops);
-                self.handle_require_inner(loc, m, world2, op, rhs_op, recursion_ops);
+                self.handle_require_inner(loc, m, world1, op, rhs_op, recursion_ops, kind);
+                self.handle_require_inner(loc, m, world2, op, rhs_op, recursion_ops, kind);
             }
             (e, f) => todo!("any: {:?} {:?}", e, f),
         }
@@ -285,6 +679,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
         op: RangeOp,
         rhs_op: RangeOp,
         recursion_ops: (RangeOp, RangeOp),
+        kind: RequireKind,
     ) -> Option<ContextVarNode> {
         let mut any_unsat = false;
         let mut tmp_cvar = None;
@@ -298,6 +693,25 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
 
             if let Some(mut rhs_range) = new_rhs.range(self) {
                 rhs_range.update_deps(ctx, self);
+                match kind {
+                    // an assert should never fail, so it is reported regardless of strictness
+                    RequireKind::Assert if self.require_can_fail(op, &lhs_range, &rhs_range) => {
+                        ctx.underlying_mut(self)
+                            .add_finding(ContextFinding::FailableAssert(loc));
+                    }
+                    RequireKind::Require if self.require_can_fail(op, &lhs_range, &rhs_range) => {
+                        if self.strict_requires() {
+                            ctx.underlying_mut(self)
+                                .add_finding(ContextFinding::FailableRequire(loc));
+                        }
+                    }
+                    // a require that can't fail is already guaranteed by what came before it
+                    RequireKind::Require => {
+                        ctx.underlying_mut(self)
+                            .add_finding(ContextFinding::RedundantRequire(loc));
+                    }
+                    _ => {}
+                }
                 let lhs_is_const = new_lhs.is_const(self);
                 let rhs_is_const = new_rhs.is_const(self);
                 match (lhs_is_const, rhs_is_const) {
@@ -422,6 +836,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
                     SolcRange::from(Concrete::Bool(true)),
                 ),
+                metadata: Default::default(),
             };
 
             let cvar = ContextVarNode::from(self.add_node(Node::ContextVar(tmp_var)));
@@ -438,7 +853,12 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
             ctx.add_ctx_dep(cvar, self);
         }
 
-        if let Some(tmp) = new_lhs.tmp_of(self) {
+        if let Some((underlying, adjusted_rhs, flip)) =
+            self.invert_const_arith(loc, new_lhs, new_rhs, ctx)
+        {
+            let underlying_op = if flip { flip_cmp_op(op) } else { op };
+            self.require_underlying(loc, underlying, adjusted_rhs, underlying_op, ctx);
+        } else if let Some(tmp) = new_lhs.tmp_of(self) {
             if tmp.op.inverse().is_some() {
                 self.range_recursion(tmp, recursion_ops, new_rhs, ctx, loc, &mut any_unsat)
             } else {
@@ -446,9 +866,127 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
             }
         }
 
+        if ctx.is_killed(self) {
+            return None;
+        }
+
+        if let Some((underlying, adjusted_lhs, flip)) =
+            self.invert_const_arith(loc, new_rhs, new_lhs, ctx)
+        {
+            // the derived value is on the right hand side, so the comparison is flipped to put
+            // the underlying variable on the left
+            let underlying_op = if flip { op } else { flip_cmp_op(op) };
+            self.require_underlying(loc, underlying, adjusted_lhs, underlying_op, ctx);
+        }
+
         tmp_cvar
     }
 
+    /// If the derived variable is a temporary of a non-constant variable plus or minus a constant
+    /// and the other side of the comparison is constant, moves the arithmetic to the other side.
+    /// Returns the underlying variable, the adjusted other side and whether the comparison flips,
+    /// i.e. `x + c op y` becomes `x op y - c` and `c - x op y` becomes `x flip(op) c - y`
+    fn invert_const_arith(
+        &mut self,
+        loc: Loc,
+        derived: ContextVarNode,
+        other: ContextVarNode,
+        ctx: ContextNode,
+    ) -> Option<(ContextVarNode, ContextVarNode, bool)> {
+        let tmp = derived.tmp_of(self)?;
+        let rhs = tmp.rhs?;
+        if !other.is_const(self) {
+            return None;
+        }
+
+        let (underlying, adjusted_lhs, adjusted_op, adjusted_rhs, flip) =
+            match (tmp.op, tmp.lhs.is_const(self), rhs.is_const(self)) {
+                (RangeOp::Add, false, true) => (tmp.lhs, other, RangeOp::Sub, rhs, false),
+                (RangeOp::Add, true, false) => (rhs, other, RangeOp::Sub, tmp.lhs, false),
+                (RangeOp::Sub, false, true) => (tmp.lhs, other, RangeOp::Add, rhs, false),
+                (RangeOp::Sub, true, false) => (rhs, tmp.lhs, RangeOp::Sub, other, true),
+                _ => return None,
+            };
+
+        // an unsigned subtraction that would underflow kills the context, so leave it to the
+        // usual range update instead
+        if adjusted_op == RangeOp::Sub && !adjusted_lhs.is_int(self) {
+            if let (Some(lmax), Some(rmin)) = (
+                adjusted_lhs.evaled_range_max(self),
+                adjusted_rhs.evaled_range_min(self),
+            ) {
+                if matches!(
+                    lmax.range_ord(&rmin),
+                    Some(Ordering::Less) | Some(Ordering::Equal)
+                ) {
+                    return None;
+                }
+            }
+        }
+
+        match self.op(loc, adjusted_lhs, adjusted_rhs, ctx, adjusted_op, false) {
+            ExprRet::Single((_, adjusted)) => Some((underlying, adjusted.into(), flip)),
+            _ => None,
+        }
+    }
+
+    /// Requires the comparison of the latest version of the underlying variable of a derived
+    /// value against the adjusted other side of the comparison
+    fn require_underlying(
+        &mut self,
+        loc: Loc,
+        underlying: ContextVarNode,
+        adjusted: ContextVarNode,
+        op: RangeOp,
+        ctx: ContextNode,
+    ) {
+        let (rhs_op, recursion_ops) = require_ops(op);
+        let new_underlying = self.advance_var_in_ctx(underlying.latest_version(self), loc, ctx);
+        self.require(
+            new_underlying,
+            adjusted,
+            ctx,
+            loc,
+            op,
+            rhs_op,
+            recursion_ops,
+            RequireKind::Implicit,
+        );
+    }
+
+    /// Checks whether the ranges allow the comparison to be false, i.e. whether a require of it
+    /// can revert
+    fn require_can_fail(&self, op: RangeOp, lhs_range: &SolcRange, rhs_range: &SolcRange) -> bool {
+        let lhs_min = lhs_range.evaled_range_min(self);
+        let lhs_max = lhs_range.evaled_range_max(self);
+        let rhs_min = rhs_range.evaled_range_min(self);
+        let rhs_max = rhs_range.evaled_range_max(self);
+        // comparisons that can't be ordered are assumed to be able to fail
+        let can_be = |lhs: &Elem<Concrete>, rhs: &Elem<Concrete>, ords: &[Ordering]| {
+            lhs.range_ord(rhs).map_or(true, |ord| ords.contains(&ord))
+        };
+        match op {
+            // fails unless both sides are the same single value
+            RangeOp::Eq => {
+                !(lhs_min.range_eq(&lhs_max)
+                    && rhs_min.range_eq(&rhs_max)
+                    && lhs_min.range_eq(&rhs_min))
+            }
+            // fails if the ranges overlap
+            RangeOp::Neq => {
+                can_be(&lhs_min, &rhs_max, &[Ordering::Less, Ordering::Equal])
+                    && can_be(&rhs_min, &lhs_max, &[Ordering::Less, Ordering::Equal])
+            }
+            // fails if lhs can be at least rhs
+            RangeOp::Lt => can_be(&lhs_max, &rhs_min, &[Ordering::Greater, Ordering::Equal]),
+            RangeOp::Lte => can_be(&lhs_max, &rhs_min, &[Ordering::Greater]),
+            // fails if lhs can be at most rhs
+            RangeOp::Gt => can_be(&lhs_min, &rhs_max, &[Ordering::Less, Ordering::Equal]),
+            RangeOp::Gte => can_be(&lhs_min, &rhs_max, &[Ordering::Less]),
+            _ => true,
+        }
+    }
+
     /// Checks and returns whether the require statement is killable (i.e. impossible)
     fn const_killable(&mut self, op: RangeOp, lhs_range: SolcRange, rhs_range: SolcRange) -> bool {
         // check that the op is satisfied, return it as a bool
@@ -522,8 +1060,9 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                 let elem = Elem::Dynamic(Dynamic::new(const_var.latest_version(self).into(), loc));
 
                 // potentially add the const var as a range exclusion
-                if let Some(Ordering::Equal) =
-                    nonconst_range.evaled_range_min(self).range_ord(&elem)
+                if let Some(Ordering::Equal) = nonconst_range
+                    .evaled_range_min(self)
+                    .range_ord(&elem.minimize(self))
                 {
                     // mins are equivalent, add 1 instead of adding an exclusion
                     let min = nonconst_range
@@ -533,8 +1072,9 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     let one = Concrete::one(&min.val).expect("Cannot increment range elem by one");
                     let min = nonconst_range.range_min() + Elem::from(one);
                     nonconst_var.set_range_min(self, min);
-                } else if let Some(std::cmp::Ordering::Equal) =
-                    nonconst_range.evaled_range_max(self).range_ord(&elem)
+                } else if let Some(std::cmp::Ordering::Equal) = nonconst_range
+                    .evaled_range_max(self)
+                    .range_ord(&elem.minimize(self))
                 {
                     // maxs are equivalent, subtract 1 instead of adding an exclusion
                     let max = nonconst_range
@@ -674,6 +1214,22 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                     }
                 }
 
+                // addresses have no meaningful order, so tightening the bounds rarely narrows
+                // them. Instead one side is made equal to the other, i.e. after
+                // `require(msg.sender == owner)`, `msg.sender` has the range of `owner`. Only one
+                // side refers to the other to avoid a cycle, and a storage variable is kept as
+                // the reference as it outlives the call
+                if new_lhs.is_address(self) && new_rhs.is_address(self) {
+                    let (eq_var, to) = if new_lhs.is_storage(self) && !new_rhs.is_storage(self) {
+                        (new_rhs, new_lhs)
+                    } else {
+                        (new_lhs, new_rhs)
+                    };
+                    let to_elem = Elem::Dynamic(Dynamic::new(to.latest_version(self).into(), loc));
+                    eq_var.set_range_min(self, to_elem.clone());
+                    eq_var.set_range_max(self, to_elem);
+                }
+
                 false
             }
             RangeOp::Neq => {
@@ -708,7 +1264,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
 
                 // we add/sub one to the element because its strict >
                 new_lhs.set_range_min(self, rhs_elem + one.clone().into());
-                new_rhs.set_range_max(self, lhs_range.range_min() - one.into());
+                new_rhs.set_range_max(self, lhs_range.range_max() - one.into());
                 false
             }
             RangeOp::Gte => {
@@ -726,7 +1282,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                 }
 
                 new_lhs.set_range_min(self, rhs_elem);
-                new_rhs.set_range_max(self, lhs_range.range_min());
+                new_rhs.set_range_max(self, lhs_range.range_max());
                 false
             }
             RangeOp::Lt => {
@@ -747,7 +1303,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                 let one = Concrete::one(&min_conc.val).expect("Cannot decrement range elem by one");
 
                 new_lhs.set_range_max(self, rhs_elem - one.clone().into());
-                new_rhs.set_range_min(self, lhs_range.range_max() + one.into());
+                new_rhs.set_range_min(self, lhs_range.range_min() + one.into());
                 false
             }
             RangeOp::Lte => {
@@ -764,7 +1320,7 @@ pub trait Require: AnalyzerLike + Variable + BinOp + Sized {
                 }
 
                 new_lhs.set_range_max(self, rhs_elem);
-                new_rhs.set_range_max(self, lhs_range.range_min());
+                new_rhs.set_range_min(self, lhs_range.range_min());
                 false
             }
             e => todo!("Non-comparator in require, {e:?}"),
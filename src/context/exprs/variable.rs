@@ -1,6 +1,6 @@
 use crate::{
     context::{exprs::env::Env, ContextBuilder},
-    Concrete, ExprRet,
+    Builtin, Concrete, ExprRet,
 };
 use shared::{
     analyzer::AnalyzerLike,
@@ -36,6 +36,8 @@ pub trait Variable: AnalyzerLike<Expr = Expression> + Sized {
                 }
                 _ => ExprRet::Single((ctx, cvar)),
             }
+        } else if let Some(builtin) = Builtin::try_from_fixed_name(&ident.name) {
+            ExprRet::Single((ctx, self.builtin_or_add(builtin)))
         } else if let Some(idx) = self.user_types().get(&ident.name) {
             let mut var = match ContextVar::maybe_from_user_ty(self, ident.loc, *idx) {
                 Some(v) => v,
@@ -46,11 +48,15 @@ pub trait Variable: AnalyzerLike<Expr = Expression> + Sized {
                 ),
             };
 
-            // We assume a storage variable is 0 to start with
+            // An immutable storage variable keeps whatever the constructor left it with, otherwise
+            // we assume a storage variable is 0 to start with
             // TODO: check if there is an initializer that we should take into account
             if let Some(r) = var.fallback_range(self) {
                 if var.storage.is_some() {
-                    if let Elem::Concrete(c) = r.range_max() {
+                    if let Some((min, max)) = self.constructor_bounds(ctx, *idx) {
+                        var.set_range_min(min, Some(r.clone()));
+                        var.set_range_max(max, Some(r));
+                    } else if let Elem::Concrete(c) = r.range_max() {
                         if let Some(size) = c.val.int_size() {
                             var.set_range_max(Elem::from(Concrete::Uint(size, 0.into())), None)
                         }
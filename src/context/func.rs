@@ -1,6 +1,9 @@
+use crate::context::exprs::Abi;
 use crate::context::exprs::Array;
+use crate::context::exprs::Env;
 use crate::context::exprs::MemberAccess;
-use crate::context::exprs::Require;
+use crate::context::exprs::{Require, RequireKind};
+use crate::context::taint::TaintTracker;
 use crate::context::ContextBuilder;
 use crate::ExprRet;
 use shared::analyzer::AsDotStr;
@@ -8,13 +11,16 @@ use shared::analyzer::GraphLike;
 use shared::context::*;
 use std::collections::BTreeMap;
 
-use shared::range::elem_ty::Dynamic;
+use shared::range::elem::{RangeElem, RangeOp};
+use shared::range::elem_ty::{Dynamic, RangeConcrete};
 
 use shared::range::Range;
 use shared::range::{elem_ty::Elem, SolcRange};
-use solang_parser::pt::{Expression, Identifier, Loc, StorageLocation};
+use solang_parser::pt::{CodeLocation, Expression, Identifier, Loc, StorageLocation};
 
 use crate::VarType;
+use ethers_core::types::{H256, U256};
+use ethers_core::utils::keccak256;
 
 use shared::{analyzer::AnalyzerLike, nodes::*, Edge, Node, NodeIdx};
 
@@ -30,8 +36,43 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
         use solang_parser::pt::Expression::*;
         match func_expr {
             MemberAccess(loc, member_expr, ident) => {
+                if let Variable(Identifier { name, .. }) = &**member_expr {
+                    if name == "abi" {
+                        return self.abi_call(*loc, &ident.name, input_exprs, ctx);
+                    }
+                }
+
                 let (mem_ctx, member) = self.parse_ctx_expr(member_expr, ctx).expect_single();
 
+                if matches!(&*ident.name, "transfer" | "send") && input_exprs.len() == 1 {
+                    if let VarType::BuiltIn(bn, _) = ContextVarNode::from(member).ty(self) {
+                        if matches!(
+                            bn.underlying(self),
+                            Builtin::Address | Builtin::AddressPayable | Builtin::Payable
+                        ) {
+                            return self.transfer_out(
+                                *loc,
+                                ident.name == "send",
+                                &input_exprs[0],
+                                ctx,
+                            );
+                        }
+                    }
+                }
+
+                if matches!(&*ident.name, "call" | "delegatecall" | "staticcall")
+                    && ContextVarNode::from(member).is_address(self)
+                {
+                    return self.low_level_call(
+                        *loc,
+                        &ident.name,
+                        ContextVarNode::from(member).latest_version(self),
+                        input_exprs,
+                        None,
+                        ctx,
+                    );
+                }
+
                 let inputs = match ContextVarNode::from(member).underlying(self).ty {
                     VarType::User(TypeNode::Contract(_)) => input_exprs
                         .iter()
@@ -53,15 +94,15 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
                 if !inputs.has_literal() {
                     let as_input_str = inputs.try_as_func_input_str(self);
 
-                    let (_func_ctx, func_idx) = match self.parse_ctx_expr(
-                        &MemberAccess(
-                            *loc,
-                            member_expr.clone(),
-                            Identifier {
-                                loc: ident.loc,
-                                name: format!("{}{}", ident.name, as_input_str),
-                            },
-                        ),
+                    // the member was already parsed, and parsing it again would repeat any
+                    // calls in it, i.e. `token.underlying()` in `token.underlying().balanceOf(x)`
+                    let (_func_ctx, func_idx) = match self.member_access_inner(
+                        *loc,
+                        member,
+                        &Identifier {
+                            loc: ident.loc,
+                            name: format!("{}{}", ident.name, as_input_str),
+                        },
                         ctx,
                     ) {
                         ExprRet::Single((ctx, idx)) => (ctx, idx),
@@ -196,6 +237,42 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
                     }
                 }
             }
+            FunctionCallBlock(_, block_func_expr, block) => {
+                // i.e. `target.call{value: amount}(data)`
+                if let MemberAccess(_, member_expr, ident) = &**block_func_expr {
+                    if matches!(&*ident.name, "call" | "delegatecall" | "staticcall") {
+                        let (_, member) = self.parse_ctx_expr(member_expr, ctx).expect_single();
+                        let target = ContextVarNode::from(member).latest_version(self);
+                        if target.is_address(self) {
+                            let mut value = None;
+                            if let solang_parser::pt::Statement::Args(_, args) = &**block {
+                                for arg in args.iter() {
+                                    match self.parse_ctx_expr(&arg.expr, ctx) {
+                                        ExprRet::Single((_, var))
+                                        | ExprRet::SingleLiteral((_, var))
+                                            if arg.name.name == "value" =>
+                                        {
+                                            value =
+                                                Some(ContextVarNode::from(var).latest_version(self))
+                                        }
+                                        ExprRet::CtxKilled => return ExprRet::CtxKilled,
+                                        _ => {}
+                                    }
+                                }
+                            }
+                            return self.low_level_call(
+                                *loc,
+                                &ident.name,
+                                target,
+                                input_exprs,
+                                value,
+                                ctx,
+                            );
+                        }
+                    }
+                }
+                todo!("Function call block")
+            }
             _ => {
                 let (func_ctx, func_idx) = match self.parse_ctx_expr(func_expr, ctx) {
                     ExprRet::Single((ctx, idx)) => (ctx, idx),
@@ -311,6 +388,68 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
         }
     }
 
+    /// Allocates a new dynamically sized array, `bytes` or `string` of type `ty_idx`, i.e.
+    /// `new uint256[](n)` or `new bytes(n)`, whose length is the value of `len_expr`
+    fn new_dyn_builtin(
+        &mut self,
+        loc: &Loc,
+        ty_idx: NodeIdx,
+        len_expr: &Expression,
+        ctx: ContextNode,
+    ) -> ExprRet {
+        let (ctx, len_cvar) = self.parse_ctx_expr(len_expr, ctx).expect_single();
+        let ty = VarType::try_from_idx(self, ty_idx);
+
+        let new_arr = ContextVar {
+            loc: Some(*loc),
+            name: format!("tmp_arr{}", ctx.new_tmp(self)),
+            display_name: "arr".to_string(),
+            storage: None,
+            is_tmp: true,
+            is_symbolic: false,
+            tmp_of: None,
+            ty: ty.expect("No type for node"),
+            metadata: Default::default(),
+        };
+
+        let arr = ContextVarNode::from(self.add_node(Node::ContextVar(new_arr)));
+
+        let len_var = ContextVar {
+            loc: Some(*loc),
+            name: arr.name(self) + ".length",
+            display_name: arr.display_name(self) + ".length",
+            storage: None,
+            is_tmp: true,
+            tmp_of: None,
+            is_symbolic: true,
+            ty: ContextVarNode::from(len_cvar).underlying(self).ty.clone(),
+            metadata: Default::default(),
+        };
+
+        let len_cvar = self.add_node(Node::ContextVar(len_var));
+        self.add_edge(arr, ctx, Edge::Context(ContextEdge::Variable));
+        self.add_edge(len_cvar, ctx, Edge::Context(ContextEdge::Variable));
+        self.add_edge(len_cvar, arr, Edge::Context(ContextEdge::AttrAccess));
+
+        // update the length
+        if let Some(r) = arr.range(self) {
+            let min = r.evaled_range_min(self);
+            let max = r.evaled_range_max(self);
+
+            if let Some(mut rd) = min.maybe_range_dyn() {
+                rd.len = Elem::Dynamic(Dynamic::new(len_cvar, *loc));
+                arr.set_range_min(self, Elem::ConcreteDyn(Box::new(rd)));
+            }
+
+            if let Some(mut rd) = max.maybe_range_dyn() {
+                rd.len = Elem::Dynamic(Dynamic::new(len_cvar, *loc));
+                arr.set_range_max(self, Elem::ConcreteDyn(Box::new(rd)))
+            }
+        }
+
+        ExprRet::Single((ctx, arr.into()))
+    }
+
     /// Calls an intrinsic/builtin function call (casts, require, etc.)
     fn intrinsic_func_call(
         &mut self,
@@ -323,8 +462,12 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
             Node::Function(underlying) => {
                 if let Some(func_name) = &underlying.name {
                     match &*func_name.name {
-                        "require" | "assert" => {
-                            self.handle_require(input_exprs, ctx);
+                        "require" => {
+                            self.handle_require(input_exprs, ctx, RequireKind::Require);
+                            ExprRet::Multi(vec![])
+                        }
+                        "assert" => {
+                            self.handle_require(input_exprs, ctx, RequireKind::Assert);
                             ExprRet::Multi(vec![])
                         }
                         "type" => ExprRet::Single(
@@ -362,6 +505,60 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
                             let cvar = self.add_node(Node::ContextVar(var));
                             ExprRet::Single((ctx, cvar))
                         }
+                        "gasleft" | "blockhash" => {
+                            let func_name = func_name.name.clone();
+                            input_exprs.iter().for_each(|expr| {
+                                // we want to parse even though we dont need the variables here
+                                let _ = self.parse_ctx_expr(expr, ctx);
+                            });
+                            // the return is unconstrained, i.e. the full range of its type
+                            let ret = FunctionNode::from(func_idx).returns(self)[0];
+                            let ret_ty = ret.underlying(self).ty;
+                            let mut var = ContextVar::new_from_builtin(*loc, ret_ty.into(), self);
+                            var.name = format!("tmp{}({func_name}())", ctx.new_tmp(self));
+                            var.display_name = format!("{func_name}()");
+                            var.is_symbolic = true;
+                            let cvar = self.add_node(Node::ContextVar(var));
+                            self.add_edge(cvar, ctx, Edge::Context(ContextEdge::Variable));
+                            if func_name == "gasleft" {
+                                // gas only decreases, so this is at most the previous read
+                                let cvar = ContextVarNode::from(cvar);
+                                if let Some(prev) = ctx.underlying(self).last_gasleft {
+                                    let prev = prev.latest_version(self);
+                                    cvar.set_range_max(
+                                        self,
+                                        Elem::Dynamic(Dynamic::new(prev.into(), *loc)),
+                                    );
+                                }
+                                ctx.underlying_mut(self).last_gasleft = Some(cvar);
+                            }
+                            ExprRet::Single((ctx, cvar))
+                        }
+                        "keccak256" => {
+                            let input = match self.parse_hash_input(&input_exprs[0], ctx) {
+                                ExprRet::CtxKilled => return ExprRet::CtxKilled,
+                                ExprRet::Single((_, input))
+                                | ExprRet::SingleLiteral((_, input)) => {
+                                    self.concrete_value(input.into())
+                                }
+                                _ => None,
+                            };
+                            // a constant input hashes to a known value
+                            if let Some(bytes) = input.and_then(|input| input.packed_bytes()) {
+                                let hash = Concrete::from(H256::from(keccak256(bytes)));
+                                return self.concrete_var(*loc, hash, ctx);
+                            }
+                            // otherwise the hash is unconstrained, i.e. the full range of bytes32
+                            let ret = FunctionNode::from(func_idx).returns(self)[0];
+                            let ret_ty = ret.underlying(self).ty;
+                            let mut var = ContextVar::new_from_builtin(*loc, ret_ty.into(), self);
+                            var.name = format!("tmp{}(keccak256())", ctx.new_tmp(self));
+                            var.display_name = "keccak256()".to_string();
+                            var.is_symbolic = true;
+                            let cvar = self.add_node(Node::ContextVar(var));
+                            self.add_edge(cvar, ctx, Edge::Context(ContextEdge::Variable));
+                            ExprRet::Single((ctx, cvar))
+                        }
                         e => todo!("builtin function: {:?}", e),
                     }
                 } else {
@@ -370,55 +567,7 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
             }
             Node::Builtin(Builtin::Array(_)) => {
                 // create a new list
-                let (ctx, len_cvar) = self.parse_ctx_expr(&input_exprs[0], ctx).expect_single();
-                let ty = VarType::try_from_idx(self, func_idx);
-
-                let new_arr = ContextVar {
-                    loc: Some(*loc),
-                    name: format!("tmp_arr{}", ctx.new_tmp(self)),
-                    display_name: "arr".to_string(),
-                    storage: None,
-                    is_tmp: true,
-                    is_symbolic: false,
-                    tmp_of: None,
-                    ty: ty.expect("No type for node"),
-                };
-
-                let arr = ContextVarNode::from(self.add_node(Node::ContextVar(new_arr)));
-
-                let len_var = ContextVar {
-                    loc: Some(*loc),
-                    name: arr.name(self) + ".length",
-                    display_name: arr.display_name(self) + ".length",
-                    storage: None,
-                    is_tmp: true,
-                    tmp_of: None,
-                    is_symbolic: true,
-                    ty: ContextVarNode::from(len_cvar).underlying(self).ty.clone(),
-                };
-
-                let len_cvar = self.add_node(Node::ContextVar(len_var));
-                self.add_edge(arr, ctx, Edge::Context(ContextEdge::Variable));
-                self.add_edge(len_cvar, ctx, Edge::Context(ContextEdge::Variable));
-                self.add_edge(len_cvar, arr, Edge::Context(ContextEdge::AttrAccess));
-
-                // update the length
-                if let Some(r) = arr.range(self) {
-                    let min = r.evaled_range_min(self);
-                    let max = r.evaled_range_max(self);
-
-                    if let Some(mut rd) = min.maybe_range_dyn() {
-                        rd.len = Elem::Dynamic(Dynamic::new(len_cvar, *loc));
-                        arr.set_range_min(self, Elem::ConcreteDyn(Box::new(rd)));
-                    }
-
-                    if let Some(mut rd) = max.maybe_range_dyn() {
-                        rd.len = Elem::Dynamic(Dynamic::new(len_cvar, *loc));
-                        arr.set_range_min(self, Elem::ConcreteDyn(Box::new(rd)))
-                    }
-                }
-
-                ExprRet::Single((ctx, arr.into()))
+                self.new_dyn_builtin(loc, func_idx, &input_exprs[0], ctx)
             }
             Node::Builtin(ty) => {
                 // it is a cast
@@ -429,6 +578,12 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
 
                 new_var.underlying_mut(self).ty = VarType::try_from_idx(self, func_idx).expect("");
 
+                if matches!(ty, Builtin::String | Builtin::DynamicBytes)
+                    && self.bytes_string_cast(ContextVarNode::from(cvar), new_var)
+                {
+                    return ExprRet::Single((ctx, new_var.into()));
+                }
+
                 // cast the ranges
                 if let Some(r) = ContextVarNode::from(cvar).range(self) {
                     let curr_range = SolcRange::try_from_builtin(&ty).expect("No default range");
@@ -440,6 +595,39 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
                         *range = range.clone().cast(curr_range.range_min());
                     });
                     new_var.set_range_exclusions(self, exclusions);
+
+                    // fold casts of constants, i.e. `address(0)`, to a single concrete point
+                    if let (Elem::Concrete(min), Elem::Concrete(max)) =
+                        (r.evaled_range_min(self), r.evaled_range_max(self))
+                    {
+                        if !ty.is_dyn() && min.val == max.val {
+                            if let Some(val) = min.val.cast(ty.clone()) {
+                                let val = Elem::from(val);
+                                new_var.set_range_min(self, val.clone());
+                                new_var.set_range_max(self, val);
+                            }
+                        }
+                    }
+
+                    if let Builtin::Uint(size) = ty {
+                        if ContextVarNode::from(cvar).is_int(self) {
+                            self.reinterpret_signed_cast(&r, size, new_var);
+                        } else {
+                            self.truncate_uint_cast(&r, size, new_var);
+                        }
+                    }
+
+                    // a narrowing cast truncates the value if its range doesn't fit in the
+                    // target type, unless a prior require already bounded it
+                    if matches!(ty, Builtin::Uint(_) | Builtin::Int(_))
+                        && self.cast_may_truncate(&r, &curr_range)
+                    {
+                        ctx.underlying_mut(self)
+                            .add_finding(ContextFinding::UnsafeCast(
+                                *loc,
+                                ContextVarNode::from(cvar),
+                            ));
+                    }
                 } else {
                     // todo!("unable to cast: {:?}, {ty:?}", self.node(cvar))
                 }
@@ -599,7 +787,12 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
             })
             .collect::<BTreeMap<_, ContextVarNode>>();
 
-        let mods = func_node.modifiers(self);
+        // trusted functions are opaque, so their modifiers aren't run either
+        let mods = if !entry_call && self.is_trusted_fn(func_node) {
+            vec![]
+        } else {
+            func_node.modifiers(self)
+        };
         if let Some(mod_state) = modifier_state {
             // we are iterating through modifiers
             if mod_state.num + 1 < mods.len() {
@@ -630,7 +823,26 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
         func_node: FunctionNode,
         renamed_inputs: BTreeMap<ContextVarNode, ContextVarNode>,
     ) -> ExprRet {
-        if let Some(body) = func_node.underlying(self).body.clone() {
+        if caller_ctx != callee_ctx {
+            if let Some(ret) = self.min_max_call(caller_ctx, callee_ctx, func_node) {
+                return ret;
+            }
+        }
+
+        let body = if caller_ctx != callee_ctx && self.is_trusted_fn(func_node) {
+            // trusted functions are treated as if they had no body
+            None
+        } else if callee_ctx.call_depth(self, func_node) > self.max_call_depth() {
+            // too deeply recursive to keep inlining, treat the function as if it had no body
+            callee_ctx
+                .underlying_mut(self)
+                .add_finding(ContextFinding::RecursionLimited(loc));
+            None
+        } else {
+            func_node.underlying(self).body.clone()
+        };
+
+        if let Some(body) = body {
             // add return nodes into the subctx
             func_node.returns(self).iter().for_each(|ret| {
                 if let Some(var) =
@@ -643,9 +855,21 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
 
             self.parse_ctx_statement(&body, false, Some(callee_ctx));
 
+            // reaching the end of the body without a return statement returns the named returns
+            let forks = callee_ctx.live_forks(self);
+            if forks.is_empty() {
+                if !callee_ctx.is_ended(self) {
+                    self.return_named_vars(body.loc(), callee_ctx);
+                }
+            } else {
+                forks.into_iter().for_each(|fork| {
+                    self.return_named_vars(body.loc(), fork);
+                });
+            }
+
             // update any requirements
             self.inherit_input_changes(loc, caller_ctx, callee_ctx, &renamed_inputs);
-            self.inherit_storage_changes(caller_ctx, callee_ctx);
+            self.inherit_storage_changes(caller_ctx, callee_ctx, &renamed_inputs);
 
             ExprRet::Multi(
                 callee_ctx
@@ -658,8 +882,12 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
             )
         } else {
             self.inherit_input_changes(loc, caller_ctx, callee_ctx, &renamed_inputs);
-            self.inherit_storage_changes(caller_ctx, callee_ctx);
+            self.inherit_storage_changes(caller_ctx, callee_ctx, &renamed_inputs);
 
+            // whatever an untrusted external contract returns is untrusted input
+            let untrusted =
+                callee_ctx.underlying(self).ext_fn_call.is_some() && !self.is_trusted_fn(func_node);
+            let returndata = format!("returndata of {}", func_node.name(self));
             ExprRet::Multi(
                 func_node
                     .returns(self)
@@ -668,6 +896,9 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
                         let underlying = ret.underlying(self);
                         let var = ContextVar::maybe_new_from_func_ret(self, underlying.clone())?;
                         let node = self.add_node(Node::ContextVar(var));
+                        if untrusted {
+                            self.taint_source(node.into(), returndata.clone());
+                        }
                         Some(ExprRet::Single((caller_ctx, node)))
                     })
                     .collect(),
@@ -675,6 +906,58 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
         }
     }
 
+    /// Library `min`/`max` helpers (i.e. `Math.min(a, b)`) are recognized by their signature
+    /// instead of being inlined. Inlining forks on the comparison, so no single context would
+    /// hold the tight range of the result, which for `min` is
+    /// `[min(a.min, b.min), min(a.max, b.max)]` (and likewise for `max`)
+    fn min_max_call(
+        &mut self,
+        caller_ctx: ContextNode,
+        callee_ctx: ContextNode,
+        func_node: FunctionNode,
+    ) -> Option<ExprRet> {
+        // function names include their parameter types, i.e. `min(uint256, uint256)`
+        let name = func_node.name(self);
+        let op = match name.split('(').next() {
+            Some("min") => RangeOp::Min,
+            Some("max") => RangeOp::Max,
+            _ => return None,
+        };
+        if !func_node.contract(self)?.is_library(self) {
+            return None;
+        }
+        let params = func_node.params(self);
+        let rets = func_node.returns(self);
+        if params.len() != 2 || rets.len() != 1 {
+            return None;
+        }
+
+        let inputs = params
+            .iter()
+            .map(|param| callee_ctx.latest_var_by_name(self, &param.maybe_name(self)?))
+            .collect::<Option<Vec<_>>>()?;
+        let (a, b) = (inputs[0], inputs[1]);
+        let (a_min, a_max) = (a.range_min(self)?, a.range_max(self)?);
+        let (b_min, b_max) = (b.range_min(self)?, b.range_max(self)?);
+        let (min, max) = if op == RangeOp::Min {
+            (a_min.min(b_min), a_max.min(b_max))
+        } else {
+            (a_min.max(b_min), a_max.max(b_max))
+        };
+
+        let var = ContextVar::maybe_new_from_func_ret(self, rets[0].underlying(self).clone())?;
+        if var.fallback_range(self).is_none() {
+            return None;
+        }
+        let ret = ContextVarNode::from(self.add_node(Node::ContextVar(var)));
+        ret.set_range_min(self, min);
+        ret.set_range_max(self, max);
+        Some(ExprRet::Multi(vec![ExprRet::Single((
+            caller_ctx,
+            ret.0.into(),
+        ))]))
+    }
+
     /// Calls a modifier for a function
     fn call_modifier_for_fn(
         &mut self,
@@ -774,7 +1057,11 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
             ctx,
             &modifier_state.renamed_inputs,
         );
-        self.inherit_storage_changes(modifier_state.parent_ctx, ctx);
+        self.inherit_storage_changes(
+            modifier_state.parent_ctx,
+            ctx,
+            &modifier_state.renamed_inputs,
+        );
 
         // actually execute the parent function
         self.execute_call_inner(
@@ -814,10 +1101,20 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
         mod_ctx.underlying_mut(self).ret = ret;
     }
 
-    /// Inherit the storage changes from a function call
-    fn inherit_storage_changes(&mut self, inheritor_ctx: ContextNode, grantor_ctx: ContextNode) {
+    /// Inherit the storage changes from a function call. Storage references passed as inputs are
+    /// inherited by `inherit_input_changes` instead
+    fn inherit_storage_changes(
+        &mut self,
+        inheritor_ctx: ContextNode,
+        grantor_ctx: ContextNode,
+        renamed_inputs: &BTreeMap<ContextVarNode, ContextVarNode>,
+    ) {
         if inheritor_ctx != grantor_ctx {
-            let vars = grantor_ctx.local_vars(self);
+            let vars = grantor_ctx
+                .local_vars(self)
+                .into_iter()
+                .filter(|var| !renamed_inputs.values().any(|input| input == var))
+                .collect::<Vec<_>>();
             vars.iter().for_each(|old_var| {
                 let var = old_var.latest_version(self);
                 let underlying = var.underlying(self);
@@ -889,4 +1186,146 @@ pub trait FuncCaller: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
             .enumerate()
             .for_each(|(i, modifier)| self.add_edge(*modifier, func, Edge::FuncModifier(i)));
     }
+
+    /// `string(b)` and `bytes(s)` reinterpret the same data, so the cast keeps the range of the
+    /// source, including its length, instead of going through the integer cast path. Returns
+    /// false if the source isn't a `string` or `bytes`
+    fn bytes_string_cast(&mut self, cvar: ContextVarNode, new_var: ContextVarNode) -> bool {
+        let range = match cvar.ty(self) {
+            VarType::BuiltIn(bn, _)
+                if matches!(bn.underlying(self), Builtin::String | Builtin::DynamicBytes) =>
+            {
+                cvar.range(self)
+            }
+            // string literals have no range of their own, so use their bytes
+            VarType::Concrete(cnode) => match cnode.underlying(self) {
+                Concrete::String(s) => SolcRange::from(Concrete::DynBytes(s.clone().into_bytes())),
+                Concrete::DynBytes(_) => cvar.range(self),
+                _ => return false,
+            },
+            _ => return false,
+        };
+
+        if let Some(r) = range {
+            new_var.set_range_min(self, r.range_min());
+            new_var.set_range_max(self, r.range_max());
+            new_var.set_range_exclusions(self, r.range_exclusions());
+        }
+        true
+    }
+
+    /// Narrowing an unsigned integer, i.e. `uint160(x)` for a `uint256 x`, keeps only the low
+    /// bits instead of saturating at the type maximum. If the range spans more values than the
+    /// target type holds, or wraps around when masked, any value of the target type is possible.
+    /// This keeps chains like `address(uint160(x))` within 20 bytes
+    fn truncate_uint_cast(&mut self, range: &SolcRange, size: u16, new_var: ContextVarNode) {
+        let (min, max) = match (range.evaled_range_min(self), range.evaled_range_max(self)) {
+            (
+                Elem::Concrete(RangeConcrete {
+                    val: Concrete::Uint(_, min),
+                    ..
+                }),
+                Elem::Concrete(RangeConcrete {
+                    val: Concrete::Uint(_, max),
+                    ..
+                }),
+            ) => (min, max),
+            _ => return,
+        };
+
+        let mask = if size == 256 {
+            U256::MAX
+        } else {
+            (U256::from(1) << size) - 1
+        };
+        if max <= mask {
+            // fits in the target type, nothing is truncated
+            return;
+        }
+
+        let (lo, hi) = (min & mask, max & mask);
+        let (lo, hi) = if max - min <= mask && lo <= hi {
+            (lo, hi)
+        } else {
+            (U256::zero(), mask)
+        };
+        new_var.set_range_min(self, Elem::from(Concrete::Uint(size, lo)));
+        new_var.set_range_max(self, Elem::from(Concrete::Uint(size, hi)));
+        new_var.set_range_exclusions(self, vec![]);
+    }
+
+    /// Checks if the range of the input of an integer cast reaches outside of the range of the
+    /// target type. Bounds are compared by value, even between signed and unsigned types, so a
+    /// negative input never fits an unsigned type. Bounds that aren't concrete are assumed to fit
+    fn cast_may_truncate(&self, range: &SolcRange, ty_range: &SolcRange) -> bool {
+        let concrete = |elem: Elem<Concrete>| match elem {
+            Elem::Concrete(RangeConcrete { val, .. }) => Some(val),
+            _ => None,
+        };
+        let cmp = |lhs: &Concrete, rhs: &Concrete| match (lhs, rhs) {
+            (Concrete::Uint(_, lhs), Concrete::Uint(_, rhs)) => Some(lhs.cmp(rhs)),
+            (Concrete::Int(_, lhs), Concrete::Int(_, rhs)) => Some(lhs.cmp(rhs)),
+            (Concrete::Int(_, lhs), Concrete::Uint(_, rhs)) => Some(if lhs.is_negative() {
+                std::cmp::Ordering::Less
+            } else {
+                lhs.into_raw().cmp(rhs)
+            }),
+            (Concrete::Uint(_, lhs), Concrete::Int(_, rhs)) => Some(if rhs.is_negative() {
+                std::cmp::Ordering::Greater
+            } else {
+                lhs.cmp(&rhs.into_raw())
+            }),
+            _ => None,
+        };
+
+        match (
+            concrete(range.evaled_range_min(self)),
+            concrete(range.evaled_range_max(self)),
+            concrete(ty_range.evaled_range_min(self)),
+            concrete(ty_range.evaled_range_max(self)),
+        ) {
+            (Some(min), Some(max), Some(ty_min), Some(ty_max)) => {
+                cmp(&min, &ty_min) == Some(std::cmp::Ordering::Less)
+                    || cmp(&max, &ty_max) == Some(std::cmp::Ordering::Greater)
+            }
+            _ => false,
+        }
+    }
+
+    /// Casting a signed integer to an unsigned one reinterprets its two's complement bits, so a
+    /// negative value wraps to the top of the unsigned type, i.e. `uint8(int8(-1)) == 255`. A
+    /// range whose wrapped bounds stay in order maps to a single interval. A range straddling
+    /// zero maps to `[0, max] ∪ [2^n + min, 2^n - 1]`, and as exclusions are single values the
+    /// gap between the two can't be kept, leaving the whole unsigned range
+    fn reinterpret_signed_cast(&mut self, range: &SolcRange, size: u16, new_var: ContextVarNode) {
+        let (min, max) = match (range.evaled_range_min(self), range.evaled_range_max(self)) {
+            (
+                Elem::Concrete(RangeConcrete {
+                    val: Concrete::Int(_, min),
+                    ..
+                }),
+                Elem::Concrete(RangeConcrete {
+                    val: Concrete::Int(_, max),
+                    ..
+                }),
+            ) => (min.into_raw(), max.into_raw()),
+            _ => return,
+        };
+
+        let mask = if size == 256 {
+            U256::MAX
+        } else {
+            (U256::from(1) << size) - 1
+        };
+        let (lo, hi) = (min & mask, max & mask);
+        let span = max.overflowing_sub(min).0;
+        let (lo, hi) = if span <= mask && lo <= hi {
+            (lo, hi)
+        } else {
+            (U256::zero(), mask)
+        };
+        new_var.set_range_min(self, Elem::from(Concrete::Uint(size, lo)));
+        new_var.set_range_max(self, Elem::from(Concrete::Uint(size, hi)));
+        new_var.set_range_exclusions(self, vec![]);
+    }
 }
@@ -1,11 +1,16 @@
-use solang_parser::pt::Loc;
+use ethers_core::types::U256;
 use solang_parser::pt::Statement;
+use solang_parser::pt::{CodeLocation, Loc};
 
+use crate::context::exprs::CondOp;
 use crate::context::ContextBuilder;
 use shared::analyzer::GraphLike;
 use shared::context::*;
+use shared::nodes::{Builtin, Concrete, VarType};
+use shared::range::{elem::RangeElem, elem_ty::Elem, Range};
 use shared::{analyzer::AnalyzerLike, Edge, Node, NodeIdx};
 use solang_parser::pt::Expression;
+use std::cmp::Ordering;
 
 impl<T> Looper for T where T: AnalyzerLike<Expr = Expression> + Sized + GraphLike {}
 pub trait Looper: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
@@ -13,11 +18,16 @@ pub trait Looper: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
         &mut self,
         loc: Loc,
         ctx: ContextNode,
-        _maybe_init: &Option<Box<Statement>>,
-        _maybe_limiter: &Option<Box<Expression>>,
-        _maybe_post: &Option<Box<Statement>>,
+        maybe_init: &Option<Box<Statement>>,
+        maybe_limiter: &Option<Box<Expression>>,
+        maybe_post: &Option<Box<Statement>>,
         maybe_body: &Option<Box<Statement>>,
     ) {
+        if self.branch_budget_exceeded(ctx) {
+            self.skip_loop(loc, ctx);
+            return;
+        }
+
         // TODO: improve this
         if let Some(body) = maybe_body {
             let subctx = ContextNode::from(self.add_node(Node::Context(Context::new_subctx(
@@ -31,7 +41,49 @@ pub trait Looper: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
                 ctx_fork,
                 Edge::Context(ContextEdge::Subcontext),
             );
+
+            if let Some(limiter) = maybe_limiter {
+                if self.loop_bound_uncapped(limiter, subctx) {
+                    subctx
+                        .underlying_mut(self)
+                        .add_finding(ContextFinding::UnboundedLoop(loc));
+                }
+            }
+
+            // variables declared in the initializer are local to the loop, so they
+            // are never widened in the parent context
+            if let Some(init) = maybe_init {
+                self.parse_ctx_statement(init, false, Some(subctx));
+            }
+            // a missing condition is always true
+            let cond_entry = match maybe_limiter {
+                Some(limiter) => self.loop_cond_entry(limiter, subctx),
+                None => Some(vec![]),
+            };
+            if let (Some(limiter), Some(post)) = (maybe_limiter, maybe_post) {
+                if let Some((inc_loc, counter)) =
+                    self.loop_increment_overflow(limiter, post, subctx)
+                {
+                    subctx
+                        .underlying_mut(self)
+                        .add_finding(ContextFinding::LoopCounterOverflow(inc_loc, counter));
+                }
+            }
             self.parse_ctx_statement(body, false, Some(subctx));
+            if let Some(post) = maybe_post {
+                self.for_loop_post(post, subctx);
+            }
+            if let Some(entry) = cond_entry {
+                let stays_true = match maybe_limiter {
+                    Some(limiter) => !self.loop_changes_cond(limiter, &entry, subctx),
+                    None => true,
+                };
+                if stays_true && !Self::stmt_may_exit_loop(body, true) {
+                    subctx
+                        .underlying_mut(self)
+                        .add_finding(ContextFinding::InfiniteLoop(loc));
+                }
+            }
             let vars = subctx.local_vars(self);
             vars.iter().for_each(|var| {
                 // widen to max range
@@ -47,7 +99,28 @@ pub trait Looper: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
         }
     }
 
-    fn while_loop(&mut self, loc: Loc, ctx: ContextNode, _limiter: &Expression, body: &Statement) {
+    /// Parses the update clause of a for loop in the loop's context. An update of multiple
+    /// variables (`(i++, j--)`) is parsed as a list, so each sub-expression is parsed as its
+    /// own statement, advancing each updated variable
+    fn for_loop_post(&mut self, post: &Statement, loop_ctx: ContextNode) {
+        match post {
+            Statement::Expression(_, Expression::List(_, params)) => params
+                .iter()
+                .filter_map(|(_, param)| param.as_ref())
+                .for_each(|param| {
+                    let update = Statement::Expression(param.ty.loc(), param.ty.clone());
+                    self.parse_ctx_statement(&update, false, Some(loop_ctx));
+                }),
+            _ => self.parse_ctx_statement(post, false, Some(loop_ctx)),
+        }
+    }
+
+    fn while_loop(&mut self, loc: Loc, ctx: ContextNode, limiter: &Expression, body: &Statement) {
+        if self.branch_budget_exceeded(ctx) {
+            self.skip_loop(loc, ctx);
+            return;
+        }
+
         // TODO: improve this
         let subctx = ContextNode::from(self.add_node(Node::Context(Context::new_subctx(
             ctx, loc, false, None, false, self, None,
@@ -60,7 +133,24 @@ pub trait Looper: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
             ctx_fork,
             Edge::Context(ContextEdge::Subcontext),
         );
+
+        let cond_entry = self.loop_cond_entry(limiter, subctx);
+        if self.loop_bound_uncapped(limiter, subctx) {
+            subctx
+                .underlying_mut(self)
+                .add_finding(ContextFinding::UnboundedLoop(loc));
+        }
+
         self.parse_ctx_statement(body, false, Some(subctx));
+        if let Some(entry) = cond_entry {
+            if !self.loop_changes_cond(limiter, &entry, subctx)
+                && !Self::stmt_may_exit_loop(body, true)
+            {
+                subctx
+                    .underlying_mut(self)
+                    .add_finding(ContextFinding::InfiniteLoop(loc));
+            }
+        }
         let vars = subctx.local_vars(self);
         vars.iter().for_each(|var| {
             // widen to max range
@@ -74,4 +164,329 @@ pub trait Looper: GraphLike + AnalyzerLike<Expr = Expression> + Sized {
             }
         });
     }
+
+    /// Once the function exceeds its branch budget, a loop's body isn't parsed in a subcontext.
+    /// Any variable could be modified by the loop, so each is widened to the full range of its
+    /// type, which loses precision, so the location is recorded
+    fn skip_loop(&mut self, loc: Loc, ctx: ContextNode) {
+        ctx.underlying_mut(self)
+            .add_finding(ContextFinding::MergedBranch(loc));
+        let mut vars: Vec<ContextVarNode> = ctx
+            .vars(self)
+            .into_iter()
+            .filter(|var| !var.is_tmp(self))
+            .map(|var| var.latest_version(self))
+            .collect();
+        vars.sort();
+        vars.dedup();
+        vars.into_iter().for_each(|var| {
+            if let Some(r) = var.underlying(self).ty.default_range(self) {
+                let new_var = self.advance_var_in_ctx(var, loc, ctx);
+                new_var.set_range_min(self, r.min);
+                new_var.set_range_max(self, r.max);
+            }
+        });
+    }
+
+    /// Checks if a loop condition is true when the loop is entered. If it is, returns the bounds
+    /// of the variables it reads, so the loop can check if its body changes any of them
+    fn loop_cond_entry(
+        &self,
+        limiter: &Expression,
+        loop_ctx: ContextNode,
+    ) -> Option<Vec<Option<(Elem<Concrete>, Elem<Concrete>)>>> {
+        if !self.cond_always_true(limiter, loop_ctx) {
+            return None;
+        }
+        Some(self.cond_var_bounds(limiter, loop_ctx))
+    }
+
+    /// Checks if any path through the loop's body leaves a variable the condition reads with
+    /// different bounds than it had when the loop was entered. The body is only walked once, so
+    /// a condition that still holds afterwards, like `i < 10` after `i++`, isn't proof that the
+    /// loop never ends
+    fn loop_changes_cond(
+        &self,
+        limiter: &Expression,
+        entry: &[Option<(Elem<Concrete>, Elem<Concrete>)>],
+        loop_ctx: ContextNode,
+    ) -> bool {
+        loop_ctx.self_and_subcontexts(self).into_iter().any(|ctx| {
+            self.cond_var_bounds(limiter, ctx).iter().zip(entry).any(
+                |(bounds, entry_bounds)| match (bounds, entry_bounds) {
+                    (Some((min, max)), Some((entry_min, entry_max))) => {
+                        !min.range_eq(entry_min) || !max.range_eq(entry_max)
+                    }
+                    (None, None) => false,
+                    _ => true,
+                },
+            )
+        })
+    }
+
+    /// Evaluates a condition from the known bounds of what it reads instead of parsing it, so
+    /// any side effects of the condition are left to the loop itself. Returns false unless the
+    /// condition is known to always be true
+    fn cond_always_true(&self, cond: &Expression, ctx: ContextNode) -> bool {
+        let ord = |lhs: &Expression, rhs: &Expression| {
+            let (lhs_min, lhs_max) = self.cond_operand_bounds(lhs, ctx)?;
+            let (rhs_min, rhs_max) = self.cond_operand_bounds(rhs, ctx)?;
+            Some((lhs_max.range_ord(&rhs_min), lhs_min.range_ord(&rhs_max)))
+        };
+        match cond {
+            Expression::BoolLiteral(_, val) => *val,
+            Expression::Parenthesis(_, inner) => self.cond_always_true(inner, ctx),
+            Expression::And(_, lhs, rhs) => {
+                self.cond_always_true(lhs, ctx) && self.cond_always_true(rhs, ctx)
+            }
+            Expression::Or(_, lhs, rhs) => {
+                self.cond_always_true(lhs, ctx) || self.cond_always_true(rhs, ctx)
+            }
+            Expression::Less(_, lhs, rhs) => {
+                matches!(ord(lhs, rhs), Some((Some(Ordering::Less), _)))
+            }
+            Expression::LessEqual(_, lhs, rhs) => matches!(
+                ord(lhs, rhs),
+                Some((Some(Ordering::Less | Ordering::Equal), _))
+            ),
+            Expression::More(_, lhs, rhs) => {
+                matches!(ord(lhs, rhs), Some((_, Some(Ordering::Greater))))
+            }
+            Expression::MoreEqual(_, lhs, rhs) => matches!(
+                ord(lhs, rhs),
+                Some((_, Some(Ordering::Greater | Ordering::Equal)))
+            ),
+            Expression::Variable(_) => {
+                let t = Elem::from(Concrete::Bool(true));
+                matches!(
+                    self.cond_operand_bounds(cond, ctx),
+                    Some((min, max)) if min.range_eq(&t) && max.range_eq(&t)
+                )
+            }
+            _ => false,
+        }
+    }
+
+    /// The bounds of an operand of a condition, read from what is already known
+    fn cond_operand_bounds(
+        &self,
+        operand: &Expression,
+        ctx: ContextNode,
+    ) -> Option<(Elem<Concrete>, Elem<Concrete>)> {
+        match operand {
+            Expression::BoolLiteral(_, val) => {
+                let val = Elem::from(Concrete::Bool(*val));
+                Some((val.clone(), val))
+            }
+            Expression::NumberLiteral(_, int, exp, _unit) => {
+                let val = Elem::from(Concrete::from(Concrete::dec_literal_val(int, exp)?));
+                Some((val.clone(), val))
+            }
+            Expression::Parenthesis(_, inner) => self.cond_operand_bounds(inner, ctx),
+            Expression::Variable(ident) => {
+                let var = ctx
+                    .var_by_name_or_recurse(self, &ident.name)?
+                    .latest_version(self);
+                Some((var.evaled_range_min(self)?, var.evaled_range_max(self)?))
+            }
+            _ => None,
+        }
+    }
+
+    /// The bounds of each variable a condition reads, in the order they are read
+    fn cond_var_bounds(
+        &self,
+        cond: &Expression,
+        ctx: ContextNode,
+    ) -> Vec<Option<(Elem<Concrete>, Elem<Concrete>)>> {
+        match cond {
+            Expression::Variable(_) => vec![self.cond_operand_bounds(cond, ctx)],
+            Expression::Parenthesis(_, inner) => self.cond_var_bounds(inner, ctx),
+            Expression::And(_, lhs, rhs)
+            | Expression::Or(_, lhs, rhs)
+            | Expression::Less(_, lhs, rhs)
+            | Expression::LessEqual(_, lhs, rhs)
+            | Expression::More(_, lhs, rhs)
+            | Expression::MoreEqual(_, lhs, rhs) => {
+                let mut bounds = self.cond_var_bounds(lhs, ctx);
+                bounds.extend(self.cond_var_bounds(rhs, ctx));
+                bounds
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Checks if a loop condition like `i < arr.length` is bounded by the length of a dynamic
+    /// array whose length nothing caps, so the gas cost of the loop grows with the array
+    fn loop_bound_uncapped(&self, limiter: &Expression, loop_ctx: ContextNode) -> bool {
+        let bound = match limiter {
+            Expression::Less(_, _, bound)
+            | Expression::LessEqual(_, _, bound)
+            | Expression::More(_, bound, _)
+            | Expression::MoreEqual(_, bound, _) => bound,
+            _ => return false,
+        };
+
+        self.length_bound_max(bound, loop_ctx)
+            .and_then(|max| max.maybe_concrete())
+            .and_then(|max| max.val.into_u256())
+            == Some(U256::MAX)
+    }
+
+    /// Looks up the maximum of an `arr.length` loop bound where `arr` is a dynamic array,
+    /// without parsing the bound. Parsing it would advance the array and its length in the
+    /// loop's context (and inline any call in it) before the body even runs. The length is
+    /// tracked by an `arr.length` variable once something reads it, otherwise it comes from
+    /// the array's own range, i.e. for `new uint256[](n)`, or is unknown and so uncapped
+    fn length_bound_max(
+        &self,
+        bound: &Expression,
+        loop_ctx: ContextNode,
+    ) -> Option<Elem<Concrete>> {
+        let arr_name = match bound {
+            Expression::MemberAccess(_, arr_expr, ident) if ident.name == "length" => {
+                match &**arr_expr {
+                    Expression::Variable(arr_ident) => &arr_ident.name,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        let uncapped = Elem::from(Concrete::from(U256::MAX));
+
+        let arr = match loop_ctx.var_by_name_or_recurse(self, arr_name) {
+            Some(arr) => arr.latest_version(self),
+            None => {
+                // a storage array the function hasn't touched yet, so nothing caps its length
+                let idx = self.user_types().get(arr_name)?;
+                return match VarType::try_from_idx(self, *idx) {
+                    Some(ty) if ty.is_dyn_builtin(self) => Some(uncapped),
+                    _ => None,
+                };
+            }
+        };
+        if !arr.underlying(self).ty.is_dyn_builtin(self) {
+            return None;
+        }
+
+        let len_name = format!("{}.length", arr.first_version(self).name(self));
+        if let Some(len) = loop_ctx.var_by_name_or_recurse(self, &len_name) {
+            return len.latest_version(self).evaled_range_max(self);
+        }
+        let len_max = arr
+            .evaled_range_max(self)
+            .and_then(|max| max.maybe_range_dyn())
+            .map(|rd| rd.len.maximize(self))
+            .filter(|len_max| len_max.maybe_concrete().is_some());
+        Some(len_max.unwrap_or(uncapped))
+    }
+
+    /// Checks if the update of a for loop increments its counter (`i++` or `++i`) while the loop
+    /// condition still holds at the maximum of the counter's type, i.e. `uint8 i; i < 256`, so
+    /// the increment overflows before the condition can end the loop. Returns the location of
+    /// the increment and the counter
+    fn loop_increment_overflow(
+        &self,
+        limiter: &Expression,
+        post: &Statement,
+        loop_ctx: ContextNode,
+    ) -> Option<(Loc, ContextVarNode)> {
+        let (inc_loc, inc_expr) = match post {
+            Statement::Expression(
+                _,
+                Expression::PostIncrement(loc, inc_expr) | Expression::PreIncrement(loc, inc_expr),
+            ) => (*loc, inc_expr),
+            _ => return None,
+        };
+        let (strict, cond_expr, bound) = match limiter {
+            Expression::Less(_, cond_expr, bound) | Expression::More(_, bound, cond_expr) => {
+                (true, cond_expr, bound)
+            }
+            Expression::LessEqual(_, cond_expr, bound)
+            | Expression::MoreEqual(_, bound, cond_expr) => (false, cond_expr, bound),
+            _ => return None,
+        };
+        let name = match (&**inc_expr, &**cond_expr) {
+            (Expression::Variable(inc_ident), Expression::Variable(cond_ident))
+                if inc_ident.name == cond_ident.name =>
+            {
+                &inc_ident.name
+            }
+            _ => return None,
+        };
+
+        let counter = loop_ctx.latest_var_by_name(self, name)?;
+        if !matches!(
+            counter.builtin(self),
+            Some(Builtin::Uint(_) | Builtin::Int(_))
+        ) {
+            return None;
+        }
+        let ty_max = counter
+            .underlying(self)
+            .ty
+            .default_range(self)?
+            .evaled_range_max(self);
+        // the bound is read from what is already known instead of being parsed, so checking it
+        // doesn't add to the loop's context
+        let bound_max = match &**bound {
+            Expression::NumberLiteral(_, int, exp, _unit) => {
+                Elem::from(Concrete::from(Concrete::dec_literal_val(int, exp)?))
+            }
+            Expression::Variable(ident) => loop_ctx
+                .var_by_name_or_recurse(self, &ident.name)?
+                .latest_version(self)
+                .evaled_range_max(self)?,
+            _ => self.length_bound_max(bound, loop_ctx)?,
+        };
+
+        let reaches_max = match bound_max.range_ord(&ty_max)? {
+            Ordering::Greater => true,
+            Ordering::Equal => !strict,
+            Ordering::Less => false,
+        };
+        if reaches_max {
+            Some((inc_loc, counter))
+        } else {
+            None
+        }
+    }
+
+    /// Checks if a statement in a loop body could leave the loop, i.e. via a `break`, `return`
+    /// or a revert. `break`s in nested loops only leave the nested loop, so they are only
+    /// considered when `top_level` is true
+    fn stmt_may_exit_loop(stmt: &Statement, top_level: bool) -> bool {
+        match stmt {
+            Statement::Break(_) => top_level,
+            Statement::Return(..) | Statement::Revert(..) | Statement::RevertNamedArgs(..) => true,
+            Statement::Block { statements, .. } => statements
+                .iter()
+                .any(|stmt| Self::stmt_may_exit_loop(stmt, top_level)),
+            Statement::If(_, _, true_stmt, maybe_false_stmt) => {
+                Self::stmt_may_exit_loop(true_stmt, top_level)
+                    || maybe_false_stmt
+                        .as_ref()
+                        .map(|false_stmt| Self::stmt_may_exit_loop(false_stmt, top_level))
+                        .unwrap_or(false)
+            }
+            Statement::While(_, _, body) | Statement::DoWhile(_, body, _) => {
+                Self::stmt_may_exit_loop(body, false)
+            }
+            Statement::For(_, _, _, _, maybe_body) => maybe_body
+                .as_ref()
+                .map(|body| Self::stmt_may_exit_loop(body, false))
+                .unwrap_or(false),
+            Statement::Expression(_, Expression::FunctionCall(_, func, _)) => {
+                // `revert(..)`, `require(..)` and `assert(..)` can all leave the loop
+                matches!(
+                    &**func,
+                    Expression::Variable(ident)
+                        if matches!(&*ident.name, "revert" | "require" | "assert")
+                )
+            }
+            // try/catch and assembly may leave the loop in ways we don't track, be conservative
+            Statement::Try(..) | Statement::Assembly { .. } => true,
+            _ => false,
+        }
+    }
 }
@@ -4,15 +4,21 @@ use shared::analyzer::GraphLike;
 use shared::context::*;
 
 use shared::range::elem_ty::Dynamic;
+use shared::range::elem_ty::RangeDyn;
 
+use shared::range::elem::RangeElem;
 use shared::range::elem_ty::Elem;
 use shared::range::Range;
+use shared::range::SolcRange;
+use solang_parser::pt::CodeLocation;
+use solang_parser::pt::VariableAttribute;
 use solang_parser::pt::VariableDeclaration;
+use std::cmp::Ordering;
 
 use crate::VarType;
 use petgraph::{visit::EdgeRef, Direction};
 use shared::{analyzer::AnalyzerLike, nodes::*, range::elem::RangeOp, Edge, Node, NodeIdx};
-use solang_parser::pt::{Expression, Identifier, Loc, Statement};
+use solang_parser::pt::{Expression, FunctionTy, Identifier, IdentifierPath, Loc, Statement};
 
 pub mod func;
 use func::*;
@@ -20,6 +26,9 @@ use func::*;
 pub mod loops;
 use loops::*;
 
+pub mod taint;
+use taint::*;
+
 pub mod exprs;
 use exprs::*;
 
@@ -107,11 +116,44 @@ impl ExprRet {
         }
         idxs
     }
+
+    /// Gets the nodes at the end of each path of a possibly forked return, skipping killed paths
+    pub fn fork_leaves(&self) -> Vec<NodeIdx> {
+        match self {
+            ExprRet::Single((_, idx)) | ExprRet::SingleLiteral((_, idx)) => vec![*idx],
+            ExprRet::Fork(world1, world2) => {
+                let mut idxs = world1.fork_leaves();
+                idxs.extend(world2.fork_leaves());
+                idxs
+            }
+            _ => vec![],
+        }
+    }
 }
 
 impl<T> ContextBuilder for T where T: AnalyzerLike<Expr = Expression> + Sized + ExprParser {}
 
 pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
+    /// Analyzes the body of a single function in a new context, injecting its parameters and
+    /// named returns, and returns the body context. A previous analysis of the function is
+    /// detached from it, so that reanalyzing a function replaces its body context. Returns `None`
+    /// if the function has no body
+    fn analyze_function_body(&mut self, func: FunctionNode) -> Option<ContextNode> {
+        let body = func.underlying(self).body.clone()?;
+        let prev_ctx_edges = self
+            .graph()
+            .edges_directed(func.into(), Direction::Incoming)
+            .filter(|edge| *edge.weight() == Edge::Context(ContextEdge::Context))
+            .map(|edge| edge.id())
+            .collect::<Vec<_>>();
+        prev_ctx_edges.into_iter().for_each(|edge| {
+            self.graph_mut().remove_edge(edge);
+        });
+
+        self.parse_ctx_statement(&body, false, Some(func));
+        func.maybe_body_ctx(self)
+    }
+
     fn parse_ctx_statement(
         &mut self,
         stmt: &Statement,
@@ -119,6 +161,24 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
         parent_ctx: Option<impl Into<NodeIdx> + Clone + Copy>,
     ) where
         Self: Sized,
+    {
+        let start = self
+            .metrics_mut()
+            .and_then(|metrics| metrics.enter_statement());
+        self.parse_ctx_statement_forks(stmt, unchecked, parent_ctx);
+        if let Some(metrics) = self.metrics_mut() {
+            metrics.exit_statement(start);
+        }
+    }
+
+    /// Parses the statement in each live fork of the parent context
+    fn parse_ctx_statement_forks(
+        &mut self,
+        stmt: &Statement,
+        unchecked: bool,
+        parent_ctx: Option<impl Into<NodeIdx> + Clone + Copy>,
+    ) where
+        Self: Sized,
     {
         if let Some(parent) = parent_ctx {
             match self.node(parent) {
@@ -213,6 +273,11 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                                 ctx_node,
                                 Edge::Context(ContextEdge::Variable),
                             );
+                            // anyone can call a public or external function with any arguments
+                            if FunctionNode::from(parent.into()).is_public_or_ext(self) {
+                                let cvar = ContextVarNode::from(cvar_node);
+                                self.taint_source(cvar, cvar.display_name(self));
+                            }
 
                             Some((*param_node, ContextVarNode::from(cvar_node)))
                         } else {
@@ -246,6 +311,14 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                         let parent = FunctionNode::from(parent.into());
                         self.set_modifiers(parent, ctx_node.into());
                     }
+                    self.call_base_constructors(
+                        ctx_node.into(),
+                        FunctionNode::from(parent.into()),
+                        fn_loc,
+                    );
+                    if ContextNode::from(ctx_node).is_killed(self) {
+                        return;
+                    }
                     self.func_call_inner(
                         true,
                         ctx_node.into(),
@@ -258,6 +331,10 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                     return;
                 }
 
+                if *unchecked {
+                    ContextNode::from(ctx_node).set_unchecked(true, self);
+                }
+
                 let forks = ContextNode::from(ctx_node).live_forks(self);
                 if forks.is_empty() {
                     statements.iter().for_each(|stmt| {
@@ -270,6 +347,12 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                         });
                     });
                 }
+
+                // arithmetic after the `unchecked` block is checked again, including in any
+                // forks created inside of it
+                if *unchecked {
+                    ContextNode::from(ctx_node).set_unchecked(false, self);
+                }
             }
             VariableDefinition(loc, var_decl, maybe_expr) => {
                 let ctx = ContextNode::from(
@@ -298,7 +381,25 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                     });
                 }
             }
-            Args(_loc, _args) => {}
+            Args(_loc, args) => {
+                // a block of named arguments, i.e. `{ value: x + 1 }`. It binds nothing, but the
+                // argument expressions are still evaluated
+                if let Some(parent) = parent_ctx {
+                    let ctx = ContextNode::from(parent.into());
+                    let forks = ctx.live_forks(self);
+                    if forks.is_empty() {
+                        args.iter().for_each(|arg| {
+                            let _paths = self.parse_ctx_expr(&arg.expr, ctx);
+                        });
+                    } else {
+                        forks.into_iter().for_each(|fork| {
+                            args.iter().for_each(|arg| {
+                                let _paths = self.parse_ctx_expr(&arg.expr, fork);
+                            });
+                        });
+                    }
+                }
+            }
             If(loc, if_expr, true_expr, maybe_false_expr) => {
                 let ctx = ContextNode::from(parent_ctx.expect("Dangling if statement").into());
                 let forks = ctx.live_forks(self);
@@ -317,7 +418,11 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
             }
             Expression(_loc, expr) => {
                 if let Some(parent) = parent_ctx {
-                    let _paths = self.parse_ctx_expr(expr, ContextNode::from(parent.into()));
+                    let ctx = ContextNode::from(parent.into());
+                    let _paths = self.parse_ctx_expr(expr, ctx);
+                    if let solang_parser::pt::Expression::FunctionCall(call_loc, ..) = expr {
+                        self.record_unused_return(*call_loc, ctx);
+                    }
                 }
             }
             For(loc, maybe_for_start, maybe_for_middle, maybe_for_end, maybe_for_body) => {
@@ -391,24 +496,71 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                             });
                         }
                     }
+                } else if let Some(parent) = parent_ctx {
+                    // a bare `return;` returns the current values of the named returns
+                    let parent = ContextNode::from(parent.into());
+                    let forks = parent.live_forks(self);
+                    if forks.is_empty() {
+                        self.return_named_vars(*loc, parent);
+                    } else {
+                        forks.into_iter().for_each(|parent| {
+                            self.return_named_vars(*loc, parent);
+                        });
+                    }
+                }
+            }
+            Revert(loc, maybe_err_path, exprs) => {
+                if let Some(parent) = parent_ctx {
+                    let parent = ContextNode::from(parent.into());
+                    let args: Vec<&Expression> = exprs.iter().collect();
+                    let forks = parent.live_forks(self);
+                    if forks.is_empty() {
+                        self.revert(*loc, maybe_err_path, &args, parent);
+                    } else {
+                        forks.into_iter().for_each(|parent| {
+                            self.revert(*loc, maybe_err_path, &args, parent);
+                        });
+                    }
                 }
             }
-            Revert(loc, _maybe_err_path, _exprs) => {
+            RevertNamedArgs(loc, maybe_err_path, named_args) => {
                 if let Some(parent) = parent_ctx {
                     let parent = ContextNode::from(parent.into());
+                    let args: Vec<&Expression> = named_args.iter().map(|arg| &arg.expr).collect();
                     let forks = parent.live_forks(self);
                     if forks.is_empty() {
-                        parent.kill(self, *loc);
+                        self.revert(*loc, maybe_err_path, &args, parent);
                     } else {
                         forks.into_iter().for_each(|parent| {
-                            parent.kill(self, *loc);
+                            self.revert(*loc, maybe_err_path, &args, parent);
                         });
                     }
                 }
             }
-            RevertNamedArgs(_loc, _maybe_err_path, _named_args) => {}
-            Emit(_loc, _emit_expr) => {}
-            Try(_loc, _try_expr, _maybe_returns, _clauses) => {}
+            Emit(_loc, emit_expr) => {
+                if let Some(parent) = parent_ctx {
+                    let parent = ContextNode::from(parent.into());
+                    let forks = parent.live_forks(self);
+                    if forks.is_empty() {
+                        self.emit(emit_expr, parent);
+                    } else {
+                        forks.into_iter().for_each(|parent| {
+                            self.emit(emit_expr, parent);
+                        });
+                    }
+                }
+            }
+            Try(loc, try_expr, maybe_returns, clauses) => {
+                let ctx = ContextNode::from(parent_ctx.expect("Dangling try statement").into());
+                let forks = ctx.live_forks(self);
+                if forks.is_empty() {
+                    self.try_stmt(*loc, try_expr, maybe_returns, clauses, ctx)
+                } else {
+                    forks.into_iter().for_each(|parent| {
+                        self.try_stmt(*loc, try_expr, maybe_returns, clauses, parent)
+                    })
+                }
+            }
             Error(_loc) => {}
         };
 
@@ -444,6 +596,379 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
         }
     }
 
+    /// Parses the arguments of a revert for their side effects, records the custom error being
+    /// reverted with (if any) and kills the context
+    fn revert(
+        &mut self,
+        loc: Loc,
+        maybe_err_path: &Option<IdentifierPath>,
+        args: &[&Expression],
+        ctx: ContextNode,
+    ) {
+        args.iter().for_each(|arg| {
+            let _ = self.parse_ctx_expr(arg, ctx);
+        });
+
+        let err = maybe_err_path
+            .as_ref()
+            .and_then(|path| self.resolve_error(path, ctx));
+
+        let forks = ctx.live_forks(self);
+        if forks.is_empty() {
+            ctx.underlying_mut(self).reverted_with = err;
+            ctx.kill(self, loc);
+        } else {
+            forks.into_iter().for_each(|fork| {
+                fork.underlying_mut(self).reverted_with = err;
+                fork.kill(self, loc);
+            });
+        }
+    }
+
+    /// Finds the custom error a `revert` refers to. A qualified error, like
+    /// `revert IToken.Failed()`, is looked up in the named contract, otherwise in the reverting
+    /// function's contract and the contracts it inherits, then in the source unit
+    fn resolve_error(&self, err_path: &IdentifierPath, ctx: ContextNode) -> Option<ErrorNode> {
+        let (scope, name) = match &err_path.identifiers[..] {
+            [ident] => {
+                let mut scope = vec![];
+                if let Some(con) = ctx.associated_fn(self).contract(self) {
+                    scope.extend(con.errors(self));
+                    scope.extend(
+                        self.graph()
+                            .edges_directed(
+                                con.associated_source_unit_part(self),
+                                Direction::Incoming,
+                            )
+                            .filter(|edge| *edge.weight() == Edge::Error)
+                            .map(|edge| ErrorNode::from(edge.source())),
+                    );
+                }
+                (scope, &ident.name)
+            }
+            [con_ident, ident] => {
+                let scope = self
+                    .user_types()
+                    .get(&con_ident.name)
+                    .filter(|idx| matches!(self.node(**idx), Node::Contract(_)))
+                    .map(|idx| ContractNode::from(*idx).errors(self))
+                    .unwrap_or_default();
+                (scope, &ident.name)
+            }
+            _ => return None,
+        };
+
+        scope
+            .into_iter()
+            .find(|err| err.name(self) == *name)
+            .or_else(|| {
+                // i.e. an error declared in an imported file
+                self.user_types()
+                    .get(name)
+                    .filter(|idx| matches!(self.node(**idx), Node::Error(_)))
+                    .map(|idx| ErrorNode::from(*idx))
+            })
+    }
+
+    /// Parses the arguments of an emitted event, matching them to the parameters of the event.
+    /// An argument that may not fit the type of its parameter is recorded
+    fn emit(&mut self, emit_expr: &Expression, ctx: ContextNode) {
+        let (func_expr, args) = match emit_expr {
+            Expression::FunctionCall(_, func_expr, args) => (func_expr, args),
+            _ => return,
+        };
+
+        let arg_vars: Vec<Option<ContextVarNode>> = args
+            .iter()
+            .map(|arg| match self.parse_ctx_expr(arg, ctx) {
+                ExprRet::Single((_, var)) | ExprRet::SingleLiteral((_, var)) => {
+                    Some(ContextVarNode::from(var).latest_version(self))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let params = self
+            .resolve_event(func_expr, &arg_vars, ctx)
+            .map(|event| event.params(self))
+            .unwrap_or_default();
+        args.iter()
+            .zip(arg_vars)
+            .zip(params)
+            .for_each(|((arg, var), param)| {
+                if let Some(var) = var {
+                    if !self.fits_event_param(var, param) {
+                        ctx.underlying_mut(self)
+                            .add_finding(ContextFinding::EventArgMismatch(arg.loc(), var, param));
+                    }
+                }
+            });
+    }
+
+    /// Finds the event an `emit` refers to. A qualified event, like `emit IToken.Transfer(..)`,
+    /// is looked up in the named contract, otherwise in the emitting function's contract and
+    /// the contracts it inherits, then in the source unit. Overloads are told apart by their
+    /// parameters: the event must take as many parameters as there are arguments, and if that
+    /// still leaves more than one, the one whose parameter types the arguments convert to. An
+    /// event that can't be told apart isn't resolved
+    fn resolve_event(
+        &self,
+        func_expr: &Expression,
+        arg_vars: &[Option<ContextVarNode>],
+        ctx: ContextNode,
+    ) -> Option<EventNode> {
+        let (scope, name) = match func_expr {
+            Expression::Variable(ident) => {
+                let mut scope = vec![];
+                if let Some(con) = ctx.associated_fn(self).contract(self) {
+                    scope.extend(con.events(self));
+                    scope.extend(
+                        self.graph()
+                            .edges_directed(
+                                con.associated_source_unit_part(self),
+                                Direction::Incoming,
+                            )
+                            .filter(|edge| *edge.weight() == Edge::Event)
+                            .map(|edge| EventNode::from(edge.source())),
+                    );
+                }
+                (scope, &ident.name)
+            }
+            Expression::MemberAccess(_, con_expr, ident) => {
+                let scope = match &**con_expr {
+                    Expression::Variable(con_ident) => self
+                        .user_types()
+                        .get(&con_ident.name)
+                        .filter(|idx| matches!(self.node(**idx), Node::Contract(_)))
+                        .map(|idx| ContractNode::from(*idx).events(self))
+                        .unwrap_or_default(),
+                    _ => vec![],
+                };
+                (scope, &ident.name)
+            }
+            _ => return None,
+        };
+
+        let mut candidates: Vec<EventNode> = scope
+            .into_iter()
+            .filter(|event| event.name(self) == *name)
+            .collect();
+        if candidates.is_empty() {
+            // i.e. an event declared in an imported file
+            candidates.extend(
+                self.user_types()
+                    .get(name)
+                    .filter(|idx| matches!(self.node(**idx), Node::Event(_)))
+                    .map(|idx| EventNode::from(*idx)),
+            );
+        }
+
+        candidates.retain(|event| event.params(self).len() == arg_vars.len());
+        if candidates.len() > 1 {
+            candidates.retain(|event| {
+                event
+                    .params(self)
+                    .iter()
+                    .zip(arg_vars)
+                    .all(|(param, var)| self.converts_to_event_param(*var, *param))
+            });
+        }
+        match candidates.as_slice() {
+            [event] => Some(*event),
+            _ => None,
+        }
+    }
+
+    /// Checks if an argument's type converts implicitly to an event parameter's type, ignoring
+    /// its range. Arguments and parameters that aren't builtins are assumed to convert
+    fn converts_to_event_param(&self, var: Option<ContextVarNode>, param: EventParamNode) -> bool {
+        let param_ty = match VarType::try_from_idx(self, param.underlying(self).ty) {
+            Some(VarType::BuiltIn(bn, _)) => bn.underlying(self).clone(),
+            _ => return true,
+        };
+        match var.and_then(|var| var.builtin(self)) {
+            Some(var_ty) => var_ty.implicitly_castable_to(&param_ty),
+            None => true,
+        }
+    }
+
+    /// Checks if a value can be passed as an event parameter unchanged: its type converts
+    /// implicitly to the parameter's type, or it is a number whose range fits in it. Only value
+    /// types are checked
+    fn fits_event_param(&self, var: ContextVarNode, param: EventParamNode) -> bool {
+        let param_ty = match VarType::try_from_idx(self, param.underlying(self).ty) {
+            Some(VarType::BuiltIn(bn, _)) => bn.underlying(self).clone(),
+            _ => return true,
+        };
+        let var_ty = match var.builtin(self) {
+            Some(var_ty) => var_ty,
+            None => return true,
+        };
+
+        match (&var_ty, &param_ty) {
+            _ if var_ty.implicitly_castable_to(&param_ty) => true,
+            (Builtin::Uint(_), Builtin::Uint(_)) | (Builtin::Int(_), Builtin::Int(_)) => {
+                match (
+                    SolcRange::try_from_builtin(&param_ty),
+                    var.evaled_range_min(self),
+                    var.evaled_range_max(self),
+                ) {
+                    (Some(param_range), Some(min), Some(max)) => {
+                        min.range_ord(&param_range.range_min()) != Some(Ordering::Less)
+                            && max.range_ord(&param_range.range_max()) != Some(Ordering::Greater)
+                    }
+                    _ => true,
+                }
+            }
+            _ => !(var_ty.is_value() && param_ty.is_value()),
+        }
+    }
+
+    /// Records a function call made as a statement if the called function returns values, as
+    /// the returns are ignored. The call is the latest call context starting where the statement
+    /// does: calls made for the arguments start later and calls chained on are made first
+    fn record_unused_return(&mut self, call_loc: Loc, ctx: ContextNode) {
+        let call_start = match call_loc {
+            Loc::File(file, start, _) => (file, start),
+            _ => return,
+        };
+
+        let called = ctx
+            .subcontexts(self)
+            .into_iter()
+            .filter(|subctx| matches!(self.node(*subctx), Node::Context(_)))
+            .filter_map(|subctx| {
+                let underlying = subctx.underlying(self);
+                let func = underlying.fn_call.or(underlying.ext_fn_call)?;
+                match underlying.loc {
+                    Loc::File(file, start, _)
+                        if (file, start) == call_start
+                            && func.underlying(self).ty != FunctionTy::Modifier =>
+                    {
+                        Some((subctx, func))
+                    }
+                    _ => None,
+                }
+            })
+            .max_by_key(|(subctx, _)| subctx.0);
+
+        if let Some((_, func)) = called {
+            if !func.returns(self).is_empty() {
+                ctx.underlying_mut(self)
+                    .add_finding(ContextFinding::UnusedReturn(call_loc, func));
+            }
+        }
+    }
+
+    /// When entering a constructor, calls the constructors of inherited contracts that are
+    /// passed arguments in the inheritance list (i.e. `contract A is B(1)`) before the body
+    fn call_base_constructors(&mut self, ctx: ContextNode, func: FunctionNode, loc: Loc) {
+        if func.underlying(self).ty != FunctionTy::Constructor {
+            return;
+        }
+
+        let con_node = self
+            .graph()
+            .edges_directed(func.into(), Direction::Outgoing)
+            .find(|edge| *edge.weight() == Edge::Constructor)
+            .map(|edge| ContractNode::from(edge.target()));
+
+        if let Some(con_node) = con_node {
+            for (base_constructor, args) in con_node.base_constructor_calls(self) {
+                if ctx.is_killed(self) {
+                    return;
+                }
+                if args.len() != base_constructor.params(self).len() {
+                    continue;
+                }
+                let inputs = ExprRet::Multi(
+                    args.iter()
+                        .map(|arg| self.parse_ctx_expr(arg, ctx))
+                        .collect(),
+                );
+                if !ctx.is_killed(self) {
+                    self.func_call(ctx, loc, &inputs, base_constructor);
+                }
+            }
+        }
+    }
+
+    /// The bounds an immutable state variable is left with by its contract's constructor, joined
+    /// across every path through the constructor that doesn't revert. `None` if the variable
+    /// isn't immutable, the context is the constructor itself, or a path leaves it unknown
+    fn constructor_bounds(
+        &self,
+        ctx: ContextNode,
+        var_idx: NodeIdx,
+    ) -> Option<(Elem<Concrete>, Elem<Concrete>)> {
+        let is_immutable = match self.node(var_idx) {
+            Node::Var(var) => var
+                .attrs
+                .iter()
+                .any(|attr| matches!(attr, VariableAttribute::Immutable(_))),
+            _ => false,
+        };
+        let func = ctx.associated_fn(self);
+        if !is_immutable || func.underlying(self).ty == FunctionTy::Constructor {
+            return None;
+        }
+
+        let body_ctx = func.contract(self)?.constructor(self)?.maybe_body_ctx(self)?;
+        let name = VarNode::from(var_idx).name(self);
+        let mut bounds: Option<(Elem<Concrete>, Elem<Concrete>)> = None;
+        for end_ctx in body_ctx.terminal_child_list(self) {
+            if end_ctx.is_killed(self) {
+                continue;
+            }
+            let cvar = end_ctx.latest_var_by_name(self, &name)?;
+            let min = cvar.evaled_range_min(self)?;
+            let max = cvar.evaled_range_max(self)?;
+            bounds = Some(match bounds {
+                Some((curr_min, curr_max)) => (
+                    if let Some(Ordering::Less) = min.range_ord(&curr_min) {
+                        min
+                    } else {
+                        curr_min
+                    },
+                    if let Some(Ordering::Greater) = max.range_ord(&curr_max) {
+                        max
+                    } else {
+                        curr_max
+                    },
+                ),
+                None => (min, max),
+            });
+        }
+        bounds
+    }
+
+    /// Returns the current values of the named returns of the context's function, as done by a
+    /// bare `return;` or reaching the end of the function body. Nothing is returned if any of
+    /// the returns are unnamed
+    fn return_named_vars(&mut self, loc: Loc, ctx: ContextNode) {
+        let rets = ctx.executing_fn(self).returns(self);
+        if rets.is_empty() {
+            return;
+        }
+
+        let vars = rets
+            .iter()
+            .map(|ret| {
+                let name = ret.maybe_name(self)?;
+                ctx.latest_var_by_name(self, &name)
+            })
+            .collect::<Option<Vec<_>>>();
+
+        if let Some(vars) = vars {
+            let paths = ExprRet::Multi(
+                vars.into_iter()
+                    .map(|var| ExprRet::Single((ctx, var.into())))
+                    .collect(),
+            );
+            self.return_match(&loc, &paths);
+        }
+    }
+
     fn return_match(&mut self, loc: &Loc, paths: &ExprRet) {
         match paths {
             ExprRet::CtxKilled => {}
@@ -456,19 +981,21 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                 ctx.add_return_node(*loc, ContextVarNode::from(*expr).latest_version(self), self);
             }
             ExprRet::Multi(rets) => {
-                rets.iter().for_each(|expr_ret| {
-                    let (ctx, expr) = expr_ret.expect_single();
-                    self.add_edge(
-                        ContextVarNode::from(expr).latest_version(self),
-                        ctx,
-                        Edge::Context(ContextEdge::Return),
-                    );
-                    ctx.add_return_node(
-                        *loc,
-                        ContextVarNode::from(expr).latest_version(self),
-                        self,
-                    );
+                let ctx = rets.iter().find_map(|ret| match ret {
+                    ExprRet::Single((ctx, _)) | ExprRet::SingleLiteral((ctx, _)) => Some(*ctx),
+                    _ => None,
                 });
+                if let Some(ctx) = ctx {
+                    let expected = ctx.executing_fn(self).returns(self).len();
+                    if expected != rets.len() {
+                        panic!(
+                            "Return length mismatch: function returns {} values but {} were given",
+                            expected,
+                            rets.len()
+                        );
+                    }
+                }
+                rets.iter().for_each(|expr_ret| self.return_match(loc, expr_ret));
             }
             ExprRet::Fork(world1, world2) => {
                 self.return_match(loc, world1);
@@ -510,6 +1037,7 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                     is_symbolic: true,
                     tmp_of: None,
                     ty,
+                    metadata: Default::default(),
                 };
                 let lhs = ContextVarNode::from(self.add_node(Node::ContextVar(var)));
                 self.add_edge(lhs, *rhs_ctx, Edge::Context(ContextEdge::Variable));
@@ -529,6 +1057,7 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                     is_symbolic: true,
                     tmp_of: None,
                     ty,
+                    metadata: Default::default(),
                 };
                 let lhs = ContextVarNode::from(self.add_node(Node::ContextVar(var)));
                 self.add_edge(lhs, *lhs_ctx, Edge::Context(ContextEdge::Variable));
@@ -727,7 +1256,9 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
             ArraySubscript(loc, ty_expr, Some(index_expr)) => {
                 self.index_into_array(*loc, ty_expr, index_expr, ctx)
             }
-            ArraySlice(_loc, _lhs_expr, _maybe_middle_expr, _maybe_rhs) => todo!("Array slice"),
+            ArraySlice(loc, arr_expr, maybe_start, maybe_end) => {
+                self.slice_array(*loc, arr_expr, maybe_start, maybe_end, ctx)
+            }
             ArrayLiteral(_, _) => todo!("Array literal"),
 
             // Comparator
@@ -750,7 +1281,21 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                 self.fn_call_expr(ctx, loc, func_expr, input_exprs)
             }
             // member
-            New(_loc, expr) => self.parse_ctx_expr(expr, ctx),
+            New(_loc, expr) => {
+                // `new bytes(n)` and `new string(n)` allocate a buffer of length `n`, rather than
+                // casting `n`
+                if let FunctionCall(loc, ty_expr, input_exprs) = &**expr {
+                    if let Type(_, ty) = &**ty_expr {
+                        if let Some(ty @ (Builtin::DynamicBytes | Builtin::String)) =
+                            Builtin::try_from_ty(ty.clone(), self)
+                        {
+                            let ty_idx = self.builtin_or_add(ty);
+                            return self.new_dyn_builtin(loc, ty_idx, &input_exprs[0], ctx);
+                        }
+                    }
+                }
+                self.parse_ctx_expr(expr, ctx)
+            }
             This(loc) => {
                 let var = ContextVar::new_from_contract(*loc, ctx.associated_contract(self), self);
                 let cvar = self.add_node(Node::ContextVar(var));
@@ -761,7 +1306,11 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                 self.member_access(*loc, member_expr, ident, ctx)
             }
 
-            Delete(_loc, _expr) => todo!("Delete"),
+            Delete(loc, expr) => {
+                let resp = self.parse_ctx_expr(expr, ctx);
+                let is_element = matches!(**expr, ArraySubscript(_, _, Some(_)));
+                self.match_delete(*loc, is_element, &resp)
+            }
 
             // de/increment stuff
             PreIncrement(loc, expr) => {
@@ -821,6 +1370,7 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                     if increment {
                         if pre {
                             let new_cvar = self.advance_var_in_ctx(cvar, loc, *ctx);
+                            self.mark_storage_write(new_cvar, *ctx);
                             new_cvar.set_range_min(self, r.min + one.clone());
                             new_cvar.set_range_max(self, r.max + one);
                             ExprRet::Single((*ctx, new_cvar.into()))
@@ -832,6 +1382,7 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                         }
                     } else if pre {
                         let new_cvar = self.advance_var_in_ctx(cvar, loc, *ctx);
+                        self.mark_storage_write(new_cvar, *ctx);
                         new_cvar.set_range_min(self, r.min - one.clone());
                         new_cvar.set_range_max(self, r.max - one);
                         ExprRet::Single((*ctx, new_cvar.into()))
@@ -858,6 +1409,71 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
         }
     }
 
+    /// Resets a deleted variable to the zero value of its type. Deleting an array element
+    /// (`delete arr[i]`) only resets the element, while deleting a whole array also sets its
+    /// length and any of its known elements to zero
+    fn match_delete(&mut self, loc: Loc, is_element: bool, rhs: &ExprRet) -> ExprRet {
+        match rhs {
+            ExprRet::CtxKilled => ExprRet::CtxKilled,
+            ExprRet::Single((ctx, var)) | ExprRet::SingleLiteral((ctx, var)) => {
+                let cvar = ContextVarNode::from(*var).latest_version(self);
+                let new_cvar = self.advance_var_in_ctx(cvar, loc, *ctx);
+                self.mark_storage_write(new_cvar, *ctx);
+                if !is_element && new_cvar.underlying(self).ty.is_dyn_builtin(self) {
+                    let arr = new_cvar.first_version(self);
+                    let len_var = self.tmp_length(arr, *ctx, loc);
+                    let new_len = self.advance_var_in_ctx(len_var, loc, *ctx);
+                    let zero = Elem::from(Concrete::from(U256::zero()));
+                    new_len.set_range_min(self, zero.clone());
+                    new_len.set_range_max(self, zero.clone());
+
+                    let empty = Elem::ConcreteDyn(Box::new(RangeDyn {
+                        len: zero,
+                        val: Default::default(),
+                        loc,
+                    }));
+                    new_cvar.set_range_min(self, empty.clone());
+                    new_cvar.set_range_max(self, empty);
+                    new_cvar.set_range_exclusions(self, vec![]);
+
+                    let elems: Vec<_> = self
+                        .graph()
+                        .edges_directed(arr.into(), Direction::Incoming)
+                        .filter(|edge| *edge.weight() == Edge::Context(ContextEdge::IndexAccess))
+                        .map(|edge| ContextVarNode::from(edge.source()).latest_version(self))
+                        .collect();
+                    elems.into_iter().for_each(|elem| {
+                        let new_elem = self.advance_var_in_ctx(elem, loc, *ctx);
+                        self.reset_to_zero(new_elem);
+                    });
+                } else {
+                    self.reset_to_zero(new_cvar);
+                }
+                ExprRet::Single((*ctx, new_cvar.into()))
+            }
+            ExprRet::Multi(inner) => ExprRet::Multi(
+                inner
+                    .iter()
+                    .map(|expr| self.match_delete(loc, is_element, expr))
+                    .collect(),
+            ),
+            ExprRet::Fork(w1, w2) => ExprRet::Fork(
+                Box::new(self.match_delete(loc, is_element, w1)),
+                Box::new(self.match_delete(loc, is_element, w2)),
+            ),
+        }
+    }
+
+    /// Sets the range of a value type variable to the zero value of its type
+    fn reset_to_zero(&mut self, cvar: ContextVarNode) {
+        let zero = cvar.builtin(self).and_then(|builtin| builtin.zero_value());
+        if let Some(zero) = zero {
+            cvar.set_range_min(self, Elem::from(zero.clone()));
+            cvar.set_range_max(self, Elem::from(zero));
+            cvar.set_range_exclusions(self, vec![]);
+        }
+    }
+
     fn assign_exprs(
         &mut self,
         loc: Loc,
@@ -957,13 +1573,20 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
     ) -> ExprRet {
 
         // println!("rhs_range: {:?}", rhs_cvar.range(self));
-        let (new_lower_bound, new_upper_bound): (Elem<Concrete>, Elem<Concrete>) = (
-            Elem::Dynamic(Dynamic::new(rhs_cvar.latest_version(self).into(), loc)),
-            Elem::Dynamic(Dynamic::new(rhs_cvar.latest_version(self).into(), loc)),
-        );
+        let (new_lower_bound, new_upper_bound): (Elem<Concrete>, Elem<Concrete>) =
+            match self.const_value(rhs_cvar.latest_version(self)) {
+                // copy constants by value so that chains of assignments stay concrete
+                Some(val) => (val.clone(), val),
+                None => (
+                    Elem::Dynamic(Dynamic::new(rhs_cvar.latest_version(self).into(), loc)),
+                    Elem::Dynamic(Dynamic::new(rhs_cvar.latest_version(self).into(), loc)),
+                ),
+            };
 
 
         let new_lhs = self.advance_var_in_ctx(lhs_cvar.latest_version(self), loc, ctx);
+        self.mark_storage_write(new_lhs, ctx);
+        self.assign_taint(rhs_cvar, new_lhs);
         if !lhs_cvar.ty_eq(&rhs_cvar, self) {
             let _ = new_lhs.try_set_range_min(
                 self,
@@ -984,6 +1607,7 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
         if let Some(arr) = lhs_cvar.index_to_array(self) {
             if let Some(index) = lhs_cvar.index_access_to_index(self) {
                 let next_arr = self.advance_var_in_ctx(arr, loc, ctx);
+                self.mark_storage_write(next_arr, ctx);
                 if next_arr.underlying(self).ty.is_dyn_builtin(self) {
                     if let Some(r) = next_arr.range(self) {
                         let min = r.evaled_range_min(self);
@@ -1012,6 +1636,14 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
         ExprRet::Single((ctx, new_lhs.into()))
     }
 
+    /// The concrete value of a variable whose range is a single point
+    fn const_value(&self, cvar: ContextVarNode) -> Option<Elem<Concrete>> {
+        match (cvar.evaled_range_min(self)?, cvar.evaled_range_max(self)?) {
+            (min @ Elem::Concrete(_), max @ Elem::Concrete(_)) if min.range_eq(&max) => Some(min),
+            _ => None,
+        }
+    }
+
     fn advance_var_in_ctx(
         &mut self,
         cvar_node: ContextVarNode,
@@ -1029,6 +1661,7 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
                 self.add_edge(new_cvarnode, ctx, Edge::Context(ContextEdge::Variable));
             } else {
                 self.add_edge(new_cvarnode, cvar_node.0, Edge::Context(ContextEdge::Prev));
+                ctx.cache_next_version(cvar_node, new_cvarnode.into(), self);
             }
         } else {
             self.add_edge(new_cvarnode, cvar_node.0, Edge::Context(ContextEdge::Prev));
@@ -1037,12 +1670,22 @@ pub trait ContextBuilder: AnalyzerLike<Expr = Expression> + Sized + ExprParser {
         ContextVarNode::from(new_cvarnode)
     }
 
+    /// Marks a new version of a variable as a write to storage if the variable is a storage variable
+    fn mark_storage_write(&mut self, cvar_node: ContextVarNode, ctx: ContextNode) {
+        if cvar_node.is_storage(self) {
+            self.add_edge(cvar_node, ctx, Edge::Context(ContextEdge::StorageAssign));
+        }
+    }
+
     fn advance_var_underlying(&mut self, cvar_node: ContextVarNode, loc: Loc) -> &mut ContextVar {
         assert_eq!(None, cvar_node.next_version(self));
         let mut new_cvar = cvar_node.latest_version(self).underlying(self).clone();
         new_cvar.loc = Some(loc);
         let new_cvarnode = self.add_node(Node::ContextVar(new_cvar));
         self.add_edge(new_cvarnode, cvar_node.0, Edge::Context(ContextEdge::Prev));
+        if let Some(ctx) = cvar_node.maybe_ctx(self) {
+            ctx.cache_next_version(cvar_node, new_cvarnode.into(), self);
+        }
         ContextVarNode::from(new_cvarnode).underlying_mut(self)
     }
 }
@@ -1,4 +1,4 @@
-use crate::analyzers::{bounds::BoundAnalyzer, *};
+use crate::analyzers::{bounds::BoundAnalyzer, findings::Severity, *};
 use shared::{
     analyzer::*,
     context::ContextNode,
@@ -7,17 +7,21 @@ use shared::{
     NodeIdx,
 };
 
-use ariadne::{Cache, Color, Config, Label, Report, ReportKind};
+use ariadne::{Color, Label, ReportKind};
 use std::collections::BTreeMap;
 
 #[derive(Debug, Clone)]
 pub struct AccessStorageWriteReport {
     pub msgs: Vec<String>,
+    pub span: LocStrSpan,
 }
 
 impl AccessStorageWriteReport {
     pub fn new(msgs: Vec<String>) -> Self {
-        Self { msgs }
+        Self {
+            msgs,
+            span: LocStrSpan::default(),
+        }
     }
 }
 
@@ -25,6 +29,9 @@ impl ReportDisplay for AccessStorageWriteReport {
     fn report_kind(&self) -> ReportKind {
         ReportKind::Custom("Access Analysis", Color::Green)
     }
+    fn severity(&self) -> Severity {
+        Severity::Advice
+    }
     fn msg(&self, _analyzer: &(impl AnalyzerLike + Search)) -> String {
         self.msgs.join(";\n")
     }
@@ -33,34 +40,8 @@ impl ReportDisplay for AccessStorageWriteReport {
         vec![]
     }
 
-    fn reports(&self, analyzer: &(impl AnalyzerLike + Search)) -> Vec<Report<LocStrSpan>> {
-        let report = Report::build(self.report_kind(), "".to_string(), 0)
-            .with_message(self.msg(analyzer))
-            .with_config(
-                Config::default()
-                    .with_cross_gap(false)
-                    .with_underlines(true)
-                    .with_tab_width(4),
-            );
-        vec![report.finish()]
-    }
-
-    fn print_reports(&self, src: &mut impl Cache<String>, analyzer: &(impl AnalyzerLike + Search)) {
-        let reports = &self.reports(analyzer);
-        for report in reports.iter() {
-            report.print(&mut *src).unwrap();
-        }
-    }
-
-    fn eprint_reports(
-        &self,
-        mut src: &mut impl Cache<String>,
-        analyzer: &(impl AnalyzerLike + Search),
-    ) {
-        let reports = &self.reports(analyzer);
-        reports.iter().for_each(|report| {
-            report.eprint(&mut src).unwrap();
-        });
+    fn report_span(&self) -> &LocStrSpan {
+        &self.span
     }
 }
 
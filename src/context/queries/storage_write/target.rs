@@ -1,5 +1,6 @@
 use crate::analyzers::{
     bounds::{BoundAnalysis, BoundAnalyzer},
+    findings::Severity,
     *,
 };
 use shared::{
@@ -9,7 +10,7 @@ use shared::{
     NodeIdx,
 };
 
-use ariadne::{Cache, Color, Config, Fmt, Label, Report, ReportKind, Span};
+use ariadne::{Color, Config, Fmt, Label, Report, ReportKind, Span};
 use std::collections::BTreeMap;
 
 #[derive(Debug, Clone)]
@@ -23,6 +24,9 @@ impl ReportDisplay for StorageRangeReport {
     fn report_kind(&self) -> ReportKind {
         ReportKind::Custom("Storage Write Query", Color::Green)
     }
+    fn severity(&self) -> Severity {
+        Severity::Advice
+    }
     fn msg(&self, analyzer: &(impl AnalyzerLike + Search)) -> String {
         let bounds_string = self
             .analysis
@@ -96,6 +100,10 @@ impl ReportDisplay for StorageRangeReport {
         vec![]
     }
 
+    fn report_span(&self) -> &LocStrSpan {
+        &self.analysis.var_def.0
+    }
+
     fn reports(&self, analyzer: &(impl AnalyzerLike + Search)) -> Vec<Report<LocStrSpan>> {
         let mut report = Report::build(
             self.analysis.report_kind(),
@@ -126,24 +134,6 @@ impl ReportDisplay for StorageRangeReport {
 
         reports
     }
-
-    fn print_reports(&self, src: &mut impl Cache<String>, analyzer: &(impl AnalyzerLike + Search)) {
-        let reports = &self.reports(analyzer);
-        for report in reports.iter() {
-            report.print(&mut *src).unwrap();
-        }
-    }
-
-    fn eprint_reports(
-        &self,
-        mut src: &mut impl Cache<String>,
-        analyzer: &(impl AnalyzerLike + Search),
-    ) {
-        let reports = &self.reports(analyzer);
-        reports.iter().for_each(|report| {
-            report.eprint(&mut src).unwrap();
-        });
-    }
 }
 
 impl<T> StorageRangeQuery for T where T: BoundAnalyzer + Search + AnalyzerLike + Sized {}
@@ -0,0 +1,49 @@
+use shared::analyzer::AnalyzerLike;
+use shared::context::*;
+use solang_parser::pt::{Expression, Loc};
+
+impl<T> TaintTracker for T where T: AnalyzerLike<Expr = Expression> + Sized {}
+/// Tracks which variables derive from input an attacker may control, using the `Taint` metadata
+/// of variables
+pub trait TaintTracker: AnalyzerLike<Expr = Expression> + Sized {
+    /// Marks a variable as untrusted input
+    fn taint_source(&mut self, cvar: ContextVarNode, source: String) {
+        cvar.set_metadata(self, Taint { source });
+    }
+
+    /// Carries the taint of the first tainted input of an operation over to its result
+    fn propagate_taint(&mut self, inputs: &[ContextVarNode], result: ContextVarNode) {
+        if let Some(taint) = inputs
+            .iter()
+            .find_map(|input| input.metadata::<Taint>(self))
+            .cloned()
+        {
+            result.set_metadata(self, taint);
+        }
+    }
+
+    /// An assigned variable is tainted exactly when the assigned value is, regardless of what
+    /// it held before
+    fn assign_taint(&mut self, rhs: ContextVarNode, new_lhs: ContextVarNode) {
+        match rhs.metadata::<Taint>(self).cloned() {
+            Some(taint) => new_lhs.set_metadata(self, taint),
+            None => {
+                new_lhs.underlying_mut(self).metadata.remove::<Taint>();
+            }
+        }
+    }
+
+    /// Records the use of a tainted variable by a sink
+    fn check_taint_sink(
+        &mut self,
+        ctx: ContextNode,
+        loc: Loc,
+        sink: TaintSink,
+        cvar: ContextVarNode,
+    ) {
+        if cvar.metadata::<Taint>(self).is_some() {
+            ctx.underlying_mut(self)
+                .add_finding(ContextFinding::TaintedSink(loc, sink, cvar));
+        }
+    }
+}
@@ -1,35 +1,68 @@
-use ethers_core::types::U256;
 use shared::analyzer::*;
+use shared::context::{ContextEdge, ContextNode, ContextVarNode};
 use shared::nodes::*;
+use shared::range::{elem::RangeElem, Range};
 use shared::{Edge, Node, NodeIdx};
 use solang_parser::pt::Import;
 
 use solang_parser::pt::{
-    ContractDefinition, ContractPart, EnumDefinition, ErrorDefinition, Expression,
-    FunctionDefinition, FunctionTy, SourceUnit, SourceUnitPart, StructDefinition, TypeDefinition,
-    Using, UsingList, VariableDefinition,
+    ContractDefinition, ContractPart, EnumDefinition, ErrorDefinition, EventDefinition, Expression,
+    FunctionDefinition, FunctionTy, Loc, SourceUnit, SourceUnitPart, StructDefinition,
+    TypeDefinition, Using, UsingList, VariableDefinition,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
 };
-use std::{collections::HashMap, fs};
 
-use petgraph::{graph::*, Directed};
+use petgraph::{graph::*, stable_graph::StableGraph, visit::EdgeRef, Directed, Direction};
 
 mod builtin_fns;
 
 pub mod context;
 // pub mod range;
+use context::exprs::CondOp;
 use context::*;
 
 #[derive(Debug, Clone)]
 pub struct Analyzer {
+    /// Import path prefixes and what they are replaced with, i.e. `@openzeppelin/` to
+    /// `lib/openzeppelin-contracts/contracts/`
     pub remappings: HashMap<String, String>,
     pub file_no: usize,
+    /// Sources of imported files keyed by their resolved path. Imports missing from here are
+    /// read from the filesystem, unless `read_imports_from_fs` is false
+    pub sources: BTreeMap<String, String>,
+    /// Whether imports missing from `sources` are read from the filesystem. When false they are
+    /// skipped with a diagnostic instead, i.e. when `analyze_project`'s resolver can't provide them
+    pub read_imports_from_fs: bool,
+    /// Resolved paths of files that have been parsed, or are being parsed, so each file is only
+    /// parsed once and circular imports terminate
+    pub parsed_files: BTreeSet<String>,
+    /// The resolved path of the file currently being parsed, used to resolve relative imports
+    pub current_path: Option<String>,
     pub msg: MsgNode,
     pub block: BlockNode,
-    pub graph: Graph<Node, Edge, Directed, usize>,
+    pub graph: StableGraph<Node, Edge, Directed, usize>,
     pub builtins: HashMap<Builtin, NodeIdx>,
     pub user_types: HashMap<String, NodeIdx>,
     pub builtin_fns: HashMap<String, Function>,
     pub builtin_fn_inputs: HashMap<String, (Vec<FunctionParam>, Vec<FunctionReturn>)>,
+    pub metrics: Option<Metrics>,
+    pub max_call_depth: usize,
+    pub max_branch_contexts: usize,
+    pub strict_requires: bool,
+    /// Patterns of functions whose calls aren't inlined, i.e. `_transfer`, `ERC20._transfer` or
+    /// `_transfer(address,address,uint256)`. A trailing `*` matches any suffix, i.e. `ERC20.*`.
+    /// Should be set before `parse`
+    pub trusted_fns: Vec<String>,
+    /// The `(major, minor, patch)` compiler version whose semantics are used. Before 0.8.0
+    /// arithmetic wraps by default. Defaults to checked arithmetic when not set
+    pub solc_version: Option<(u64, u64, u64)>,
+    /// The type given to number literals that have no type from their context
+    pub literal_ty: LiteralTy,
+    /// Things that were skipped instead of analyzed, with their location
+    pub diagnostics: Vec<(Loc, String)>,
 }
 
 impl Default for Analyzer {
@@ -37,6 +70,10 @@ impl Default for Analyzer {
         let mut a = Self {
             remappings: Default::default(),
             file_no: 0,
+            sources: Default::default(),
+            read_imports_from_fs: true,
+            parsed_files: Default::default(),
+            current_path: None,
             msg: MsgNode(0),
             block: BlockNode(0),
             graph: Default::default(),
@@ -44,6 +81,14 @@ impl Default for Analyzer {
             user_types: Default::default(),
             builtin_fns: builtin_fns::builtin_fns(),
             builtin_fn_inputs: Default::default(),
+            metrics: None,
+            max_call_depth: 8,
+            max_branch_contexts: 1024,
+            strict_requires: false,
+            trusted_fns: vec![],
+            solc_version: None,
+            literal_ty: LiteralTy::SmallestFitting,
+            diagnostics: vec![],
         };
         a.builtin_fn_inputs = builtin_fns::builtin_fns_inputs(&mut a);
 
@@ -59,11 +104,11 @@ impl Default for Analyzer {
 }
 
 impl GraphLike for Analyzer {
-    fn graph_mut(&mut self) -> &mut Graph<Node, Edge, Directed, usize> {
+    fn graph_mut(&mut self) -> &mut StableGraph<Node, Edge, Directed, usize> {
         &mut self.graph
     }
 
-    fn graph(&self) -> &Graph<Node, Edge, Directed, usize> {
+    fn graph(&self) -> &StableGraph<Node, Edge, Directed, usize> {
         &self.graph
     }
 }
@@ -78,6 +123,68 @@ impl AnalyzerLike for Analyzer {
         self.block
     }
 
+    fn metrics_mut(&mut self) -> Option<&mut Metrics> {
+        self.metrics.as_mut()
+    }
+
+    fn max_call_depth(&self) -> usize {
+        self.max_call_depth
+    }
+
+    fn max_branch_contexts(&self) -> usize {
+        self.max_branch_contexts
+    }
+
+    fn strict_requires(&self) -> bool {
+        self.strict_requires
+    }
+
+    fn checked_arithmetic(&self) -> bool {
+        self.solc_version
+            .map_or(true, |version| version >= (0, 8, 0))
+    }
+
+    fn literal_ty(&self) -> LiteralTy {
+        self.literal_ty
+    }
+
+    fn add_diagnostic(&mut self, loc: Loc, msg: String) {
+        self.diagnostics.push((loc, msg));
+    }
+
+    fn is_trusted_fn(&self, func: FunctionNode) -> bool {
+        if self.trusted_fns.is_empty() {
+            return false;
+        }
+
+        // function names include their parameter list, i.e. `_transfer(address,uint256)`, so
+        // patterns may name either the bare function or its full signature
+        let signature = func.name(self);
+        let name = signature
+            .split('(')
+            .next()
+            .unwrap_or(&signature)
+            .to_string();
+        let mut candidates = vec![name, signature];
+        if let Some(contract) = func.contract(self) {
+            let contract_name = contract.name(self);
+            candidates.extend(
+                candidates
+                    .clone()
+                    .into_iter()
+                    .map(|candidate| format!("{contract_name}.{candidate}")),
+            );
+        }
+        self.trusted_fns.iter().any(|pattern| {
+            candidates
+                .iter()
+                .any(|candidate| match pattern.strip_suffix('*') {
+                    Some(prefix) => candidate.starts_with(prefix),
+                    None => candidate == pattern,
+                })
+        })
+    }
+
     fn builtin_fns(&self) -> &HashMap<String, Function> {
         &self.builtin_fns
     }
@@ -119,6 +226,8 @@ impl AnalyzerLike for Analyzer {
             Variable(ident) => {
                 if let Some(idx) = self.user_types.get(&ident.name) {
                     *idx
+                } else if let Some(builtin) = Builtin::try_from_fixed_name(&ident.name) {
+                    self.builtin_or_add(builtin)
                 } else {
                     let node = self.add_node(Node::Unresolved(ident.clone()));
                     self.user_types.insert(ident.name.clone(), node);
@@ -158,22 +267,223 @@ impl AnalyzerLike for Analyzer {
                 // }
                 0.into()
             }
-            NumberLiteral(_loc, int, exp, _unit) => {
-                let int = U256::from_dec_str(int).unwrap();
-                let val = if !exp.is_empty() {
-                    let exp = U256::from_dec_str(exp).unwrap();
-                    int.pow(exp)
-                } else {
-                    int
-                };
-                self.add_node(Node::Concrete(Concrete::Uint(256, val)))
+            MemberAccess(_loc, scope_expr, ident) => {
+                // a type qualified by the contract or library that defines it, i.e. `Lib.Struct`
+                let scope = self.parse_expr(scope_expr);
+                if let Node::Contract(_) = self.node(scope) {
+                    if let Some(idx) =
+                        ContractNode::from(scope).user_type_by_name(self, &ident.name)
+                    {
+                        return idx;
+                    }
+                }
+                // the scope may not be parsed yet, so fall back to resolving by name
+                self.parse_expr(&Variable(ident.clone()))
             }
+            NumberLiteral(loc, int, exp, _unit) => match Concrete::dec_literal_val(int, exp) {
+                Some(val) => self.add_node(Node::Concrete(Concrete::Uint(256, val))),
+                None => {
+                    self.add_diagnostic(
+                        *loc,
+                        format!("Number literal {int}e{exp} is not a 256 bit integer"),
+                    );
+                    0.into()
+                }
+            },
             _ => 0.into(),
         }
     }
 }
 
 impl Analyzer {
+    /// Enables collection of analysis metrics. Should be called before `parse`
+    pub fn enable_metrics(&mut self) {
+        self.metrics = Some(Metrics::default());
+    }
+
+    /// Gets the collected analysis metrics, if metrics collection is enabled
+    pub fn metrics(&self) -> Option<Metrics> {
+        let mut metrics = self.metrics.clone()?;
+        metrics.nodes = self.graph.node_count();
+        metrics.edges = self.graph.edge_count();
+        metrics.contexts = self
+            .graph
+            .node_indices()
+            .filter(|idx| matches!(self.node(*idx), Node::Context(_)))
+            .count();
+        Some(metrics)
+    }
+
+    /// Removes temporary variables whose ranges have been fully absorbed into the variables that
+    /// consumed them. A temporary is removed only if no range, temporary construction or context
+    /// (i.e. as a return, a dependency or a reported cast) refers to it, it has no later versions
+    /// and its only edges are to its context and its previous version. Removing a temporary can
+    /// make its previous version prunable, so this runs until nothing else can be removed.
+    /// Should be called after `parse`
+    pub fn prune_temporaries(&mut self) {
+        loop {
+            let referenced = self.referenced_vars();
+            let prunable: BTreeSet<NodeIdx> = self
+                .graph
+                .node_indices()
+                .filter(|idx| match self.node(*idx) {
+                    Node::ContextVar(cvar) => cvar.is_tmp && !referenced.contains(idx),
+                    _ => false,
+                })
+                .filter(|idx| {
+                    self.graph
+                        .edges_directed(*idx, Direction::Incoming)
+                        .next()
+                        .is_none()
+                        && self
+                            .graph
+                            .edges_directed(*idx, Direction::Outgoing)
+                            .all(|edge| {
+                                matches!(
+                                    edge.weight(),
+                                    Edge::Context(ContextEdge::Variable)
+                                        | Edge::Context(ContextEdge::Prev)
+                                )
+                            })
+                })
+                .collect();
+
+            if prunable.is_empty() {
+                break;
+            }
+
+            self.remove_nodes(prunable);
+        }
+    }
+
+    /// Formats the range of each user variable at the exit of a function, keyed by variable
+    /// name. If the function exits from multiple live branches, a variable's ranges at each exit
+    /// are merged, i.e. `uint256[0, 5]` and `uint256[10, 20]` become `uint256[0, 20]`. Ranges
+    /// that can't be merged are listed, separated by ` | `. Temporaries are skipped. Should be
+    /// called after `parse`
+    pub fn exit_ranges(&self, func: FunctionNode) -> BTreeMap<String, String> {
+        let mut exit_vars: BTreeMap<String, Vec<ContextVarNode>> = BTreeMap::default();
+        if let Some(body_ctx) = func.maybe_body_ctx(self) {
+            body_ctx
+                .terminal_child_list(self)
+                .into_iter()
+                .filter(|exit| !exit.is_killed(self))
+                .for_each(|exit| {
+                    let mut names = BTreeSet::default();
+                    let mut curr: Option<ContextNode> = Some(exit);
+                    while let Some(ctx) = curr {
+                        names.extend(
+                            ctx.local_vars(self)
+                                .into_iter()
+                                .filter(|var| !var.is_tmp(self))
+                                .map(|var| var.name(self)),
+                        );
+                        curr = ctx.underlying(self).parent_ctx;
+                    }
+                    names.into_iter().for_each(|name| {
+                        if let Some(var) = exit.var_by_name_or_recurse(self, &name) {
+                            exit_vars
+                                .entry(name)
+                                .or_default()
+                                .push(var.latest_version(self));
+                        }
+                    });
+                });
+        }
+
+        exit_vars
+            .into_iter()
+            .filter_map(|(name, vars)| {
+                let mut range_strs: Vec<String> = vars
+                    .iter()
+                    .filter_map(|var| var.range_string(self))
+                    .collect();
+                range_strs.sort();
+                range_strs.dedup();
+                let range_str = match range_strs.len() {
+                    0 => return None,
+                    1 => range_strs.remove(0),
+                    _ => match self.union_bounds(&vars) {
+                        Some((min, max)) => format!(
+                            "{}[{}, {}]",
+                            vars[0].ty(self).as_string(self),
+                            min.to_decimal_string(false, self),
+                            max.to_decimal_string(true, self)
+                        ),
+                        None => range_strs.join(" | "),
+                    },
+                };
+                Some((name, range_str))
+            })
+            .collect()
+    }
+
+    /// Resolves a byte offset in the source to the function containing it and the innermost
+    /// context of that function whose location contains it, i.e. for hover tooltips in an
+    /// editor. Contexts of called functions are skipped. Both forks of an if statement have the
+    /// location of the whole statement, so an offset in either branch resolves to the first
+    /// fork. Should be called after `parse`
+    pub fn resolve_at_offset(&self, offset: usize) -> Option<(FunctionNode, ContextNode)> {
+        let span = |loc: Loc| match loc {
+            Loc::File(_, start, end) if start <= offset && offset < end => Some(end - start),
+            _ => None,
+        };
+
+        self.graph
+            .node_indices()
+            .filter(|idx| matches!(self.node(*idx), Node::Function(_)))
+            .map(FunctionNode::from)
+            .filter_map(|func| Some((func, func.maybe_body_ctx(self)?)))
+            .filter(|(func, body_ctx)| {
+                span(func.underlying(self).loc).is_some()
+                    || span(body_ctx.underlying(self).loc).is_some()
+            })
+            .flat_map(|(func, body_ctx)| {
+                let mut ctxs = vec![body_ctx];
+                ctxs.extend(body_ctx.subcontexts(self).into_iter().filter(|subctx| {
+                    matches!(self.node(*subctx), Node::Context(_))
+                        && subctx.associated_fn(self) == func
+                }));
+                ctxs.into_iter().map(move |ctx| (func, ctx))
+            })
+            .filter_map(|(func, ctx)| Some((span(ctx.underlying(self).loc)?, func, ctx)))
+            .min_by_key(|(span, _, ctx)| (*span, ctx.0))
+            .map(|(_, func, ctx)| (func, ctx))
+    }
+
+    /// Gets every context variable that is referenced by a range, a temporary construction or a
+    /// context
+    fn referenced_vars(&self) -> BTreeSet<NodeIdx> {
+        let mut referenced: BTreeSet<NodeIdx> = BTreeSet::default();
+        for idx in self.graph.node_indices() {
+            match self.node(idx) {
+                Node::ContextVar(cvar) => {
+                    if let Some(range) = cvar.ty.range(self) {
+                        referenced.extend(range.dependent_on().into_iter().map(NodeIdx::from));
+                        referenced.extend(
+                            range
+                                .exclusions
+                                .iter()
+                                .flat_map(|excl| excl.dependent_on())
+                                .map(NodeIdx::from),
+                        );
+                    }
+                    if let Some(tmp) = &cvar.tmp_of {
+                        referenced.insert(tmp.lhs.into());
+                        if let Some(rhs) = tmp.rhs {
+                            referenced.insert(rhs.into());
+                        }
+                    }
+                }
+                Node::Context(ctx) => {
+                    referenced.extend(ctx.referenced_vars().into_iter().map(NodeIdx::from));
+                }
+                _ => {}
+            }
+        }
+        referenced
+    }
+
     pub fn parse(
         &mut self,
         src: &str,
@@ -194,6 +504,14 @@ impl Analyzer {
                 funcs.iter().for_each(|func| {
                     // add params now that parsing is done
                     func.set_params_and_ret(self);
+                    // constructors and the `fallback` and `receive` functions can't be referred
+                    // to by name, and every contract can have its own
+                    if matches!(
+                        func.underlying(self).ty,
+                        FunctionTy::Constructor | FunctionTy::Fallback | FunctionTy::Receive
+                    ) {
+                        return;
+                    }
                     let name = func.name(self);
                     if let Some(user_ty_node) = self.user_types.get(&name).cloned() {
                         let underlying = func.underlying(self).clone();
@@ -205,10 +523,12 @@ impl Analyzer {
                     }
                 });
 
-                funcs.into_iter().for_each(|func| {
-                    if let Some(body) = &func.underlying(self).body.clone() {
-                        self.parse_ctx_statement(body, false, Some(func));
-                    }
+                // constructors go first so the state they initialize is known to the rest
+                let (constructors, funcs): (Vec<_>, Vec<_>) = funcs
+                    .into_iter()
+                    .partition(|func| func.underlying(self).ty == FunctionTy::Constructor);
+                constructors.into_iter().chain(funcs).for_each(|func| {
+                    self.analyze_function_body(func);
                 });
 
                 (Some(parent), imported)
@@ -295,7 +615,10 @@ impl Analyzer {
                 let node = self.parse_ty_def(def);
                 self.add_edge(node, sup_node, Edge::Ty);
             }
-            EventDefinition(_def) => todo!(),
+            EventDefinition(def) => {
+                let node = self.parse_event_def(def);
+                self.add_edge(node, sup_node, Edge::Event);
+            }
             Annotation(_anno) => todo!(),
             Using(using) => usings.push((*using.clone(), parent)),
             StraySemicolon(_loc) => todo!(),
@@ -309,36 +632,125 @@ impl Analyzer {
         &mut self,
         import: &Import,
     ) -> Vec<(Option<NodeIdx>, String, String, usize)> {
-        match import {
-            Import::Plain(path, _) => {
-                // println!("path: {:?}", path);
-                let sol = fs::read_to_string(path.string.clone()).unwrap_or_else(|_| {
-                    panic!("Could not find file for dependency: {:?}", path.string)
-                });
-                self.file_no += 1;
-                let file_no = self.file_no;
-                let (maybe_entry, mut inner_sources) = self.parse(&sol);
-                inner_sources.push((maybe_entry, path.string.clone(), sol.to_string(), file_no));
-                inner_sources
-            }
-            Import::Rename(path, elems, _) => {
-                println!(
-                    "path: {:?}, elems: {:?}, curr: {:?}",
-                    path,
-                    elems,
-                    std::env::current_dir()
+        let (path, loc) = match import {
+            Import::Plain(path, _)
+            | Import::GlobalSymbol(path, _, _)
+            | Import::Rename(path, _, _) => (
+                self.resolve_import_path(self.current_path.as_deref(), &path.string),
+                path.loc,
+            ),
+        };
+
+        // already parsed, or an import cycle back to a file still being parsed
+        if !self.parsed_files.insert(path.clone()) {
+            return vec![];
+        }
+
+        let sol = match self.sources.get(&path) {
+            Some(sol) => sol.clone(),
+            None if self.read_imports_from_fs => fs::read_to_string(&path)
+                .unwrap_or_else(|_| panic!("Could not find file for dependency: {path:?}")),
+            None => {
+                self.add_diagnostic(
+                    loc,
+                    format!("Could not resolve import {path:?}, skipped it"),
                 );
-                let sol = fs::read_to_string(path.string.clone()).unwrap_or_else(|_| {
-                    panic!("Could not find file for dependency: {:?}", path.string)
+                return vec![];
+            }
+        };
+        self.file_no += 1;
+        let file_no = self.file_no;
+        let prev_path = self.current_path.replace(path.clone());
+        let (maybe_entry, mut inner_sources) = self.parse(&sol);
+        self.current_path = prev_path;
+        inner_sources.push((maybe_entry, path, sol, file_no));
+        inner_sources
+    }
+
+    /// Resolves an import path as written in `importer` to the path of the imported file.
+    /// Relative paths (starting with `./` or `../`) are joined to the importer's directory,
+    /// other paths have the longest matching remapping applied
+    pub fn resolve_import_path(&self, importer: Option<&str>, import: &str) -> String {
+        if import.starts_with("./") || import.starts_with("../") {
+            let dir = importer
+                .and_then(|importer| importer.rsplit_once('/'))
+                .map(|(dir, _)| dir)
+                .unwrap_or("");
+            let mut parts: Vec<&str> = dir.split('/').filter(|part| !part.is_empty()).collect();
+            import.split('/').for_each(|part| match part {
+                "." | "" => {}
+                ".." => {
+                    parts.pop();
+                }
+                part => parts.push(part),
+            });
+            let joined = parts.join("/");
+            if dir.starts_with('/') {
+                format!("/{joined}")
+            } else {
+                joined
+            }
+        } else {
+            self.remappings
+                .iter()
+                .filter(|(prefix, _)| import.starts_with(prefix.as_str()))
+                .max_by_key(|(prefix, _)| prefix.len())
+                .map(|(prefix, target)| format!("{target}{}", &import[prefix.len()..]))
+                .unwrap_or_else(|| import.to_string())
+        }
+    }
+
+    /// Parses and analyzes a project starting from the file at `root`, with `resolver` providing
+    /// the source of each file by its resolved path. Imports are resolved relative to the
+    /// importing file and through `remappings`, and each file is parsed once even if imported
+    /// multiple times or circularly. Imports the resolver can't provide are skipped with a
+    /// diagnostic, the filesystem is never read. Returns the root's source unit and the imported
+    /// sources, as `parse` does, or `None` if `resolver` can't provide the root
+    pub fn analyze_project(
+        &mut self,
+        root: &str,
+        resolver: impl Fn(&str) -> Option<String>,
+    ) -> (
+        Option<NodeIdx>,
+        Vec<(Option<NodeIdx>, String, String, usize)>,
+    ) {
+        let root_sol = match resolver(root) {
+            Some(sol) => sol,
+            None => return (None, vec![]),
+        };
+
+        // gather all reachable sources up front so imports don't need the resolver
+        let mut queue = vec![(root.to_string(), root_sol.clone())];
+        let mut seen: BTreeSet<String> = vec![root.to_string()].into_iter().collect();
+        while let Some((path, sol)) = queue.pop() {
+            if let Ok((source_unit, _comments)) = solang_parser::parse(&sol, 0) {
+                source_unit.0.iter().for_each(|part| {
+                    if let SourceUnitPart::ImportDirective(
+                        Import::Plain(import, _)
+                        | Import::GlobalSymbol(import, _, _)
+                        | Import::Rename(import, _, _),
+                    ) = part
+                    {
+                        let resolved = self.resolve_import_path(Some(&path), &import.string);
+                        if seen.insert(resolved.clone()) {
+                            if let Some(sol) = resolver(&resolved) {
+                                queue.push((resolved, sol));
+                            }
+                        }
+                    }
                 });
-                self.file_no += 1;
-                let file_no = self.file_no;
-                let (maybe_entry, mut inner_sources) = self.parse(&sol);
-                inner_sources.push((maybe_entry, path.string.clone(), sol.to_string(), file_no));
-                inner_sources
             }
-            e => todo!("import {:?}", e),
+            self.sources.insert(path, sol);
         }
+
+        // every file the resolver knows of is in `sources` now, anything else is missing
+        let prev_read_imports_from_fs = std::mem::replace(&mut self.read_imports_from_fs, false);
+        self.parsed_files.insert(root.to_string());
+        let prev_path = self.current_path.replace(root.to_string());
+        let res = self.parse(&root_sol);
+        self.current_path = prev_path;
+        self.read_imports_from_fs = prev_read_imports_from_fs;
+        res
     }
 
     pub fn parse_contract_def(
@@ -385,7 +797,10 @@ impl Analyzer {
                 let node = self.parse_ty_def(def);
                 self.add_edge(node, con_node, Edge::Ty);
             }
-            EventDefinition(_def) => {}
+            EventDefinition(def) => {
+                let node = self.parse_event_def(def);
+                self.add_edge(node, con_node, Edge::Event);
+            }
             Annotation(_anno) => todo!(),
             Using(using) => usings.push((*using.clone(), con_node.0.into())),
             StraySemicolon(_loc) => todo!(),
@@ -516,7 +931,25 @@ impl Analyzer {
     }
 
     pub fn parse_err_def(&mut self, err_def: &ErrorDefinition) -> ErrorNode {
-        let err_node = ErrorNode(self.add_node(Error::from(err_def.clone())).index());
+        let err = Error::from(err_def.clone());
+        let name = err.name.clone().expect("Error was not named").name;
+
+        // check if we have an unresolved type by the same name. Different contracts may declare
+        // errors of the same name, so an existing error is kept. This is only a fallback for
+        // `revert`s, which resolve errors in the reverting contract's scope
+        let err_node: ErrorNode = match self.user_types.get(&name).cloned() {
+            Some(user_ty_node) if matches!(self.node(user_ty_node), Node::Unresolved(_)) => {
+                let unresolved = self.node_mut(user_ty_node);
+                *unresolved = Node::Error(err);
+                ErrorNode::from(user_ty_node)
+            }
+            _ => {
+                let node = self.add_node(err);
+                self.user_types.entry(name).or_insert(node);
+                ErrorNode::from(node)
+            }
+        };
+
         err_def.fields.iter().for_each(|field| {
             let param = ErrorParam::new(self, field.clone());
             let field_node = self.add_node(param);
@@ -525,6 +958,34 @@ impl Analyzer {
         err_node
     }
 
+    pub fn parse_event_def(&mut self, event_def: &EventDefinition) -> EventNode {
+        let event = Event::from(event_def.clone());
+        let name = event.name.clone().expect("Event was not named").name;
+
+        // check if we have an unresolved type by the same name. Events are often redeclared,
+        // i.e. in an interface and its implementation, so an existing event is kept. This is
+        // only a fallback for `emit`s, which resolve events in the emitting contract's scope
+        let event_node: EventNode = match self.user_types.get(&name).cloned() {
+            Some(user_ty_node) if matches!(self.node(user_ty_node), Node::Unresolved(_)) => {
+                let unresolved = self.node_mut(user_ty_node);
+                *unresolved = Node::Event(event);
+                EventNode::from(user_ty_node)
+            }
+            _ => {
+                let node = self.add_node(event);
+                self.user_types.entry(name).or_insert(node);
+                EventNode::from(node)
+            }
+        };
+
+        event_def.fields.iter().for_each(|field| {
+            let param = EventParam::new(self, field.clone());
+            let field_node = self.add_node(param);
+            self.add_edge(field_node, event_node, Edge::EventParam);
+        });
+        event_node
+    }
+
     pub fn parse_func_def(
         &mut self,
         func_def: &FunctionDefinition,
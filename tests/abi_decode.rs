@@ -0,0 +1,127 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::context::exprs::Abi;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{
+    context::ContextNode,
+    nodes::{Builtin, Concrete, TypeNode, VarType},
+};
+use solang_parser::pt::Loc;
+
+const SOL: &str = include_str!("./test_data/abi_decode.sol");
+
+fn assert_bounds(
+    analyzer: &Analyzer,
+    ctx: ContextNode,
+    var_name: &str,
+    min: Concrete,
+    max: Concrete,
+) {
+    let var = ctx
+        .latest_var_by_name(analyzer, var_name)
+        .expect("No variable with that name");
+    assert!(var
+        .evaled_range_min(analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(min)));
+    assert!(var
+        .evaled_range_max(analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(max)));
+}
+
+#[test]
+fn test_decode_uint_and_bool() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "decode");
+    assert_bounds(
+        &analyzer,
+        ctx,
+        "a",
+        Concrete::from(U256::zero()),
+        Concrete::from(U256::MAX),
+    );
+    assert_bounds(
+        &analyzer,
+        ctx,
+        "b",
+        Concrete::Bool(false),
+        Concrete::Bool(true),
+    );
+}
+
+#[test]
+fn test_decode_narrow_types() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "decode_small");
+    assert_bounds(
+        &analyzer,
+        ctx,
+        "a",
+        Concrete::Uint(8, U256::zero()),
+        Concrete::Uint(8, U256::from(255)),
+    );
+}
+
+#[test]
+fn test_decode_struct() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "decode_struct");
+    let p = ctx
+        .latest_var_by_name(&analyzer, "p")
+        .expect("No variable with that name");
+    assert!(matches!(
+        p.ty(&analyzer),
+        VarType::User(TypeNode::Struct(_))
+    ));
+    assert_bounds(
+        &analyzer,
+        ctx,
+        "a",
+        Concrete::Uint(8, U256::zero()),
+        Concrete::Uint(8, U256::from(255)),
+    );
+    assert_bounds(
+        &analyzer,
+        ctx,
+        "b",
+        Concrete::Bool(false),
+        Concrete::Bool(true),
+    );
+}
+
+#[test]
+fn test_decode_struct_field() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "decode_struct_field");
+    assert_bounds(
+        &analyzer,
+        ctx,
+        "a",
+        Concrete::Uint(8, U256::zero()),
+        Concrete::Uint(8, U256::from(255)),
+    );
+}
+
+#[test]
+fn test_encode_call() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "encode_call");
+    let data = ctx
+        .latest_var_by_name(&analyzer, "data")
+        .expect("No variable with that name");
+    assert_eq!(data.builtin(&analyzer), Some(Builtin::DynamicBytes));
+    assert!(analyzer.diagnostics.is_empty());
+}
+
+#[test]
+fn test_unknown_abi_member_is_a_diagnostic() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "encode_call");
+    let ret = analyzer.abi_call(Loc::File(0, 0, 0), "encodeSomething", &[], ctx);
+    assert!(ret.expect_multi().is_empty());
+    assert_eq!(analyzer.diagnostics.len(), 1);
+}
@@ -0,0 +1,69 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::Address;
+use pyrometer::context::analyzers::access_control::MissingAccessControlAnalyzer;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextVarNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/access_control.sol");
+
+/// Asserts that the guarded path's `msg.sender` is bounded on both sides by `owner`
+fn assert_sender_is_owner(func_name: &str) {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, func_name);
+    let sender = ctx.latest_var_by_name(&analyzer, "msg.sender").unwrap();
+    let range = sender.range(&analyzer).unwrap();
+    for bound in [range.min, range.max] {
+        match bound {
+            Elem::Dynamic(dy) => {
+                assert_eq!(ContextVarNode::from(dy.idx).name(&analyzer), "owner")
+            }
+            e => panic!("msg.sender not bounded by owner: {e:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_sender_equal_to_owner() {
+    assert_sender_is_owner("guarded");
+}
+
+#[test]
+fn test_owner_equal_to_sender() {
+    assert_sender_is_owner("reversed");
+}
+
+#[test]
+fn test_sender_takes_known_owner() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "known_owner");
+    let sender = ctx.latest_var_by_name(&analyzer, "msg.sender").unwrap();
+    let mut bytes = [0u8; 20];
+    bytes[19] = 1;
+    let one = Elem::from(Concrete::Address(Address::from_slice(&bytes)));
+    assert!(sender.evaled_range_min(&analyzer).unwrap().range_eq(&one));
+    assert!(sender.evaled_range_max(&analyzer).unwrap().range_eq(&one));
+}
+
+fn missing_access_control(func_name: &str) -> bool {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, func_name);
+    let file_mapping = file_mapping("access_control.sol");
+    analyzer
+        .missing_access_control(&file_mapping, ctx)
+        .is_some()
+}
+
+#[test]
+fn test_unguarded_state_change_flagged() {
+    assert!(missing_access_control("setOwner"));
+}
+
+#[test]
+fn test_guarded_state_change_not_flagged() {
+    assert!(!missing_access_control("setOwnerGuarded"));
+    assert!(!missing_access_control("setOwnerOnlyOwner"));
+    assert!(!missing_access_control("guarded"));
+}
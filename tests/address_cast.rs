@@ -0,0 +1,65 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::{Address, U256};
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::elem_ty::{Elem, RangeConcrete};
+
+const SOL: &str = include_str!("./test_data/address_cast.sol");
+
+fn as_address(elem: Elem<Concrete>) -> Address {
+    match elem {
+        Elem::Concrete(RangeConcrete {
+            val: Concrete::Address(addr),
+            ..
+        }) => addr,
+        e => panic!("Not a concrete address: {e:?}"),
+    }
+}
+
+fn assert_address_range(func_name: &str, var_name: &str, min: Address, max: Address) {
+    let mut analyzer = Analyzer::default();
+    let var = body_var(&mut analyzer, SOL, func_name, var_name);
+    assert_eq!(as_address(var.evaled_range_min(&analyzer).unwrap()), min);
+    assert_eq!(as_address(var.evaled_range_max(&analyzer).unwrap()), max);
+}
+
+#[test]
+fn test_full_range_cast_chain() {
+    let max = Address::from_slice(&[0xff; 20]);
+    assert_address_range("full", "a", Address::zero(), max);
+    assert_address_range("full", "b", Address::zero(), max);
+}
+
+#[test]
+fn test_uint160_masked() {
+    let mut analyzer = Analyzer::default();
+    let y = body_var(&mut analyzer, SOL, "full", "y");
+    assert_eq!(
+        y.range_string(&analyzer).unwrap(),
+        format!("uint160[0, {}]", (U256::from(1) << 160) - 1)
+    );
+}
+
+#[test]
+fn test_constant_cast_chain_truncates() {
+    let five = Address::from_low_u64_be(5);
+    assert_address_range("constant_value", "a", five, five);
+}
+
+#[test]
+fn test_bounded_cast_chain_truncates() {
+    assert_address_range(
+        "bounded",
+        "a",
+        Address::zero(),
+        Address::from_low_u64_be(10),
+    );
+}
+
+#[test]
+fn test_address_round_trip() {
+    let max = Address::from_slice(&[0xff; 20]);
+    assert_address_range("round_trip", "b", Address::zero(), max);
+}
@@ -0,0 +1,138 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::context::analyzers::balances::{OverTransferAnalyzer, StrictBalanceAnalyzer};
+use pyrometer::context::ContextBuilder;
+use pyrometer::Analyzer;
+use shared::analyzer::GraphLike;
+use shared::context::{Context, ContextNode};
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem, SolcRange};
+use shared::Node;
+use solang_parser::pt::Loc;
+
+const SOL: &str = include_str!("./test_data/balance.sol");
+
+fn over_transfer_count(analyzer: &Analyzer, ctx: ContextNode) -> usize {
+    analyzer
+        .over_transfers(&file_mapping("balance.sol"), ctx)
+        .len()
+}
+
+fn strict_balance_count(func_name: &str) -> usize {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, func_name);
+    assert!(!ctx.is_killed(&analyzer));
+    analyzer
+        .strict_balance_checks(&file_mapping("balance.sol"), ctx)
+        .len()
+}
+
+#[test]
+fn test_over_transfer_flagged() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "over_transfer");
+    assert_eq!(over_transfer_count(&analyzer, ctx), 1);
+    // the transfer always reverts
+    assert!(ctx.is_killed(&analyzer));
+}
+
+#[test]
+fn test_over_send_flagged() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "over_send");
+    assert_eq!(over_transfer_count(&analyzer, ctx), 1);
+    // a failed send returns false instead of reverting
+    assert!(!ctx.is_killed(&analyzer));
+}
+
+#[test]
+fn test_transfer_decrements_balance() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "in_range");
+    assert_eq!(over_transfer_count(&analyzer, ctx), 0);
+    assert!(!ctx.is_killed(&analyzer));
+
+    let balance = ctx.latest_var_by_name(&analyzer, "this.balance").unwrap();
+    let seventy = Elem::from(Concrete::from(U256::from(70)));
+    assert!(balance
+        .evaled_range_min(&analyzer)
+        .unwrap()
+        .range_eq(&seventy));
+    assert!(balance
+        .evaled_range_max(&analyzer)
+        .unwrap()
+        .range_eq(&seventy));
+}
+
+#[test]
+fn test_unknown_balance_not_flagged() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "unknown_balance");
+    assert_eq!(over_transfer_count(&analyzer, ctx), 0);
+    assert!(!ctx.is_killed(&analyzer));
+}
+
+#[test]
+fn test_transfer_of_either_amount() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "ternary_amount");
+    // only the transfer of 200 exceeds the balance, and it reverts on its own path
+    assert_eq!(over_transfer_count(&analyzer, ctx), 1);
+    assert!(!ctx.is_killed(&analyzer));
+    assert_eq!(ctx.live_forks(&analyzer).len(), 1);
+}
+
+#[test]
+fn test_assumed_balance_over_transfer_flagged() {
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, SOL);
+    let func = func_named(&analyzer, entry, "unknown_balance");
+    let body = func.underlying(&analyzer).body.clone().unwrap();
+
+    let ctx = ContextNode::from(analyzer.add_node(Node::Context(Context::new(
+        func,
+        func.name(&analyzer),
+        Loc::Implicit,
+    ))));
+    ctx.assume_global(
+        &mut analyzer,
+        "this.balance",
+        SolcRange {
+            min: Elem::from(Concrete::from(U256::zero())),
+            max: Elem::from(Concrete::from(U256::from(100))),
+            exclusions: vec![],
+        },
+    )
+    .expect("Not an assumable global");
+    analyzer.parse_ctx_statement(&body, false, Some(ctx));
+    assert_eq!(over_transfer_count(&analyzer, ctx), 1);
+}
+
+#[test]
+fn test_payable_balance_includes_msg_value() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "deposit");
+    assert!(!ctx.is_killed(&analyzer));
+    let balance = ctx.latest_var_by_name(&analyzer, "this.balance").unwrap();
+    assert!(balance
+        .evaled_range_min(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(10)))));
+}
+
+#[test]
+fn test_strict_balance_equality_flagged() {
+    assert_eq!(strict_balance_count("strict_eq"), 1);
+}
+
+#[test]
+fn test_balance_lower_bound_not_flagged() {
+    assert_eq!(strict_balance_count("at_least"), 0);
+}
+
+#[test]
+fn test_balance_inequality_not_flagged() {
+    assert_eq!(strict_balance_count("nonzero"), 0);
+}
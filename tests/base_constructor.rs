@@ -0,0 +1,42 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/base_constructor.sol");
+
+fn constructor_ctx(analyzer: &mut Analyzer, contract_name: &str) -> ContextNode {
+    let entry = parse(analyzer, SOL);
+    let con = contract_named(analyzer, entry, contract_name);
+    con.constructor(analyzer)
+        .expect("No constructor")
+        .maybe_body_ctx(analyzer)
+        .expect("No body context")
+}
+
+#[test]
+fn test_base_constructor_require_kills() {
+    let mut analyzer = Analyzer::default();
+    let ctx = constructor_ctx(&mut analyzer, "TooSmall");
+    assert!(ctx.is_killed(&analyzer));
+    assert!(ctx.latest_var_by_name(&analyzer, "y").is_none());
+}
+
+#[test]
+fn test_base_constructor_args_flow() {
+    let mut analyzer = Analyzer::default();
+    let ctx = constructor_ctx(&mut analyzer, "LargeEnough");
+    assert!(!ctx.is_killed(&analyzer));
+    assert!(ctx.latest_var_by_name(&analyzer, "y").is_some());
+
+    let base_ctx = ctx.underlying(&analyzer).children[0];
+    let x = base_ctx
+        .latest_var_by_name(&analyzer, "x")
+        .expect("No variable with that name");
+    let twenty = Elem::from(Concrete::from(U256::from(20)));
+    assert!(x.evaled_range_min(&analyzer).unwrap().range_eq(&twenty));
+    assert!(x.evaled_range_max(&analyzer).unwrap().range_eq(&twenty));
+}
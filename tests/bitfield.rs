@@ -0,0 +1,39 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+
+const SOL: &str = include_str!("./test_data/bitfield.sol");
+
+fn assert_var_range(func_name: &str, var_name: &str, min: u64, max: u64) {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, func_name);
+    let var = ctx.latest_var_by_name(&analyzer, var_name).unwrap();
+    assert!(var
+        .evaled_range_min(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(min)))));
+    assert!(var
+        .evaled_range_max(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(max)))));
+}
+
+#[test]
+fn test_shift_and_mask_bounded_by_mask() {
+    assert_var_range("extract", "field", 0, 255);
+}
+
+#[test]
+fn test_mask_of_range_past_mask() {
+    // 0x100 & 0xff is 0, but 0xff is in range and is left unmasked
+    assert_var_range("mask_past_max", "low", 0, 255);
+}
+
+#[test]
+fn test_mask_of_constant_is_exact() {
+    assert_var_range("mask_const", "val", 0x34, 0x34);
+}
@@ -0,0 +1,110 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::context::analyzers::branches::BranchBudgetAnalyzer;
+use pyrometer::Analyzer;
+
+fn merged_count(sol: &str, func_name: &str, max_branch_contexts: usize) -> usize {
+    let mut analyzer = Analyzer {
+        max_branch_contexts,
+        ..Default::default()
+    };
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("branch_budget.sol");
+    analyzer.merged_branches(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_nested_ifs_hit_budget() {
+    let sol = include_str!("./test_data/branch_budget.sol");
+    assert!(merged_count(sol, "nested", 4) > 0);
+}
+
+#[test]
+fn test_nested_ifs_within_budget() {
+    let sol = include_str!("./test_data/branch_budget.sol");
+    assert_eq!(merged_count(sol, "nested", 1024), 0);
+}
+
+#[test]
+fn test_single_if_within_budget() {
+    let sol = include_str!("./test_data/branch_budget.sol");
+    assert_eq!(merged_count(sol, "single", 4), 0);
+}
+
+#[test]
+fn test_merged_branches_union_ranges() {
+    let sol = include_str!("./test_data/branch_budget.sol");
+    let mut analyzer = Analyzer {
+        max_branch_contexts: 0,
+        ..Default::default()
+    };
+    let ctx = body_ctx(&mut analyzer, sol, "nested");
+    assert!(ctx.underlying(&analyzer).forks.is_empty());
+
+    let y = var(&analyzer, ctx, "y");
+    assert_range(&analyzer, y, 0, 5);
+}
+
+#[test]
+fn test_loop_within_budget() {
+    let sol = include_str!("./test_data/branch_budget.sol");
+    assert_eq!(merged_count(sol, "looped", 1024), 0);
+}
+
+#[test]
+fn test_loop_skipped_over_budget() {
+    let sol = include_str!("./test_data/branch_budget.sol");
+    assert_eq!(merged_count(sol, "looped", 0), 1);
+
+    let mut analyzer = Analyzer {
+        max_branch_contexts: 0,
+        ..Default::default()
+    };
+    let ctx = body_ctx(&mut analyzer, sol, "looped");
+    assert!(ctx.underlying(&analyzer).children.is_empty());
+    let y = var(&analyzer, ctx, "y");
+    assert_range(&analyzer, y, U256::zero(), U256::MAX);
+}
+
+#[test]
+fn test_try_forks_within_budget() {
+    let sol = include_str!("./test_data/branch_budget.sol");
+    assert_eq!(merged_count(sol, "try_single", 1024), 0);
+
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, sol, "try_single");
+    let forks = ctx.underlying(&analyzer).forks.clone();
+    assert_eq!(forks.len(), 2);
+    for (fork, val) in forks.into_iter().zip([1u64, 2]) {
+        let y = var(&analyzer, fork, "y");
+        assert_range(&analyzer, y, val, val);
+    }
+}
+
+#[test]
+fn test_try_catch_clauses_joined() {
+    let sol = include_str!("./test_data/branch_budget.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, sol, "try_many");
+    let forks = ctx.underlying(&analyzer).forks.clone();
+    assert_eq!(forks.len(), 2);
+    let y = var(&analyzer, forks[1], "y");
+    assert_range(&analyzer, y, 2, 3);
+}
+
+#[test]
+fn test_try_merged_over_budget() {
+    let sol = include_str!("./test_data/branch_budget.sol");
+    assert_eq!(merged_count(sol, "try_many", 0), 1);
+
+    let mut analyzer = Analyzer {
+        max_branch_contexts: 0,
+        ..Default::default()
+    };
+    let ctx = body_ctx(&mut analyzer, sol, "try_many");
+    assert!(ctx.underlying(&analyzer).forks.is_empty());
+    let y = var(&analyzer, ctx, "y");
+    assert_range(&analyzer, y, 1, 3);
+}
@@ -0,0 +1,50 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+
+/// Analyzes the function with a branch budget of zero so that every `if` is joined, and checks
+/// the range of the variable after the join
+fn assert_joined_range(sol: &str, func_name: &str, var_name: &str, min: u64, max: u64) {
+    let mut analyzer = Analyzer {
+        max_branch_contexts: 0,
+        ..Default::default()
+    };
+    let ctx = live_body_ctx(&mut analyzer, sol, func_name);
+    assert!(ctx.underlying(&analyzer).forks.is_empty());
+
+    let var = ctx
+        .latest_var_by_name(&analyzer, var_name)
+        .expect("No variable with that name");
+    let min = Elem::from(Concrete::from(U256::from(min)));
+    let max = Elem::from(Concrete::from(U256::from(max)));
+    assert!(var.evaled_range_min(&analyzer).unwrap().range_eq(&min));
+    assert!(var.evaled_range_max(&analyzer).unwrap().range_eq(&max));
+}
+
+#[test]
+fn test_join_both_modified() {
+    let sol = include_str!("./test_data/branch_join.sol");
+    assert_joined_range(sol, "both_modified", "y", 1, 2);
+}
+
+#[test]
+fn test_join_one_modified() {
+    let sol = include_str!("./test_data/branch_join.sol");
+    assert_joined_range(sol, "one_modified", "y", 5, 7);
+}
+
+#[test]
+fn test_join_skips_killed_branch() {
+    let sol = include_str!("./test_data/branch_join.sol");
+    assert_joined_range(sol, "killed_branch", "y", 3, 3);
+}
+
+#[test]
+fn test_join_skips_impossible_branch() {
+    let sol = include_str!("./test_data/branch_join.sol");
+    assert_joined_range(sol, "impossible_branch", "y", 2, 2);
+}
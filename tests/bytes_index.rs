@@ -0,0 +1,32 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+
+const SOL: &str = include_str!("./test_data/bytes_index.sol");
+
+#[test]
+fn test_index_after_nonempty_require() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "first");
+    assert!(!ctx.is_killed(&analyzer));
+    let rets = ctx.return_nodes(&analyzer);
+    assert_eq!(rets.len(), 1);
+    assert_eq!(rets[0].1.ty(&analyzer).as_string(&analyzer), "bytes1");
+}
+
+#[test]
+fn test_symbolic_index_bounded_by_length() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "symbolic");
+    assert!(!ctx.is_killed(&analyzer));
+    let i = var(&analyzer, ctx, "i");
+    assert_range(&analyzer, i, 0, 31);
+}
+
+#[test]
+fn test_index_into_empty_bytes() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "empty");
+    assert!(ctx.is_killed(&analyzer));
+}
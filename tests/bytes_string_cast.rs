@@ -0,0 +1,45 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextVarNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/bytes_string_cast.sol");
+
+fn assert_len(analyzer: &Analyzer, var: ContextVarNode, len: u64) {
+    let len = Elem::from(Concrete::from(U256::from(len)));
+    [
+        var.evaled_range_min(analyzer).unwrap(),
+        var.evaled_range_max(analyzer).unwrap(),
+    ]
+    .into_iter()
+    .for_each(|elem| {
+        let rd = elem.maybe_range_dyn().expect("Not a dynamic range");
+        assert!(rd.len.range_eq(&len));
+    });
+}
+
+#[test]
+fn test_string_of_packed() {
+    let mut analyzer = Analyzer::default();
+    let s = body_var(&mut analyzer, SOL, "packed", "s");
+    assert_eq!(s.underlying(&analyzer).ty.as_string(&analyzer), "string");
+}
+
+#[test]
+fn test_string_of_packed_const_keeps_length() {
+    let mut analyzer = Analyzer::default();
+    let s = body_var(&mut analyzer, SOL, "packed_const", "s");
+    assert_eq!(s.underlying(&analyzer).ty.as_string(&analyzer), "string");
+    assert_len(&analyzer, s, 2);
+}
+
+#[test]
+fn test_bytes_of_string_literal_keeps_length() {
+    let mut analyzer = Analyzer::default();
+    let b = body_var(&mut analyzer, SOL, "to_bytes", "b");
+    assert_eq!(b.underlying(&analyzer).ty.as_string(&analyzer), "bytes");
+    assert_len(&analyzer, b, 3);
+}
@@ -0,0 +1,36 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::casts::CastAnalyzer;
+use pyrometer::Analyzer;
+
+fn unsafe_cast_count(sol: &str, func_name: &str) -> usize {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("casts.sol");
+    analyzer.unsafe_casts(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_safe_casts() {
+    let sol = include_str!("./test_data/casts.sol");
+    assert_eq!(unsafe_cast_count(sol, "safe_narrowing"), 0);
+    assert_eq!(unsafe_cast_count(sol, "safe_literal_bound"), 0);
+    assert_eq!(unsafe_cast_count(sol, "widening"), 0);
+}
+
+#[test]
+fn test_unsafe_casts() {
+    let sol = include_str!("./test_data/casts.sol");
+    assert_eq!(unsafe_cast_count(sol, "unsafe_narrowing"), 1);
+    assert_eq!(unsafe_cast_count(sol, "unsafe_loose_bound"), 1);
+}
+
+#[test]
+fn test_mixed_signedness_casts() {
+    let sol = include_str!("./test_data/casts.sol");
+    assert_eq!(unsafe_cast_count(sol, "signed_to_unsigned"), 1);
+    assert_eq!(unsafe_cast_count(sol, "nonneg_signed_to_unsigned"), 0);
+    assert_eq!(unsafe_cast_count(sol, "unsigned_to_signed"), 1);
+    assert_eq!(unsafe_cast_count(sol, "bounded_unsigned_to_signed"), 0);
+}
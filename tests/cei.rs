@@ -0,0 +1,80 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::cei::CeiAnalyzer;
+use pyrometer::Analyzer;
+use shared::analyzer::Search;
+use shared::{nodes::FunctionNode, Edge};
+
+fn violations(sol: &str, func_name: &str) -> Vec<usize> {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("cei.sol");
+    analyzer
+        .cei_violations(&file_mapping, ctx)
+        .iter()
+        .map(|analysis| analysis.storage_writes.len())
+        .collect()
+}
+
+#[test]
+fn test_write_after_external_call() {
+    let sol = include_str!("./test_data/cei.sol");
+    assert_eq!(violations(sol, "write_after_call"), vec![1]);
+}
+
+#[test]
+fn test_write_before_external_call() {
+    let sol = include_str!("./test_data/cei.sol");
+    assert!(violations(sol, "write_before_call").is_empty());
+}
+
+#[test]
+fn test_write_after_transfer() {
+    let sol = include_str!("./test_data/cei.sol");
+    assert_eq!(violations(sol, "write_after_transfer"), vec![1]);
+    assert!(violations(sol, "write_before_transfer").is_empty());
+}
+
+#[test]
+fn test_write_after_low_level_call() {
+    let sol = include_str!("./test_data/cei.sol");
+    assert_eq!(violations(sol, "write_after_low_level_call").len(), 1);
+    assert!(violations(sol, "write_before_low_level_call").is_empty());
+}
+
+#[test]
+fn test_call_and_write_on_separate_branches() {
+    let sol = include_str!("./test_data/cei.sol");
+    assert!(violations(sol, "separate_branches").is_empty());
+}
+
+#[test]
+fn test_reentrancy_guard_suppresses_violation() {
+    let sol = include_str!("./test_data/reentrancy_guard.sol");
+    assert!(violations(sol, "guarded").is_empty());
+    assert_eq!(violations(sol, "unguarded"), vec![1]);
+    assert!(violations(sol, "oz_guarded").is_empty());
+}
+
+#[test]
+fn test_recognizes_reentrancy_guard() {
+    let sol = include_str!("./test_data/reentrancy_guard.sol");
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, sol);
+    let funcs = analyzer
+        .search_children(entry, &Edge::Func)
+        .into_iter()
+        .map(FunctionNode::from)
+        .collect::<Vec<_>>();
+    let guarded = |name: &str| {
+        let func = funcs
+            .iter()
+            .find(|func| func.name(&analyzer).starts_with(&format!("{name}(")))
+            .expect("No function with that name");
+        analyzer.is_reentrancy_guarded(*func)
+    };
+    assert!(guarded("guarded"));
+    assert!(!guarded("unguarded"));
+    assert!(guarded("oz_guarded"));
+}
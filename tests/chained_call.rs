@@ -0,0 +1,34 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+
+const SOL: &str = include_str!("./test_data/chained_call.sol");
+
+#[test]
+fn test_two_level_chain() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "wrapped_balance");
+
+    // each call in the chain is made exactly once, in order
+    let called = ctx
+        .underlying(&analyzer)
+        .children
+        .iter()
+        .map(|child| {
+            let child = child.underlying(&analyzer);
+            child
+                .ext_fn_call
+                .or(child.fn_call)
+                .expect("Child is not a call")
+                .name(&analyzer)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(called, vec!["underlying()", "balanceOf(address)"]);
+
+    // the result takes the return type of the last call
+    let bal = ctx
+        .latest_var_by_name(&analyzer, "bal")
+        .expect("No variable with that name");
+    assert_eq!(bal.ty(&analyzer).as_string(&analyzer), "uint256");
+}
@@ -0,0 +1,45 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::chained_cmps::ChainedComparisonAnalyzer;
+use pyrometer::Analyzer;
+use shared::context::{ContextFinding, FindingKind};
+use solang_parser::pt::Loc;
+
+const SOL: &str = include_str!("./test_data/chained_cmp.sol");
+
+fn chained_comparison_count(func_name: &str) -> usize {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, func_name);
+    let file_mapping = file_mapping("chained_cmp.sol");
+    analyzer.chained_comparisons(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_chained_require_flagged() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "chained_require");
+    let cmp = "0 < x < 10";
+    let start = SOL.find(cmp).unwrap();
+    let loc = Loc::File(0, start, start + cmp.len());
+    assert_eq!(
+        ctx.underlying(&analyzer)
+            .findings_of(FindingKind::ChainedComparison),
+        [ContextFinding::ChainedComparison(loc)]
+    );
+}
+
+#[test]
+fn test_chained_expr_flagged() {
+    assert_eq!(chained_comparison_count("chained_expr"), 1);
+}
+
+#[test]
+fn test_combined_comparisons() {
+    assert_eq!(chained_comparison_count("combined"), 0);
+}
+
+#[test]
+fn test_bool_equality() {
+    assert_eq!(chained_comparison_count("bool_eq"), 0);
+}
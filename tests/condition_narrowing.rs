@@ -0,0 +1,65 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/condition_narrowing.sol");
+
+/// The context after the require in the function
+fn require_ctx(analyzer: &mut Analyzer, func_name: &str) -> ContextNode {
+    let ctx = body_ctx(analyzer, SOL, func_name);
+    assert!(ctx.underlying(analyzer).killed.is_none());
+    ctx
+}
+
+/// The true fork of the first if statement in the function
+fn true_fork(analyzer: &mut Analyzer, func_name: &str) -> ContextNode {
+    let ctx = body_ctx(analyzer, SOL, func_name);
+    let forks = ctx.forks(analyzer);
+    assert_eq!(forks.len(), 2);
+    forks[0]
+}
+
+/// Asserts that the variable has the same bounds after the require as in the true branch of the
+/// if, and that those bounds are the expected ones
+fn assert_same_narrowing(
+    require_func: &str,
+    if_func: &str,
+    var_name: &str,
+    min: Elem<Concrete>,
+    max: Elem<Concrete>,
+) {
+    let mut analyzer = Analyzer::default();
+    let ctx = require_ctx(&mut analyzer, require_func);
+    let (require_min, require_max) = var_bounds(&analyzer, ctx, var_name);
+
+    let mut analyzer = Analyzer::default();
+    let ctx = true_fork(&mut analyzer, if_func);
+    let (if_min, if_max) = var_bounds(&analyzer, ctx, var_name);
+
+    assert!(require_min.range_eq(&if_min));
+    assert!(require_max.range_eq(&if_max));
+    assert!(require_min.range_eq(&min));
+    assert!(require_max.range_eq(&max));
+}
+
+#[test]
+fn test_and_narrows_identically() {
+    assert_same_narrowing("and_require", "and_if", "a", uint(11), uint(99));
+    assert_same_narrowing("and_require", "and_if", "b", uint(0), uint(4));
+}
+
+#[test]
+fn test_not_or_narrows_identically() {
+    assert_same_narrowing(
+        "not_or_require",
+        "not_or_if",
+        "a",
+        uint(10),
+        Elem::from(Concrete::from(U256::MAX)),
+    );
+    assert_same_narrowing("not_or_require", "not_or_if", "b", uint(0), uint(20));
+}
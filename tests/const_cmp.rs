@@ -0,0 +1,45 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+use shared::range::elem::RangeElem;
+use shared::range::elem_ty::Elem;
+use shared::{context::ContextNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/const_cmp.sol");
+
+fn assert_const_bool(analyzer: &Analyzer, ctx: ContextNode, var_name: &str, val: bool) {
+    let var = ctx
+        .latest_var_by_name(analyzer, var_name)
+        .expect("No variable with that name");
+    let val = Elem::from(Concrete::from(val));
+    assert!(var.evaled_range_min(analyzer).unwrap().range_eq(&val));
+    assert!(var.evaled_range_max(analyzer).unwrap().range_eq(&val));
+}
+
+#[test]
+fn test_tautology_folded() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "tautology");
+    assert!(ctx.underlying(&analyzer).killed.is_none());
+    assert_const_bool(&analyzer, ctx, "b", true);
+}
+
+#[test]
+fn test_contradiction_folded() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "contradiction");
+    // requiring a comparison that folded to false can never pass
+    assert!(ctx.underlying(&analyzer).killed.is_some());
+}
+
+#[test]
+fn test_all_ops_folded() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "mixed");
+    assert!(ctx.underlying(&analyzer).killed.is_none());
+    assert_const_bool(&analyzer, ctx, "lte", true);
+    assert_const_bool(&analyzer, ctx, "gte", false);
+    assert_const_bool(&analyzer, ctx, "eq", true);
+    assert_const_bool(&analyzer, ctx, "neq", false);
+}
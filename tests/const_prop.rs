@@ -0,0 +1,35 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::elem::RangeElem;
+use shared::range::elem_ty::Elem;
+
+fn assert_single_point(sol: &str, func_name: &str, var_name: &str, val: u64) {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, sol, func_name);
+    let var = ctx
+        .latest_var_by_name(&analyzer, var_name)
+        .expect("No variable with that name");
+
+    let val = Elem::from(Concrete::from(U256::from(val)));
+    assert!(var.evaled_range_min(&analyzer).unwrap().range_eq(&val));
+    assert!(var.evaled_range_max(&analyzer).unwrap().range_eq(&val));
+}
+
+#[test]
+fn test_const_assignment_chain() {
+    let sol = include_str!("./test_data/const_prop.sol");
+    assert_single_point(sol, "chain", "x", 5);
+    assert_single_point(sol, "chain", "y", 6);
+    assert_single_point(sol, "chain", "z", 6);
+    assert_single_point(sol, "chain", "w", 12);
+}
+
+#[test]
+fn test_const_reassignment() {
+    let sol = include_str!("./test_data/const_prop.sol");
+    assert_single_point(sol, "reassign", "x", 8);
+}
@@ -0,0 +1,39 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::constants::ConstantResultAnalyzer;
+use pyrometer::Analyzer;
+
+const SOL: &str = include_str!("./test_data/constant_result.sol");
+
+/// The constants of the collapsed operations in the function
+fn constant_results(func_name: &str) -> Vec<String> {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, func_name);
+    let file_mapping = file_mapping("constant_result.sol");
+    analyzer
+        .constant_results(&file_mapping, ctx)
+        .into_iter()
+        .map(|analysis| analysis.constant)
+        .collect()
+}
+
+#[test]
+fn test_times_zero_flagged() {
+    assert_eq!(constant_results("times_zero"), vec!["0".to_string()]);
+}
+
+#[test]
+fn test_mask_zero_flagged() {
+    assert_eq!(constant_results("mask_zero"), vec!["0".to_string()]);
+}
+
+#[test]
+fn test_intentional_constant_not_flagged() {
+    assert!(constant_results("literal").is_empty());
+}
+
+#[test]
+fn test_varying_result_not_flagged() {
+    assert!(constant_results("varying").is_empty());
+}
@@ -0,0 +1,57 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{
+    context::{ContextNode, ContextVarNode},
+    nodes::{Concrete, ContractNode},
+};
+
+const SOL: &str = include_str!("./test_data/constructor_args.sol");
+
+fn capped(analyzer: &mut Analyzer) -> ContractNode {
+    let entry = parse(analyzer, SOL);
+    contract_named(analyzer, entry, "Capped")
+}
+
+fn capped_body_ctx(analyzer: &mut Analyzer, func_name: &str) -> ContextNode {
+    let con = capped(analyzer);
+    let func = func_named(analyzer, con.into(), func_name);
+    func.maybe_body_ctx(analyzer).expect("No body context")
+}
+
+fn assert_bounds(analyzer: &Analyzer, var: ContextVarNode, min: u64, max: u64) {
+    let min = Elem::from(Concrete::from(U256::from(min)));
+    let max = Elem::from(Concrete::from(U256::from(max)));
+    assert!(var.evaled_range_min(analyzer).unwrap().range_eq(&min));
+    assert!(var.evaled_range_max(analyzer).unwrap().range_eq(&max));
+}
+
+#[test]
+fn test_constructor_require_narrows_state() {
+    let mut analyzer = Analyzer::default();
+    let con = capped(&mut analyzer);
+    let ctx = con
+        .constructor(&analyzer)
+        .expect("No constructor")
+        .maybe_body_ctx(&analyzer)
+        .expect("No body context");
+    assert!(!ctx.is_killed(&analyzer));
+    let cap = ctx
+        .latest_var_by_name(&analyzer, "cap")
+        .expect("No variable with that name");
+    assert_bounds(&analyzer, cap, 0, 100);
+}
+
+#[test]
+fn test_immutable_seeded_from_constructor() {
+    let mut analyzer = Analyzer::default();
+    let ctx = capped_body_ctx(&mut analyzer, "readCap");
+    assert!(!ctx.is_killed(&analyzer));
+    let c = ctx
+        .latest_var_by_name(&analyzer, "c")
+        .expect("No variable with that name");
+    assert_bounds(&analyzer, c, 0, 100);
+}
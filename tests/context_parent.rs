@@ -0,0 +1,27 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+
+const SOL: &str = include_str!("./test_data/context_parent.sol");
+
+#[test]
+fn test_parent_of_nested_subctx() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "outer");
+    assert_eq!(ctx.parent(&analyzer), None);
+    assert!(!ctx.is_call(&analyzer));
+
+    let call = ctx.underlying(&analyzer).children[0];
+    assert_eq!(call.parent(&analyzer), Some(ctx));
+    assert!(call.is_call(&analyzer));
+
+    // the branches of the `if` in the called function are forks of the call
+    let forks = call.forks(&analyzer);
+    assert_eq!(forks.len(), 2);
+    forks.iter().for_each(|fork| {
+        assert_eq!(fork.parent(&analyzer), Some(call));
+        assert!(!fork.is_call(&analyzer));
+        assert_eq!(fork.root(&analyzer), ctx);
+    });
+}
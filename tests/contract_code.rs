@@ -0,0 +1,36 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::nodes::{Builtin, Concrete};
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+
+const SOL: &str = include_str!("./test_data/contract_code.sol");
+
+fn assert_unknown_bytes(func_name: &str, code_name: &str) {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, func_name);
+    let code = ctx
+        .latest_var_by_name(&analyzer, "code")
+        .expect("No variable with that name");
+    assert_eq!(code.builtin(&analyzer), Some(Builtin::DynamicBytes));
+
+    let code = ctx
+        .latest_var_by_name(&analyzer, code_name)
+        .expect("No variable with that name");
+    assert_eq!(code.builtin(&analyzer), Some(Builtin::DynamicBytes));
+    let max = code.evaled_range_max(&analyzer).unwrap();
+    let len = max.maybe_range_dyn().expect("Not a dynamic range").len;
+    assert!(len.range_eq(&Elem::from(Concrete::from(U256::MAX))));
+}
+
+#[test]
+fn test_creation_code() {
+    assert_unknown_bytes("creation", "type(Child).creationCode");
+}
+
+#[test]
+fn test_runtime_code() {
+    assert_unknown_bytes("runtime", "type(Child).runtimeCode");
+}
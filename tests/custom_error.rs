@@ -0,0 +1,79 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::utils::id;
+use pyrometer::Analyzer;
+use shared::context::ContextNode;
+
+fn has_var(analyzer: &Analyzer, ctx: ContextNode, display_name: &str) -> bool {
+    ctx.vars(analyzer)
+        .iter()
+        .any(|var| var.display_name(analyzer) == display_name)
+}
+
+#[test]
+fn test_custom_error_revert_terminates_path() {
+    let sol = include_str!("./test_data/custom_error.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, "withdraw");
+
+    let forks = ctx.forks(&analyzer);
+    assert_eq!(forks.len(), 2);
+    let (reverting, continuing) = (forks[0], forks[1]);
+    assert!(reverting.is_killed(&analyzer));
+    assert!(!continuing.is_killed(&analyzer));
+    assert!(continuing.underlying(&analyzer).reverted_with.is_none());
+
+    let err = reverting
+        .underlying(&analyzer)
+        .reverted_with
+        .expect("No custom error recorded");
+    assert_eq!(err.name(&analyzer), "InsufficientBalance");
+    assert_eq!(
+        err.signature(&analyzer),
+        "InsufficientBalance(uint256,uint256)"
+    );
+    assert_eq!(
+        err.selector(&analyzer),
+        id("InsufficientBalance(uint256,uint256)")
+    );
+
+    // the arguments are analyzed before the path ends
+    assert!(has_var(&analyzer, reverting, "(want - have)"));
+}
+
+#[test]
+fn test_custom_error_named_args_revert() {
+    let sol = include_str!("./test_data/custom_error.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, "named");
+
+    assert!(ctx.is_killed(&analyzer));
+    let err = ctx
+        .underlying(&analyzer)
+        .reverted_with
+        .expect("No custom error recorded");
+    assert_eq!(err.name(&analyzer), "InsufficientBalance");
+    assert!(has_var(&analyzer, ctx, "(want + 1)"));
+}
+
+#[test]
+fn test_custom_error_redeclared_in_another_contract() {
+    let sol = include_str!("./test_data/custom_error_redeclared.sol");
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, sol);
+    let reverted_with = |func_name: &str| {
+        func_named(&analyzer, entry, func_name)
+            .maybe_body_ctx(&analyzer)
+            .expect("No body context")
+            .underlying(&analyzer)
+            .reverted_with
+            .expect("No custom error recorded")
+    };
+
+    let first_err = reverted_with("fail");
+    let second_err = reverted_with("failWith");
+    assert_ne!(first_err, second_err);
+    assert_eq!(first_err.signature(&analyzer), "Failed(uint256)");
+    assert_eq!(second_err.signature(&analyzer), "Failed(address,bool)");
+}
@@ -0,0 +1,21 @@
+use pyrometer::Analyzer;
+use shared::analyzer::GraphLike;
+
+fn dump(sol: &str) -> String {
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(sol);
+    analyzer.debug_dump()
+}
+
+#[test]
+fn test_debug_dump_golden() {
+    let sol = include_str!("./test_data/debug_dump.sol");
+    let expected = include_str!("./test_data/debug_dump.txt");
+    assert_eq!(dump(sol), expected.trim_end());
+}
+
+#[test]
+fn test_debug_dump_deterministic() {
+    let sol = include_str!("./test_data/math.sol");
+    assert_eq!(dump(sol), dump(sol));
+}
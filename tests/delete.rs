@@ -0,0 +1,41 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::{I256, U256};
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextVarNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/delete.sol");
+
+fn assert_const(analyzer: &Analyzer, var: ContextVarNode, val: Concrete) {
+    let val = Elem::from(val);
+    assert!(var.evaled_range_min(analyzer).unwrap().range_eq(&val));
+    assert!(var.evaled_range_max(analyzer).unwrap().range_eq(&val));
+}
+
+#[test]
+fn test_delete_element_keeps_length() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "delete_elem");
+    let elem = ctx.latest_var_by_name(&analyzer, "arr[i]").unwrap();
+    assert_const(&analyzer, elem, Concrete::from(U256::zero()));
+    let len = ctx.latest_var_by_name(&analyzer, "arr.length").unwrap();
+    assert_const(&analyzer, len, Concrete::from(U256::from(3)));
+}
+
+#[test]
+fn test_delete_array_zeroes_length() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "delete_all");
+    let len = ctx.latest_var_by_name(&analyzer, "stored.length").unwrap();
+    assert_const(&analyzer, len, Concrete::from(U256::zero()));
+}
+
+#[test]
+fn test_delete_value() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "delete_value");
+    let x = ctx.latest_var_by_name(&analyzer, "x").unwrap();
+    assert_const(&analyzer, x, Concrete::from(I256::zero()));
+}
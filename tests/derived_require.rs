@@ -0,0 +1,49 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextVarNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/derived_require.sol");
+
+fn latest_a(analyzer: &mut Analyzer, func_name: &str) -> ContextVarNode {
+    let ctx = live_body_ctx(analyzer, SOL, func_name);
+    ctx.latest_var_by_name(analyzer, "a")
+        .expect("No variable with that name")
+}
+
+#[test]
+fn test_add_const_lhs() {
+    let mut analyzer = Analyzer::default();
+    let a = latest_a(&mut analyzer, "add_lhs");
+    let min = Elem::from(Concrete::from(U256::from(10)));
+    assert!(a.evaled_range_min(&analyzer).unwrap().range_eq(&min));
+}
+
+#[test]
+fn test_add_const_rhs() {
+    let mut analyzer = Analyzer::default();
+    let a = latest_a(&mut analyzer, "add_rhs");
+    let min = Elem::from(Concrete::from(U256::from(10)));
+    assert!(a.evaled_range_min(&analyzer).unwrap().range_eq(&min));
+}
+
+#[test]
+fn test_sub_const() {
+    let mut analyzer = Analyzer::default();
+    let a = latest_a(&mut analyzer, "sub_const");
+    let min = Elem::from(Concrete::from(U256::from(5)));
+    let max = Elem::from(Concrete::from(U256::from(25)));
+    assert!(a.evaled_range_min(&analyzer).unwrap().range_eq(&min));
+    assert!(a.evaled_range_max(&analyzer).unwrap().range_eq(&max));
+}
+
+#[test]
+fn test_const_sub() {
+    let mut analyzer = Analyzer::default();
+    let a = latest_a(&mut analyzer, "const_sub");
+    let max = Elem::from(Concrete::from(U256::from(60)));
+    assert!(a.evaled_range_max(&analyzer).unwrap().range_eq(&max));
+}
@@ -0,0 +1,67 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::{Address, H256, U256};
+use pyrometer::Analyzer;
+use shared::{
+    context::ContextVarNode,
+    nodes::{Builtin, Concrete},
+};
+
+const SOL: &str = include_str!("./test_data/env.sol");
+
+/// The single value the function returns
+fn ret(analyzer: &mut Analyzer, func_name: &str) -> ContextVarNode {
+    let ctx = live_body_ctx(analyzer, SOL, func_name);
+    let rets = ctx.return_nodes(analyzer);
+    assert_eq!(rets.len(), 1);
+    rets[0].1
+}
+
+fn assert_full_uint(func_name: &str) {
+    let mut analyzer = Analyzer::default();
+    let var = ret(&mut analyzer, func_name);
+    assert_eq!(var.builtin(&analyzer), Some(Builtin::Uint(256)));
+    assert_range(&analyzer, var, U256::zero(), U256::MAX);
+}
+
+#[test]
+fn test_block_chainid() {
+    assert_full_uint("block_chainid");
+}
+
+#[test]
+fn test_block_basefee() {
+    assert_full_uint("block_basefee");
+}
+
+#[test]
+fn test_gasleft() {
+    assert_full_uint("gas_left");
+}
+
+#[test]
+fn test_block_coinbase() {
+    let mut analyzer = Analyzer::default();
+    let var = ret(&mut analyzer, "block_coinbase");
+    assert_eq!(var.builtin(&analyzer), Some(Builtin::Address));
+    assert_concrete_range(
+        &analyzer,
+        var,
+        Concrete::from(Address::from_slice(&[0x00; 20])),
+        Concrete::from(Address::from_slice(&[0xff; 20])),
+    );
+}
+
+#[test]
+fn test_blockhash() {
+    let mut analyzer = Analyzer::default();
+    let var = ret(&mut analyzer, "block_hash");
+    assert_eq!(var.builtin(&analyzer), Some(Builtin::Bytes(32)));
+    assert_concrete_range(
+        &analyzer,
+        var,
+        Concrete::Bytes(32, H256::from_slice(&[0x00; 32])),
+        Concrete::Bytes(32, H256::from_slice(&[0xff; 32])),
+    );
+}
@@ -0,0 +1,144 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::context::analyzers::requires::FailableRequireAnalyzer;
+use pyrometer::context::ContextBuilder;
+use pyrometer::Analyzer;
+use shared::analyzer::GraphLike;
+use shared::context::{Context, ContextNode};
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem, SolcRange};
+use shared::Node;
+use solang_parser::pt::Loc;
+
+fn point_range(val: u64) -> SolcRange {
+    SolcRange {
+        min: Elem::from(Concrete::from(U256::from(val))),
+        max: Elem::from(Concrete::from(U256::from(val))),
+        exclusions: vec![],
+    }
+}
+
+/// Reanalyzes a function's body in a fresh context seeded with the assumed globals
+fn ctx_with_assumptions(
+    sol: &str,
+    func_name: &str,
+    assumptions: Vec<(&str, SolcRange)>,
+) -> (Analyzer, ContextNode) {
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, sol);
+    let func = func_named(&analyzer, entry, func_name);
+    let body = func
+        .underlying(&analyzer)
+        .body
+        .clone()
+        .expect("No function body");
+
+    let ctx = ContextNode::from(analyzer.add_node(Node::Context(Context::new(
+        func,
+        func.name(&analyzer),
+        Loc::Implicit,
+    ))));
+    assumptions.into_iter().for_each(|(name, range)| {
+        ctx.assume_global(&mut analyzer, name, range)
+            .expect("Not an assumable global");
+    });
+
+    analyzer.parse_ctx_statement(&body, false, Some(ctx));
+    (analyzer, ctx)
+}
+
+/// Whether the context was killed after reanalyzing the function with the assumed globals
+fn killed_with_assumptions(
+    sol: &str,
+    func_name: &str,
+    assumptions: Vec<(&str, SolcRange)>,
+) -> bool {
+    let (analyzer, ctx) = ctx_with_assumptions(sol, func_name, assumptions);
+    ctx.underlying(&analyzer).killed.is_some()
+}
+
+fn assert_point(analyzer: &Analyzer, ctx: ContextNode, name: &str, val: u64) {
+    let var = ctx
+        .latest_var_by_name(analyzer, name)
+        .expect("No variable with that name");
+    let val = Elem::from(Concrete::from(U256::from(val)));
+    assert!(var.evaled_range_min(analyzer).unwrap().range_eq(&val));
+    assert!(var.evaled_range_max(analyzer).unwrap().range_eq(&val));
+}
+
+#[test]
+fn test_assumed_msg_value_tautology() {
+    let sol = include_str!("./test_data/env_assumptions.sol");
+    let file_mapping = file_mapping("env_assumptions.sol");
+    let (analyzer, ctx) =
+        ctx_with_assumptions(sol, "no_value", vec![("msg.value", point_range(0))]);
+    assert!(!ctx.is_killed(&analyzer));
+    assert_point(&analyzer, ctx, "msg.value", 0);
+    // the require always holds once the assumption narrows `msg.value`, and only then
+    assert_eq!(analyzer.redundant_requires(&file_mapping, ctx).len(), 1);
+
+    let (analyzer, ctx) = ctx_with_assumptions(sol, "no_value", vec![]);
+    assert!(!ctx.is_killed(&analyzer));
+    assert!(analyzer.redundant_requires(&file_mapping, ctx).is_empty());
+}
+
+#[test]
+fn test_assumed_msg_value_contradiction() {
+    let sol = include_str!("./test_data/env_assumptions.sol");
+    assert!(killed_with_assumptions(
+        sol,
+        "some_value",
+        vec![("msg.value", point_range(0))]
+    ));
+    assert!(!killed_with_assumptions(sol, "some_value", vec![]));
+}
+
+#[test]
+fn test_assumed_block_timestamp() {
+    let sol = include_str!("./test_data/env_assumptions.sol");
+    assert!(killed_with_assumptions(
+        sol,
+        "after_time",
+        vec![("block.timestamp", point_range(50))]
+    ));
+    assert!(!killed_with_assumptions(
+        sol,
+        "after_time",
+        vec![("block.timestamp", point_range(150))]
+    ));
+}
+
+#[test]
+fn test_assumed_global_range() {
+    let sol = include_str!("./test_data/env_assumptions.sol");
+    let (analyzer, ctx) =
+        ctx_with_assumptions(sol, "no_value", vec![("msg.value", point_range(0))]);
+    assert!(!ctx.is_killed(&analyzer));
+    assert_point(&analyzer, ctx, "msg.value", 0);
+
+    let (analyzer, ctx) = ctx_with_assumptions(
+        sol,
+        "after_time",
+        vec![("block.timestamp", point_range(150))],
+    );
+    assert!(!ctx.is_killed(&analyzer));
+    assert_point(&analyzer, ctx, "block.timestamp", 150);
+}
+
+#[test]
+fn test_assume_unknown_global() {
+    let sol = include_str!("./test_data/env_assumptions.sol");
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, sol);
+    let func = func_named(&analyzer, entry, "no_value");
+    let ctx = ContextNode::from(analyzer.add_node(Node::Context(Context::new(
+        func,
+        func.name(&analyzer),
+        Loc::Implicit,
+    ))));
+    assert!(ctx
+        .assume_global(&mut analyzer, "block.unknown", point_range(0))
+        .is_none());
+}
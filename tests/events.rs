@@ -0,0 +1,55 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::events::EventArgAnalyzer;
+use pyrometer::Analyzer;
+use shared::nodes::EventNode;
+
+const SOL: &str = include_str!("./test_data/events.sol");
+
+fn mismatch_count(func_name: &str) -> usize {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, func_name);
+    let file_mapping = file_mapping("events.sol");
+    assert!(!ctx.is_killed(&analyzer));
+    analyzer.event_arg_mismatches(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_event_params() {
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(SOL);
+    let event = EventNode::from(*analyzer.user_types.get("Small").expect("No event"));
+    assert_eq!(event.name(&analyzer), "Small");
+    let indexed = event
+        .params(&analyzer)
+        .iter()
+        .map(|param| param.underlying(&analyzer).indexed)
+        .collect::<Vec<_>>();
+    assert_eq!(indexed, vec![true, false]);
+}
+
+#[test]
+fn test_event_arg_overflows_param() {
+    // `a + 1` can be up to 301, which doesn't fit in `uint8`
+    assert_eq!(mismatch_count("overflows"), 1);
+}
+
+#[test]
+fn test_event_arg_fits_param() {
+    assert_eq!(mismatch_count("fits"), 0);
+    assert_eq!(mismatch_count("literal"), 0);
+}
+
+#[test]
+fn test_event_resolved_through_inheritance() {
+    // `Other.Moved` is declared first but `Child` emits the `Moved` it inherits from `Base`
+    assert_eq!(mismatch_count("inherited"), 0);
+}
+
+#[test]
+fn test_overloaded_event_resolved_by_params() {
+    assert_eq!(mismatch_count("by_arity"), 0);
+    assert_eq!(mismatch_count("by_types"), 0);
+    assert_eq!(mismatch_count("overload_overflows"), 2);
+}
@@ -0,0 +1,42 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+use std::collections::BTreeMap;
+
+fn exit_ranges(func_name: &str) -> BTreeMap<String, String> {
+    let sol = include_str!("./test_data/exit_ranges.sol");
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, sol);
+    let func = func_named(&analyzer, entry, func_name);
+    analyzer.exit_ranges(func)
+}
+
+fn snapshot(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+    entries
+        .iter()
+        .map(|(name, range)| (name.to_string(), range.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_exit_ranges_merge_branches() {
+    assert_eq!(
+        exit_ranges("branching"),
+        snapshot(&[
+            (
+                "x",
+                "uint256[0, 115792089237316195423570985008687907853269984665640564039457584007913129639935]"
+            ),
+            ("y", "uint256[5, 20]"),
+        ])
+    );
+}
+
+#[test]
+fn test_exit_ranges_single_exit() {
+    assert_eq!(
+        exit_ranges("straight"),
+        snapshot(&[("a", "uint8[0, 99]"), ("b", "uint8[1, 100]")])
+    );
+}
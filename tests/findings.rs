@@ -0,0 +1,210 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::{
+    findings::{
+        filter_findings, filter_reports, suppress_rules, Finding, FindingsAnalyzer, Severity,
+    },
+    loops::InfiniteLoopAnalyzer,
+    registry::{AnalyzerRegistry, FnLint},
+};
+use pyrometer::Analyzer;
+use std::collections::BTreeMap;
+
+fn analyzer_for(sol: &str, file_name: &str) -> (Analyzer, BTreeMap<usize, String>) {
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(sol);
+    (analyzer, file_mapping(file_name))
+}
+
+#[test]
+fn test_run_all_analyzers() {
+    let sol = include_str!("./test_data/infinite_loop.sol");
+    let (analyzer, file_mapping) = analyzer_for(sol, "infinite_loop.sol");
+    assert_eq!(analyzer.run_all_analyzers(&file_mapping).len(), 5);
+}
+
+#[test]
+fn test_no_findings() {
+    let sol = include_str!("./test_data/env.sol");
+    let (analyzer, file_mapping) = analyzer_for(sol, "env.sol");
+    assert!(analyzer.run_all_analyzers(&file_mapping).is_empty());
+}
+
+/// An infinite loop lint registered under a different name
+fn duplicate_loop_lint() -> FnLint<Analyzer> {
+    FnLint {
+        name: "duplicate-loop",
+        run: |analyzer, file_mapping, ctx| {
+            analyzer
+                .infinite_loops(file_mapping, ctx)
+                .iter()
+                .map(|analysis| Finding::new(analysis, analysis.loop_span.clone(), analyzer))
+                .collect()
+        },
+    }
+}
+
+#[test]
+fn test_findings_deduplicated() {
+    let sol = include_str!("./test_data/infinite_loop.sol");
+    let (analyzer, file_mapping) = analyzer_for(sol, "infinite_loop.sol");
+    let mut registry = AnalyzerRegistry::default();
+    registry.register(duplicate_loop_lint());
+    assert_eq!(analyzer.run_analyzers(&file_mapping, &registry).len(), 5);
+}
+
+#[test]
+fn test_registry_disable() {
+    let sol = include_str!("./test_data/infinite_loop.sol");
+    let (analyzer, file_mapping) = analyzer_for(sol, "infinite_loop.sol");
+    let mut registry = AnalyzerRegistry::default();
+    assert!(registry.disable("infinite-loop"));
+    assert!(!registry.is_enabled("infinite-loop"));
+    assert!(analyzer.run_analyzers(&file_mapping, &registry).is_empty());
+    assert!(registry.enable("infinite-loop"));
+    assert_eq!(analyzer.run_analyzers(&file_mapping, &registry).len(), 5);
+    assert!(!registry.disable("not-a-lint"));
+}
+
+#[test]
+fn test_bounds_lint_registered_disabled() {
+    let sol = include_str!("./test_data/truncating_cast.sol");
+    let (analyzer, file_mapping) = analyzer_for(sol, "truncating_cast.sol");
+    let mut registry = AnalyzerRegistry::default();
+    assert!(registry.names().contains(&"bounds"));
+    assert!(!registry.is_enabled("bounds"));
+
+    assert!(registry.enable("bounds"));
+    let findings = analyzer.findings(&file_mapping, &registry);
+    let bounds: Vec<&Finding> = findings
+        .iter()
+        .filter(|finding| finding.rule_id == "bounds")
+        .collect();
+    assert_eq!(bounds.len(), 1);
+    assert_eq!(bounds[0].severity, Severity::Advice);
+}
+
+#[test]
+fn test_findings_rule_ids() {
+    let sol = include_str!("./test_data/infinite_loop.sol");
+    let (analyzer, file_mapping) = analyzer_for(sol, "infinite_loop.sol");
+    let findings = analyzer.findings(&file_mapping, &AnalyzerRegistry::default());
+    assert_eq!(findings.len(), 5);
+    assert!(
+        findings
+            .iter()
+            .all(|finding| finding.rule_id == "infinite-loop"
+                && finding.severity == Severity::Warning)
+    );
+}
+
+#[test]
+fn test_findings_json() {
+    let sol = include_str!("./test_data/truncating_cast.sol");
+    let (analyzer, file_mapping) = analyzer_for(sol, "truncating_cast.sol");
+    let sources: BTreeMap<_, _> = vec![("truncating_cast.sol".to_string(), sol.to_string())]
+        .into_iter()
+        .collect();
+    let findings = analyzer.findings(&file_mapping, &AnalyzerRegistry::default());
+    let expected = r#"{
+  "schema_version": 1,
+  "findings": [
+    {
+      "rule_id": "unsafe-cast",
+      "severity": "warning",
+      "message": "Potentially truncating cast in function truncate",
+      "file": "truncating_cast.sol",
+      "start_line": 4,
+      "start_col": 16,
+      "end_line": 4,
+      "end_col": 24
+    }
+  ]
+}"#;
+    assert_eq!(analyzer.reports_to_json(&findings, &sources), expected);
+}
+
+#[test]
+fn test_overflow_findings_json() {
+    let sol = include_str!("./test_data/loop_counter_overflow.sol");
+    let (analyzer, file_mapping) = analyzer_for(sol, "loop_counter_overflow.sol");
+    let sources: BTreeMap<_, _> = vec![("loop_counter_overflow.sol".to_string(), sol.to_string())]
+        .into_iter()
+        .collect();
+    let findings: Vec<Finding> = analyzer
+        .findings(&file_mapping, &AnalyzerRegistry::default())
+        .into_iter()
+        .filter(|finding| finding.rule_id == "loop-counter-overflow")
+        .collect();
+    let expected = r#"{
+  "schema_version": 1,
+  "findings": [
+    {
+      "rule_id": "loop-counter-overflow",
+      "severity": "error",
+      "message": "Loop counter overflows in function strict",
+      "file": "loop_counter_overflow.sol",
+      "start_line": 4,
+      "start_col": 32,
+      "end_line": 4,
+      "end_col": 35
+    },
+    {
+      "rule_id": "loop-counter-overflow",
+      "severity": "error",
+      "message": "Loop counter overflows in function inclusive",
+      "file": "loop_counter_overflow.sol",
+      "start_line": 11,
+      "start_col": 33,
+      "end_line": 11,
+      "end_col": 36
+    }
+  ]
+}"#;
+    assert_eq!(analyzer.reports_to_json(&findings, &sources), expected);
+}
+
+#[test]
+fn test_no_findings_json() {
+    let sol = include_str!("./test_data/env.sol");
+    let (analyzer, file_mapping) = analyzer_for(sol, "env.sol");
+    let findings = analyzer.findings(&file_mapping, &AnalyzerRegistry::default());
+    assert_eq!(
+        analyzer.reports_to_json(&findings, &BTreeMap::new()),
+        "{\n  \"schema_version\": 1,\n  \"findings\": []\n}"
+    );
+}
+
+#[test]
+fn test_filter_by_severity() {
+    let sol = include_str!("./test_data/truncating_cast.sol");
+    let (analyzer, file_mapping) = analyzer_for(sol, "truncating_cast.sol");
+    let findings = analyzer.findings(&file_mapping, &AnalyzerRegistry::default());
+    assert_eq!(findings.len(), 1);
+    assert!(filter_findings(findings, Severity::Error).is_empty());
+
+    let findings = analyzer.findings(&file_mapping, &AnalyzerRegistry::default());
+    assert_eq!(filter_reports(findings, Severity::Warning).len(), 1);
+
+    let findings = analyzer.findings(&file_mapping, &AnalyzerRegistry::default());
+    assert_eq!(filter_findings(findings, Severity::Advice).len(), 1);
+}
+
+#[test]
+fn test_severity_order() {
+    assert!(Severity::Error > Severity::Warning);
+    assert!(Severity::Warning > Severity::Advice);
+    assert_eq!(Severity::from_name("warning"), Some(Severity::Warning));
+    assert_eq!(Severity::from_name("critical"), None);
+}
+
+#[test]
+fn test_suppress_rules() {
+    let sol = include_str!("./test_data/truncating_cast.sol");
+    let (analyzer, file_mapping) = analyzer_for(sol, "truncating_cast.sol");
+    let findings = analyzer.findings(&file_mapping, &AnalyzerRegistry::default());
+    let kept = suppress_rules(findings, &["infinite-loop".to_string()]);
+    assert_eq!(kept.len(), 1);
+    assert!(suppress_rules(kept, &["unsafe-cast".to_string()]).is_empty());
+}
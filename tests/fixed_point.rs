@@ -0,0 +1,44 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::context::analyzers::fixed_point::FixedPointAnalyzer;
+use pyrometer::Analyzer;
+use shared::nodes::{Builtin, Concrete};
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+
+const SOL: &str = include_str!("./test_data/fixed_point.sol");
+
+#[test]
+fn test_fixed_point_declarations() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "declare");
+    let x = ctx.latest_var_by_name(&analyzer, "x").unwrap();
+    assert!(x.is_fixed_point(&analyzer));
+    assert!(x
+        .evaled_range_min(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::zero()))));
+    assert!(x
+        .evaled_range_max(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::Uint(8, U256::from(255)))));
+
+    let file_mapping = file_mapping("fixed_point.sol");
+    let notes = analyzer.fixed_point_vars(&file_mapping, ctx);
+    assert_eq!(notes.len(), 2);
+}
+
+#[test]
+fn test_fixed_names() {
+    assert_eq!(
+        Builtin::try_from_fixed_name("ufixed"),
+        Some(Builtin::Ufixed(128, 18))
+    );
+    assert_eq!(
+        Builtin::try_from_fixed_name("fixed64x10"),
+        Some(Builtin::Fixed(64, 10))
+    );
+    assert_eq!(Builtin::try_from_fixed_name("fixed7x1"), None);
+    assert_eq!(Builtin::try_from_fixed_name("fixedFee"), None);
+}
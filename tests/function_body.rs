@@ -0,0 +1,42 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::context::ContextBuilder;
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+
+#[test]
+fn test_reanalyze_function_body() {
+    let sol = include_str!("./test_data/function_body.sol");
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, sol);
+
+    let func = func_named(&analyzer, entry, "add");
+    let prev_ctx = func.body_ctx(&analyzer);
+    let ctx = analyzer
+        .analyze_function_body(func)
+        .expect("No body context");
+    assert_ne!(prev_ctx, ctx);
+    assert_eq!(func.body_ctx(&analyzer), ctx);
+    assert!(ctx.underlying(&analyzer).killed.is_none());
+
+    let y = ctx
+        .latest_var_by_name(&analyzer, "y")
+        .expect("No variable with that name");
+    let min = Elem::from(Concrete::from(U256::from(1)));
+    let max = Elem::from(Concrete::from(U256::from(10)));
+    assert!(y.evaled_range_min(&analyzer).unwrap().range_eq(&min));
+    assert!(y.evaled_range_max(&analyzer).unwrap().range_eq(&max));
+}
+
+#[test]
+fn test_function_without_body() {
+    let sol = include_str!("./test_data/function_body.sol");
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, sol);
+
+    let func = func_named(&analyzer, entry, "thing");
+    assert!(analyzer.analyze_function_body(func).is_none());
+}
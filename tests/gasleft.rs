@@ -0,0 +1,44 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextVarNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/gasleft.sol");
+
+/// The `gasleft()` result a variable was assigned from
+fn gasleft_source(analyzer: &Analyzer, var: ContextVarNode) -> ContextVarNode {
+    let deps = var.range_max(analyzer).unwrap().dependent_on();
+    assert_eq!(deps.len(), 1);
+    deps[0]
+}
+
+#[test]
+fn test_gasleft_decreases() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "two_reads");
+    let g1 = gasleft_source(&analyzer, var(&analyzer, ctx, "g1"));
+    let g2 = gasleft_source(&analyzer, var(&analyzer, ctx, "g2"));
+    // g2 <= g1
+    let g2_max = g2.range_max(&analyzer).unwrap().dependent_on();
+    assert_eq!(g2_max, vec![g1]);
+    // the first read is unconstrained
+    assert!(g1.range_max(&analyzer).unwrap().dependent_on().is_empty());
+}
+
+#[test]
+fn test_gasleft_bounded_by_earlier_read() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "bounded_read");
+    let g = var(&analyzer, ctx, "g");
+    assert!(g
+        .evaled_range_min(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::zero()))));
+    assert!(g
+        .evaled_range_max(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(99)))));
+}
@@ -0,0 +1,62 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{nodes::Concrete, NodeIdx};
+
+fn parsed() -> (Analyzer, NodeIdx) {
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, include_str!("./test_data/getters.sol"));
+    (analyzer, entry)
+}
+
+fn assert_full_range(analyzer: &Analyzer, entry: NodeIdx, func_name: &str, var_name: &str) {
+    let ctx = func_named(analyzer, entry, func_name)
+        .maybe_body_ctx(analyzer)
+        .expect("No body context");
+    assert!(ctx.underlying(analyzer).killed.is_none());
+    let var = ctx
+        .latest_var_by_name(analyzer, var_name)
+        .expect("No variable with that name");
+    assert!(var
+        .evaled_range_min(analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::zero()))));
+    assert!(var
+        .evaled_range_max(analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::MAX))));
+}
+
+#[test]
+fn test_getter_synthesized() {
+    let (analyzer, entry) = parsed();
+    let getter = func_named(&analyzer, entry, "value");
+    assert_eq!(getter.name(&analyzer), "value()");
+    assert!(getter.params(&analyzer).is_empty());
+    assert_eq!(getter.returns(&analyzer).len(), 1);
+    assert!(getter.is_view_or_pure(&analyzer));
+}
+
+#[test]
+fn test_array_getter_takes_index() {
+    let (analyzer, entry) = parsed();
+    let getter = func_named(&analyzer, entry, "values");
+    assert_eq!(getter.name(&analyzer), "values(uint256)");
+    assert_eq!(getter.params(&analyzer).len(), 1);
+    assert_eq!(getter.returns(&analyzer).len(), 1);
+}
+
+#[test]
+fn test_call_getter() {
+    let (analyzer, entry) = parsed();
+    assert_full_range(&analyzer, entry, "readValue", "x");
+}
+
+#[test]
+fn test_call_external_getter() {
+    let (analyzer, entry) = parsed();
+    assert_full_range(&analyzer, entry, "readExternal", "y");
+}
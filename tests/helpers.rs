@@ -1,6 +1,15 @@
+#![allow(dead_code)]
+
+use ethers_core::types::U256;
 use pyrometer::Analyzer;
 use shared::analyzer::Search;
-use shared::{nodes::FunctionNode, Edge};
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{
+    context::{ContextNode, ContextVarNode},
+    nodes::{Concrete, ContractNode, FunctionNode},
+    Edge, NodeIdx,
+};
+use std::collections::BTreeMap;
 
 pub fn assert_no_ctx_killed(path_str: String, sol: &str) {
     let mut analyzer = Analyzer::default();
@@ -21,3 +30,126 @@ pub fn assert_no_ctx_killed(path_str: String, sol: &str) {
         }
     }
 }
+
+/// Parses `sol` into the analyzer and returns the source unit
+pub fn parse(analyzer: &mut Analyzer, sol: &str) -> NodeIdx {
+    let (maybe_entry, _) = analyzer.parse(sol);
+    maybe_entry.unwrap()
+}
+
+/// Finds a function by its name without the parameter list
+pub fn func_named(analyzer: &Analyzer, entry: NodeIdx, func_name: &str) -> FunctionNode {
+    analyzer
+        .search_children(entry, &Edge::Func)
+        .into_iter()
+        .map(FunctionNode::from)
+        .find(|func| func.name(analyzer).starts_with(&format!("{func_name}(")))
+        .expect("No function with that name")
+}
+
+/// Finds a contract by name
+pub fn contract_named(analyzer: &Analyzer, entry: NodeIdx, contract_name: &str) -> ContractNode {
+    analyzer
+        .search_children(entry, &Edge::Contract)
+        .into_iter()
+        .map(ContractNode::from)
+        .find(|con| con.name(analyzer) == contract_name)
+        .expect("No contract with that name")
+}
+
+/// Parses `sol` into the analyzer and returns the body context of the function `func_name`
+pub fn body_ctx(analyzer: &mut Analyzer, sol: &str, func_name: &str) -> ContextNode {
+    let entry = parse(analyzer, sol);
+    func_named(analyzer, entry, func_name)
+        .maybe_body_ctx(analyzer)
+        .expect("No body context")
+}
+
+/// Like `body_ctx`, but also asserts the body context was not killed
+pub fn live_body_ctx(analyzer: &mut Analyzer, sol: &str, func_name: &str) -> ContextNode {
+    let ctx = body_ctx(analyzer, sol, func_name);
+    assert!(ctx.underlying(analyzer).killed.is_none());
+    ctx
+}
+
+/// Maps the single parsed source to its file name, as the lints expect
+pub fn file_mapping(file_name: &str) -> BTreeMap<usize, String> {
+    vec![(0usize, file_name.to_string())].into_iter().collect()
+}
+
+/// The latest version of the variable `var_name` in the context
+pub fn var(analyzer: &Analyzer, ctx: ContextNode, var_name: &str) -> ContextVarNode {
+    ctx.latest_var_by_name(analyzer, var_name)
+        .expect("No variable with that name")
+}
+
+/// Parses `sol` into the analyzer and returns the latest version of the variable `var_name` in
+/// the body of the function `func_name`, asserting the body was not killed
+pub fn body_var(
+    analyzer: &mut Analyzer,
+    sol: &str,
+    func_name: &str,
+    var_name: &str,
+) -> ContextVarNode {
+    let ctx = live_body_ctx(analyzer, sol, func_name);
+    var(analyzer, ctx, var_name)
+}
+
+/// The range string of the variable `var_name` in the body of the function `func_name`
+pub fn range_string_of(sol: &str, func_name: &str, var_name: &str) -> String {
+    let mut analyzer = Analyzer::default();
+    body_var(&mut analyzer, sol, func_name, var_name)
+        .range_string(&analyzer)
+        .expect("Variable had no range")
+}
+
+/// The evaluated minimum and maximum of the latest version of the variable `var_name` in the
+/// context
+pub fn var_bounds(
+    analyzer: &Analyzer,
+    ctx: ContextNode,
+    var_name: &str,
+) -> (Elem<Concrete>, Elem<Concrete>) {
+    let var = var(analyzer, ctx, var_name);
+    (
+        var.evaled_range_min(analyzer).unwrap(),
+        var.evaled_range_max(analyzer).unwrap(),
+    )
+}
+
+/// A concrete unsigned integer range element
+pub fn uint(n: u64) -> Elem<Concrete> {
+    Elem::from(Concrete::from(U256::from(n)))
+}
+
+/// Asserts the evaluated range of the variable is exactly `[min, max]`
+pub fn assert_concrete_range(
+    analyzer: &Analyzer,
+    var: ContextVarNode,
+    min: Concrete,
+    max: Concrete,
+) {
+    assert!(var
+        .evaled_range_min(analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(min)));
+    assert!(var
+        .evaled_range_max(analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(max)));
+}
+
+/// Asserts the evaluated range of the unsigned integer variable is exactly `[min, max]`
+pub fn assert_range(
+    analyzer: &Analyzer,
+    var: ContextVarNode,
+    min: impl Into<U256>,
+    max: impl Into<U256>,
+) {
+    assert_concrete_range(
+        analyzer,
+        var,
+        Concrete::from(min.into()),
+        Concrete::from(max.into()),
+    );
+}
@@ -0,0 +1,51 @@
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/hover.sol");
+
+fn resolve(analyzer: &Analyzer, needle: &str) -> Option<(String, ContextNode)> {
+    let offset = SOL.find(needle).expect("No such text in the source");
+    analyzer
+        .resolve_at_offset(offset)
+        .map(|(func, ctx)| (func.name(analyzer), ctx))
+}
+
+#[test]
+fn test_offset_in_nested_blocks_resolves_to_innermost_context() {
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(SOL);
+    let (func_name, ctx) = resolve(&analyzer, "deep = x").unwrap();
+    assert_eq!(func_name, "outer");
+
+    // the fork of the inner if statement, within the fork of the outer one
+    let outer_fork = ctx.underlying(&analyzer).parent_ctx.unwrap();
+    let body_ctx = outer_fork.underlying(&analyzer).parent_ctx.unwrap();
+    assert!(body_ctx.underlying(&analyzer).parent_ctx.is_none());
+
+    let x = ctx.latest_var_by_name(&analyzer, "x").unwrap();
+    assert!(x
+        .evaled_range_min(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(11)))));
+}
+
+#[test]
+fn test_offset_in_function_body_resolves_to_body_context() {
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(SOL);
+    let (func_name, ctx) = resolve(&analyzer, "uint256 a").unwrap();
+    assert_eq!(func_name, "outer");
+    assert!(ctx.underlying(&analyzer).parent_ctx.is_none());
+
+    let (func_name, _) = resolve(&analyzer, "{}").unwrap();
+    assert_eq!(func_name, "other");
+}
+
+#[test]
+fn test_offset_outside_functions_unresolved() {
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(SOL);
+    assert!(resolve(&analyzer, "contract").is_none());
+}
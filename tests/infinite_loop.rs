@@ -0,0 +1,32 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::loops::InfiniteLoopAnalyzer;
+use pyrometer::Analyzer;
+
+fn infinite_loop_count(sol: &str, func_name: &str) -> usize {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("infinite_loop.sol");
+    analyzer.infinite_loops(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_infinite_loop_flagged() {
+    let sol = include_str!("./test_data/infinite_loop.sol");
+    assert_eq!(infinite_loop_count(sol, "while_true"), 1);
+    assert_eq!(infinite_loop_count(sol, "for_ever"), 1);
+    assert_eq!(infinite_loop_count(sol, "while_true_nested_break"), 1);
+    assert_eq!(infinite_loop_count(sol, "for_ever_with_init"), 1);
+    assert_eq!(infinite_loop_count(sol, "while_flag"), 1);
+}
+
+#[test]
+fn test_infinite_loop_not_flagged() {
+    let sol = include_str!("./test_data/infinite_loop.sol");
+    assert_eq!(infinite_loop_count(sol, "while_true_break"), 0);
+    assert_eq!(infinite_loop_count(sol, "while_true_return"), 0);
+    assert_eq!(infinite_loop_count(sol, "while_cond"), 0);
+    assert_eq!(infinite_loop_count(sol, "for_counter"), 0);
+    assert_eq!(infinite_loop_count(sol, "while_flag_cleared"), 0);
+}
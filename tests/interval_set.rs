@@ -0,0 +1,50 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem, Range};
+
+const SOL: &str = include_str!("./test_data/interval_set.sol");
+
+/// The evaluated exclusions of `y` at the end of the function
+fn y_exclusions(func_name: &str) -> Vec<Elem<Concrete>> {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, func_name);
+    let y = ctx
+        .latest_var_by_name(&analyzer, "y")
+        .expect("No variable with that name");
+    y.range(&analyzer)
+        .expect("No range")
+        .range_exclusions()
+        .iter()
+        .map(|excl| excl.maximize(&analyzer))
+        .collect()
+}
+
+fn excludes(exclusions: &[Elem<Concrete>], val: u64) -> bool {
+    let val = Elem::from(Concrete::from(U256::from(val)));
+    exclusions.iter().any(|excl| excl.range_eq(&val))
+}
+
+#[test]
+fn test_add_shifts_exclusions() {
+    let exclusions = y_exclusions("add_excl");
+    assert!(excludes(&exclusions, 6));
+    assert!(!excludes(&exclusions, 5));
+}
+
+#[test]
+fn test_sub_shifts_exclusions() {
+    let exclusions = y_exclusions("sub_excl");
+    assert!(excludes(&exclusions, 14));
+    assert!(!excludes(&exclusions, 15));
+}
+
+#[test]
+fn test_mul_div_mod_drop_exclusions() {
+    assert!(y_exclusions("mul_excl").is_empty());
+    assert!(y_exclusions("div_excl").is_empty());
+    assert!(y_exclusions("mod_excl").is_empty());
+}
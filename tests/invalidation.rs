@@ -0,0 +1,67 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::analyzer::GraphLike;
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::NodeIdx;
+use std::collections::BTreeSet;
+
+const SOL: &str = include_str!("./test_data/invalidation.sol");
+
+#[test]
+fn test_remove_dependency() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "dependent");
+    let y = ctx.latest_var_by_name(&analyzer, "y").unwrap();
+    let deps = y.range_deps(&analyzer);
+    assert!(!deps.is_empty());
+
+    deps.into_iter().for_each(|dep| analyzer.remove_node(dep));
+    assert!(analyzer.graph().node_weight(y.into()).is_some());
+    assert!(y.range_deps(&analyzer).is_empty());
+    assert!(y
+        .evaled_range_min(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(1)))));
+    assert!(y
+        .evaled_range_max(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(100)))));
+}
+
+#[test]
+fn test_remove_dependencies_in_batch() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "dependent");
+    let y = ctx.latest_var_by_name(&analyzer, "y").unwrap();
+    let deps: BTreeSet<NodeIdx> = y
+        .range_deps(&analyzer)
+        .into_iter()
+        .map(NodeIdx::from)
+        .collect();
+    assert!(!deps.is_empty());
+
+    analyzer.remove_nodes(deps);
+    assert!(y.range_deps(&analyzer).is_empty());
+    assert!(y
+        .evaled_range_min(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(1)))));
+    assert!(y
+        .evaled_range_max(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(100)))));
+}
+
+#[test]
+fn test_remove_variable_edge() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "dependent");
+    let x = ctx.latest_var_by_name(&analyzer, "x").unwrap();
+    let first_x = x.first_version(&analyzer);
+    analyzer.remove_node(first_x);
+    assert!(!ctx.underlying(&analyzer).vars_by_name.contains_key("x"));
+}
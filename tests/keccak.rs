@@ -0,0 +1,43 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::H256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextVarNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/keccak.sol");
+
+fn returned_hash(analyzer: &mut Analyzer, func_name: &str) -> ContextVarNode {
+    let ctx = live_body_ctx(analyzer, SOL, func_name);
+    let rets = ctx.return_nodes(analyzer);
+    assert_eq!(rets.len(), 1);
+    rets[0].1
+}
+
+#[test]
+fn test_literal_hash_is_concrete() {
+    let mut analyzer = Analyzer::default();
+    let hash = returned_hash(&mut analyzer, "literal_hash");
+    let expected: H256 = "0x1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8"
+        .parse()
+        .unwrap();
+    let expected = Elem::from(Concrete::from(expected));
+    assert!(hash
+        .evaled_range_min(&analyzer)
+        .unwrap()
+        .range_eq(&expected));
+    assert!(hash
+        .evaled_range_max(&analyzer)
+        .unwrap()
+        .range_eq(&expected));
+}
+
+#[test]
+fn test_symbolic_hash_is_unconstrained() {
+    let mut analyzer = Analyzer::default();
+    let hash = returned_hash(&mut analyzer, "symbolic_hash");
+    let min = hash.evaled_range_min(&analyzer).unwrap();
+    let max = hash.evaled_range_max(&analyzer).unwrap();
+    assert!(!min.range_eq(&max));
+}
@@ -0,0 +1,59 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+
+const SOL: &str = include_str!("./test_data/library_inline.sol");
+
+#[test]
+fn test_using_library_call_inlined() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "sub_conc");
+    let rets = ctx.return_nodes(&analyzer);
+    assert_eq!(rets.len(), 1);
+    assert_range(&analyzer, rets[0].1, 70, 70);
+}
+
+#[test]
+fn test_qualified_library_call_inlined() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "sub_lib_call");
+    let rets = ctx.return_nodes(&analyzer);
+    assert_eq!(rets.len(), 1);
+    assert_range(&analyzer, rets[0].1, 70, 70);
+}
+
+#[test]
+fn test_library_call_is_internal() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "sub_conc");
+    let children = ctx.underlying(&analyzer).children.clone();
+    assert!(!children.is_empty());
+    assert!(children.iter().all(|child| {
+        let child = child.underlying(&analyzer);
+        child.fn_call.is_some() && child.ext_fn_call.is_none()
+    }));
+}
+
+#[test]
+fn test_library_require_flows_to_caller() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "sub_require");
+    let b = var(&analyzer, ctx, "b");
+    assert_range(&analyzer, b, 0, 100);
+    let ret = var(&analyzer, ctx, "ret");
+    assert_range(&analyzer, ret, 0, 100);
+}
+
+#[test]
+fn test_library_storage_ref_flows_to_caller() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "inc");
+    let c = var(&analyzer, ctx, "c");
+    assert_ne!(c, c.first_version(&analyzer));
+    // the library's name for the reference doesn't leak into the caller
+    assert!(!ctx
+        .local_vars(&analyzer)
+        .iter()
+        .any(|var| var.name(&analyzer) == "counter"));
+}
@@ -0,0 +1,38 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::exprs::Literal;
+use pyrometer::Analyzer;
+use shared::analyzer::LiteralTy;
+use shared::context::ContextVarNode;
+use solang_parser::pt::Loc;
+
+/// The type given to the literal `200` with no type from its context
+fn literal_200_ty(literal_ty: LiteralTy) -> String {
+    let sol = include_str!("./test_data/literal_ty.sol");
+    let mut analyzer = Analyzer {
+        literal_ty,
+        ..Default::default()
+    };
+    let ctx = body_ctx(&mut analyzer, sol, "f");
+
+    let (_, lit) = analyzer
+        .number_literal(ctx, Loc::Implicit, "200", "", false)
+        .expect_single();
+    ContextVarNode::from(lit).ty(&analyzer).as_string(&analyzer)
+}
+
+#[test]
+fn test_smallest_fitting_literal() {
+    assert_eq!(literal_200_ty(LiteralTy::SmallestFitting), "uint8");
+}
+
+#[test]
+fn test_full_literal() {
+    assert_eq!(literal_200_ty(LiteralTy::Full), "uint256");
+}
+
+#[test]
+fn test_default_literal_ty() {
+    assert_eq!(Analyzer::default().literal_ty, LiteralTy::SmallestFitting);
+}
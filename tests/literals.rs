@@ -0,0 +1,73 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::elem::RangeElem;
+use shared::range::elem_ty::Elem;
+use shared::{context::ContextNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/literals.sol");
+
+fn boundary_ctx(analyzer: &mut Analyzer) -> ContextNode {
+    let ctx = live_body_ctx(analyzer, SOL, "boundary");
+    ctx
+}
+
+fn assert_single_point(analyzer: &Analyzer, ctx: ContextNode, var_name: &str, val: U256) {
+    let var = ctx
+        .latest_var_by_name(analyzer, var_name)
+        .expect("No variable with that name");
+    let val = Elem::from(Concrete::from(val));
+    assert!(var.evaled_range_min(analyzer).unwrap().range_eq(&val));
+    assert!(var.evaled_range_max(analyzer).unwrap().range_eq(&val));
+}
+
+#[test]
+fn test_max_literal_not_truncated() {
+    let mut analyzer = Analyzer::default();
+    let ctx = boundary_ctx(&mut analyzer);
+    assert_single_point(&analyzer, ctx, "max", U256::MAX);
+    assert_single_point(&analyzer, ctx, "below_max", U256::MAX - 1);
+}
+
+#[test]
+fn test_scientific_literals() {
+    let mut analyzer = Analyzer::default();
+    let ctx = boundary_ctx(&mut analyzer);
+    assert_single_point(&analyzer, ctx, "sci", U256::exp10(18));
+    assert_single_point(&analyzer, ctx, "big_sci", U256::exp10(77));
+    assert_single_point(&analyzer, ctx, "scaled", U256::from(25) * U256::exp10(17));
+    assert_single_point(&analyzer, ctx, "separated", U256::from(1_000_000));
+}
+
+#[test]
+fn test_dec_literal_val_bounds() {
+    assert_eq!(
+        Concrete::dec_literal_val(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+            ""
+        ),
+        Some(U256::MAX)
+    );
+    // 2**256
+    assert_eq!(
+        Concrete::dec_literal_val(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+            ""
+        ),
+        None
+    );
+    assert_eq!(Concrete::dec_literal_val("1", "77"), Some(U256::exp10(77)));
+    assert_eq!(Concrete::dec_literal_val("12", "76"), None);
+    assert_eq!(Concrete::dec_literal_val("0", "100"), Some(U256::zero()));
+}
+
+#[test]
+fn test_negative_exponent_is_a_diagnostic() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "negative_exponent");
+    assert!(ctx.latest_var_by_name(&analyzer, "x").is_some());
+    assert_eq!(analyzer.diagnostics.len(), 1);
+    assert!(analyzer.diagnostics[0].1.contains("25e-1"));
+}
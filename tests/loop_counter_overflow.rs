@@ -0,0 +1,40 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::loops::{
+    LoopCounterOverflowAnalysis, LoopCounterOverflowAnalyzer,
+};
+use pyrometer::Analyzer;
+
+fn loop_counter_overflows(func_name: &str) -> Vec<LoopCounterOverflowAnalysis> {
+    let sol = include_str!("./test_data/loop_counter_overflow.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("loop_counter_overflow.sol");
+    analyzer.loop_counter_overflows(&file_mapping, ctx)
+}
+
+#[test]
+fn test_strict_bound_past_max() {
+    let overflows = loop_counter_overflows("strict");
+    assert_eq!(overflows.len(), 1);
+    assert_eq!(overflows[0].counter_name, "i");
+    assert_eq!(overflows[0].counter_ty, "uint8");
+}
+
+#[test]
+fn test_inclusive_bound_at_max() {
+    let overflows = loop_counter_overflows("inclusive");
+    assert_eq!(overflows.len(), 1);
+    assert_eq!(overflows[0].counter_name, "i");
+}
+
+#[test]
+fn test_bound_below_max() {
+    assert!(loop_counter_overflows("in_range").is_empty());
+}
+
+#[test]
+fn test_wide_counter() {
+    assert!(loop_counter_overflows("wide_counter").is_empty());
+}
@@ -0,0 +1,29 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+
+#[test]
+fn test_for_loop_multi_update() {
+    let sol = include_str!("./test_data/loops.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, sol, "multi_update");
+
+    // both variables in the update clause are advanced, so both are widened after the loop
+    ["i", "j"].into_iter().for_each(|var_name| {
+        let var = ctx
+            .latest_var_by_name(&analyzer, var_name)
+            .expect("No variable with that name");
+        assert!(var
+            .evaled_range_min(&analyzer)
+            .unwrap()
+            .range_eq(&Elem::from(Concrete::Uint(8, U256::zero()))));
+        assert!(var
+            .evaled_range_max(&analyzer)
+            .unwrap()
+            .range_eq(&Elem::from(Concrete::Uint(8, U256::from(u8::MAX)))));
+    });
+}
@@ -0,0 +1,22 @@
+use pyrometer::Analyzer;
+
+#[test]
+fn test_metrics_disabled_by_default() {
+    let sol = include_str!("./test_data/loops.sol");
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(sol);
+    assert!(analyzer.metrics().is_none());
+}
+
+#[test]
+fn test_metrics_collected() {
+    let sol = include_str!("./test_data/loops.sol");
+    let mut analyzer = Analyzer::default();
+    analyzer.enable_metrics();
+    let _ = analyzer.parse(sol);
+    let metrics = analyzer.metrics().expect("Metrics not collected");
+    assert_eq!(metrics.nodes, analyzer.graph.node_count());
+    assert_eq!(metrics.edges, analyzer.graph.edge_count());
+    assert!(metrics.contexts > 0);
+    assert!(metrics.statements > 0);
+}
@@ -0,0 +1,42 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+use shared::analyzer::Search;
+use shared::{context::ContextVarNode, nodes::FunctionNode, Edge};
+
+fn ret(analyzer: &mut Analyzer, func_name: &str) -> ContextVarNode {
+    let sol = include_str!("./test_data/min_max.sol");
+    let entry = parse(analyzer, sol);
+
+    let func = analyzer
+        .search_children(entry, &Edge::Func)
+        .into_iter()
+        .map(FunctionNode::from)
+        .find(|func| {
+            func.name(analyzer).starts_with(&format!("{func_name}("))
+                && func
+                    .contract(analyzer)
+                    .map_or(false, |c| !c.is_library(analyzer))
+        })
+        .expect("No function with that name");
+    let ctx = func.maybe_body_ctx(analyzer).expect("No body context");
+    assert!(ctx.underlying(analyzer).killed.is_none());
+    // the call is not inlined, so the caller isn't forked on the comparison
+    assert!(ctx.live_forks(analyzer).is_empty());
+    var(analyzer, ctx, "ret")
+}
+
+#[test]
+fn test_library_min_range() {
+    let mut analyzer = Analyzer::default();
+    let ret = ret(&mut analyzer, "min");
+    assert_range(&analyzer, ret, 0, 10);
+}
+
+#[test]
+fn test_library_max_range() {
+    let mut analyzer = Analyzer::default();
+    let ret = ret(&mut analyzer, "max");
+    assert_range(&analyzer, ret, 5, 20);
+}
@@ -0,0 +1,35 @@
+mod helpers;
+use helpers::*;
+
+#[test]
+fn test_widened_lhs() {
+    let sol = include_str!("./test_data/mixed_width.sol");
+    assert_eq!(range_string_of(sol, "widen_lhs", "c"), "uint256[300, 300]");
+}
+
+#[test]
+fn test_widened_rhs() {
+    let sol = include_str!("./test_data/mixed_width.sol");
+    assert_eq!(range_string_of(sol, "widen_rhs", "c"), "uint256[300, 300]");
+}
+
+#[test]
+fn test_widened_mul() {
+    let sol = include_str!("./test_data/mixed_width.sol");
+    assert_eq!(range_string_of(sol, "widen_mul", "c"), "uint16[510, 510]");
+}
+
+#[test]
+fn test_widened_symbolic() {
+    let sol = include_str!("./test_data/mixed_width.sol");
+    assert_eq!(
+        range_string_of(sol, "widen_symbolic", "c"),
+        "uint256[1000, 1255]"
+    );
+}
+
+#[test]
+fn test_shift_keeps_lhs_type() {
+    let sol = include_str!("./test_data/mixed_width.sol");
+    assert_eq!(range_string_of(sol, "shift_keeps_lhs", "c"), "uint8[4, 4]");
+}
@@ -0,0 +1,34 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::elem::RangeElem;
+use shared::range::elem_ty::Elem;
+
+#[test]
+fn test_near_max_mul_overflow() {
+    let sol = include_str!("./test_data/mul_overflow.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, "near_max_overflow");
+    assert!(ctx.underlying(&analyzer).killed.is_some());
+
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, "narrow_overflow");
+    assert!(ctx.underlying(&analyzer).killed.is_some());
+}
+
+#[test]
+fn test_near_max_mul_exact() {
+    let sol = include_str!("./test_data/mul_overflow.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, "near_max_no_overflow");
+    assert!(ctx.underlying(&analyzer).killed.is_none());
+    let c = ctx
+        .latest_var_by_name(&analyzer, "c")
+        .expect("No variable with that name");
+    let max = Elem::from(Concrete::from(U256::MAX));
+    assert!(c.evaled_range_min(&analyzer).unwrap().range_eq(&max));
+    assert!(c.evaled_range_max(&analyzer).unwrap().range_eq(&max));
+}
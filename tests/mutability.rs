@@ -0,0 +1,37 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::mutability::MutabilityAnalyzer;
+use pyrometer::Analyzer;
+
+fn analyze(sol: &str, func_name: &str) -> Option<(usize, usize)> {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("mutability.sol");
+    analyzer
+        .mutability_violations(&file_mapping, ctx)
+        .map(|analysis| (analysis.storage_writes.len(), analysis.storage_reads.len()))
+}
+
+#[test]
+fn test_view_writes_storage() {
+    let sol = include_str!("./test_data/mutability.sol");
+    let (writes, reads) = analyze(sol, "view_writes").expect("No mutability report");
+    assert_eq!(writes, 1);
+    assert_eq!(reads, 0);
+}
+
+#[test]
+fn test_pure_reads_storage() {
+    let sol = include_str!("./test_data/mutability.sol");
+    let (writes, reads) = analyze(sol, "pure_reads").expect("No mutability report");
+    assert_eq!(writes, 0);
+    assert_eq!(reads, 1);
+}
+
+#[test]
+fn test_no_mutability_violations() {
+    let sol = include_str!("./test_data/mutability.sol");
+    assert!(analyze(sol, "view_reads").is_none());
+    assert!(analyze(sol, "pure_math").is_none());
+}
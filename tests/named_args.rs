@@ -0,0 +1,15 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+
+#[test]
+fn test_args_evaluated_in_each_fork() {
+    let sol = include_str!("./test_data/named_args.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, sol, "args_after_fork");
+    let forks = ctx.live_forks(&analyzer);
+    assert_eq!(forks.len(), 2);
+    assert_range(&analyzer, var(&analyzer, forks[0], "x"), 2, 2);
+    assert_range(&analyzer, var(&analyzer, forks[1], "x"), 3, 3);
+}
@@ -0,0 +1,32 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+
+const SOL: &str = include_str!("./test_data/named_returns.sol");
+
+#[test]
+fn test_bare_return_uses_named_return() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "bare_return");
+    let rets = ctx.return_nodes(&analyzer);
+    assert_eq!(rets.len(), 1);
+    assert_range(&analyzer, rets[0].1, 5, 14);
+}
+
+#[test]
+fn test_implicit_return_uses_named_return() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "implicit_return");
+    let rets = ctx.return_nodes(&analyzer);
+    assert_eq!(rets.len(), 1);
+    assert_range(&analyzer, rets[0].1, 0, 18);
+}
+
+#[test]
+fn test_named_return_flows_to_caller() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "caller");
+    let y = var(&analyzer, ctx, "y");
+    assert_range(&analyzer, y, 0, 18);
+}
@@ -0,0 +1,65 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/negated_conditions.sol");
+
+/// The (true, false) forks of the first if statement in the function
+fn forks(analyzer: &mut Analyzer, func_name: &str) -> (ContextNode, ContextNode) {
+    let ctx = body_ctx(analyzer, SOL, func_name);
+    let forks = ctx.forks(analyzer);
+    assert_eq!(forks.len(), 2);
+    (forks[0], forks[1])
+}
+
+#[test]
+fn test_not_var() {
+    let mut analyzer = Analyzer::default();
+    let (true_fork, false_fork) = forks(&mut analyzer, "not_var");
+
+    let (min, max) = var_bounds(&analyzer, true_fork, "done");
+    assert!(min.range_eq(&Elem::from(Concrete::Bool(false))));
+    assert!(max.range_eq(&Elem::from(Concrete::Bool(false))));
+
+    let (min, max) = var_bounds(&analyzer, false_fork, "done");
+    assert!(min.range_eq(&Elem::from(Concrete::Bool(true))));
+    assert!(max.range_eq(&Elem::from(Concrete::Bool(true))));
+}
+
+#[test]
+fn test_not_and() {
+    let mut analyzer = Analyzer::default();
+    let (_true_fork, false_fork) = forks(&mut analyzer, "not_and");
+
+    // the else branch has both sides of the conjunction holding
+    let (x_min, _) = var_bounds(&analyzer, false_fork, "x");
+    let (y_min, _) = var_bounds(&analyzer, false_fork, "y");
+    assert!(x_min.range_eq(&uint(11)));
+    assert!(y_min.range_eq(&uint(11)));
+}
+
+#[test]
+fn test_not_or() {
+    let mut analyzer = Analyzer::default();
+    let (true_fork, _false_fork) = forks(&mut analyzer, "not_or");
+
+    // the if branch has neither side of the disjunction holding
+    let (_, x_max) = var_bounds(&analyzer, true_fork, "x");
+    let (_, y_max) = var_bounds(&analyzer, true_fork, "y");
+    assert!(x_max.range_eq(&uint(10)));
+    assert!(y_max.range_eq(&uint(10)));
+}
+
+#[test]
+fn test_double_not() {
+    let mut analyzer = Analyzer::default();
+    let (true_fork, false_fork) = forks(&mut analyzer, "double_not");
+
+    let (x_min, _) = var_bounds(&analyzer, true_fork, "x");
+    assert!(x_min.range_eq(&uint(11)));
+    let (_, x_max) = var_bounds(&analyzer, false_fork, "x");
+    assert!(x_max.range_eq(&uint(10)));
+}
@@ -0,0 +1,51 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/new_bytes.sol");
+
+fn assert_len_bounds(analyzer: &Analyzer, ctx: ContextNode, min: u64, max: u64) {
+    let l = ctx
+        .latest_var_by_name(analyzer, "l")
+        .expect("No variable with that name");
+    assert!(l
+        .evaled_range_min(analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(min)))));
+    assert!(l
+        .evaled_range_max(analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(max)))));
+}
+
+#[test]
+fn test_new_bytes_bounded_len() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "alloc_bytes");
+    let b = ctx
+        .latest_var_by_name(&analyzer, "b")
+        .expect("No variable with that name");
+    assert_eq!(b.ty(&analyzer).as_string(&analyzer), "bytes");
+    assert_len_bounds(&analyzer, ctx, 0, 32);
+}
+
+#[test]
+fn test_new_string_bounded_len() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "alloc_string");
+    let s = ctx
+        .latest_var_by_name(&analyzer, "s")
+        .expect("No variable with that name");
+    assert_eq!(s.ty(&analyzer).as_string(&analyzer), "string");
+}
+
+#[test]
+fn test_new_bytes_const_len() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "alloc_const");
+    assert_len_bounds(&analyzer, ctx, 20, 20);
+}
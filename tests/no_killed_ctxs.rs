@@ -71,6 +71,13 @@ fn test_require() {
     assert_no_ctx_killed(path_str, sol);
 }
 
+#[test]
+fn test_slice() {
+    let path_str = "./test_data/slice.sol".to_string();
+    let sol = include_str!("./test_data/slice.sol");
+    assert_no_ctx_killed(path_str, sol);
+}
+
 #[test]
 fn test_using() {
     let path_str = "./test_data/using.sol".to_string();
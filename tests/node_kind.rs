@@ -0,0 +1,34 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+use shared::analyzer::{GraphLike, Search};
+use shared::{nodes::FunctionNode, Edge, NodeKind};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_node_kind_tally() {
+    let sol = include_str!("./test_data/loops.sol");
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, sol);
+
+    let mut tally: BTreeMap<NodeKind, usize> = BTreeMap::new();
+    analyzer.graph().node_indices().for_each(|idx| {
+        *tally.entry(analyzer.node_kind(idx)).or_default() += 1;
+    });
+
+    assert_eq!(tally.values().sum::<usize>(), analyzer.graph().node_count());
+    assert_eq!(tally[&NodeKind::SourceUnit], 1);
+    assert_eq!(tally[&NodeKind::Contract], 1);
+    assert_eq!(tally[&NodeKind::Msg], 1);
+    assert_eq!(tally[&NodeKind::Block], 1);
+    assert!(tally[&NodeKind::Context] >= 2);
+    assert!(tally[&NodeKind::ContextVar] > 0);
+
+    // builtin functions are also function nodes
+    let funcs = analyzer.search_children(entry, &Edge::Func);
+    assert!(tally[&NodeKind::Function] >= funcs.len());
+    assert!(funcs
+        .into_iter()
+        .all(|func| analyzer.node_kind(FunctionNode::from(func)) == NodeKind::Function));
+}
@@ -0,0 +1,51 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/nonconst_require.sol");
+
+fn require_ctx(analyzer: &mut Analyzer, func_name: &str) -> ContextNode {
+    live_body_ctx(analyzer, SOL, func_name)
+}
+
+/// The evaluated bounds of `b` after requiring it to compare to `a`, whose range is bounded on
+/// one side only
+fn assert_b_range(func_name: &str, min: U256, max: U256) {
+    let mut analyzer = Analyzer::default();
+    let ctx = require_ctx(&mut analyzer, func_name);
+    let b = ctx
+        .latest_var_by_name(&analyzer, "b")
+        .expect("No variable with that name");
+    let min = Elem::from(Concrete::from(min));
+    let max = Elem::from(Concrete::from(max));
+    assert!(b.evaled_range_min(&analyzer).unwrap().range_eq(&min));
+    assert!(b.evaled_range_max(&analyzer).unwrap().range_eq(&max));
+}
+
+#[test]
+fn test_gt_caps_rhs_by_lhs_max() {
+    // a in [0, 10], a > b
+    assert_b_range("gt", U256::zero(), U256::from(9));
+}
+
+#[test]
+fn test_gte_caps_rhs_by_lhs_max() {
+    // a in [0, 10], a >= b
+    assert_b_range("gte", U256::zero(), U256::from(10));
+}
+
+#[test]
+fn test_lt_raises_rhs_by_lhs_min() {
+    // a in [10, max], a < b
+    assert_b_range("lt", U256::from(11), U256::MAX);
+}
+
+#[test]
+fn test_lte_raises_rhs_by_lhs_min() {
+    // a in [10, max], a <= b
+    assert_b_range("lte", U256::from(10), U256::MAX);
+}
@@ -0,0 +1,28 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::packed_hashes::PackedHashAnalyzer;
+use pyrometer::Analyzer;
+
+fn packed_hash_collision_count(func_name: &str) -> usize {
+    let sol = include_str!("./test_data/packed_hash.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("packed_hash.sol");
+    analyzer.packed_hash_collisions(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_packed_dynamic_values_flagged() {
+    assert_eq!(packed_hash_collision_count("two_strings"), 1);
+}
+
+#[test]
+fn test_packed_single_dynamic_value() {
+    assert_eq!(packed_hash_collision_count("string_and_uint"), 0);
+}
+
+#[test]
+fn test_encoded_dynamic_values() {
+    assert_eq!(packed_hash_collision_count("encoded_strings"), 0);
+}
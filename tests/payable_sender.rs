@@ -0,0 +1,51 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+use shared::context::FindingKind;
+
+const SOL: &str = include_str!("./test_data/payable_sender.sol");
+
+#[test]
+fn test_payable_sender_transfer() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "withdraw");
+    // the balance after sending at most 40 out of 100 is at least 60
+    assert!(!ctx.is_killed(&analyzer));
+    assert!(ctx
+        .underlying(&analyzer)
+        .findings_of(FindingKind::OverTransfer)
+        .is_empty());
+    assert_eq!(
+        ctx.underlying(&analyzer)
+            .findings_of(FindingKind::RawCall)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_payable_sender_transfer_all() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "withdraw_all");
+    assert!(!ctx.is_killed(&analyzer));
+    assert_eq!(
+        ctx.underlying(&analyzer)
+            .findings_of(FindingKind::RawCall)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_payable_sender_send() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "try_withdraw");
+    assert!(!ctx.is_killed(&analyzer));
+    assert_eq!(
+        ctx.underlying(&analyzer)
+            .findings_of(FindingKind::RawCall)
+            .len(),
+        1
+    );
+}
@@ -0,0 +1,45 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::precision::PrecisionLossAnalyzer;
+use pyrometer::Analyzer;
+use shared::context::{ContextFinding, FindingKind};
+use solang_parser::pt::Loc;
+
+const SOL: &str = include_str!("./test_data/precision_loss.sol");
+
+fn divide_before_multiply_count(func_name: &str) -> usize {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, func_name);
+    let file_mapping = file_mapping("precision_loss.sol");
+    analyzer.divide_before_multiply(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_div_then_mul_flagged() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "div_then_mul");
+    let mul = "a / b * c";
+    let start = SOL.find(mul).unwrap();
+    let loc = Loc::File(0, start, start + mul.len());
+    assert_eq!(
+        ctx.underlying(&analyzer)
+            .findings_of(FindingKind::DivideBeforeMultiply),
+        [ContextFinding::DivideBeforeMultiply(loc)]
+    );
+}
+
+#[test]
+fn test_mul_then_div() {
+    assert_eq!(divide_before_multiply_count("mul_then_div"), 0);
+}
+
+#[test]
+fn test_exact_const_div() {
+    assert_eq!(divide_before_multiply_count("exact_const_div"), 0);
+}
+
+#[test]
+fn test_inexact_const_div_flagged() {
+    assert_eq!(divide_before_multiply_count("inexact_const_div"), 1);
+}
@@ -0,0 +1,89 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+
+fn resolver(path: &str) -> Option<String> {
+    match path {
+        "main.sol" => Some(include_str!("./test_data/project/main.sol").to_string()),
+        "missing_import.sol" => {
+            Some(include_str!("./test_data/project/missing_import.sol").to_string())
+        }
+        "lib/Base.sol" => Some(include_str!("./test_data/project/lib/Base.sol").to_string()),
+        "lib/Math.sol" => Some(include_str!("./test_data/project/lib/Math.sol").to_string()),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_analyze_project() {
+    let mut analyzer = Analyzer::default();
+    analyzer
+        .remappings
+        .insert("@math/".to_string(), "lib/".to_string());
+    let (maybe_entry, imported) = analyzer.analyze_project("main.sol", resolver);
+    let entry = maybe_entry.unwrap();
+
+    let mut imported_paths: Vec<_> = imported
+        .iter()
+        .map(|(_, path, _, _)| path.as_str())
+        .collect();
+    imported_paths.sort();
+    assert_eq!(imported_paths, vec!["lib/Base.sol", "lib/Math.sol"]);
+
+    let func = func_named(&analyzer, entry, "use_base");
+    let ctx = func.maybe_body_ctx(&analyzer).expect("No body context");
+    assert!(ctx.underlying(&analyzer).killed.is_none());
+
+    let z = ctx.latest_var_by_name(&analyzer, "z").unwrap();
+    let eleven = Elem::from(Concrete::from(U256::from(11)));
+    assert!(z.evaled_range_min(&analyzer).unwrap().range_eq(&eleven));
+    assert!(z.evaled_range_max(&analyzer).unwrap().range_eq(&eleven));
+}
+
+#[test]
+fn test_missing_import_skipped() {
+    let mut analyzer = Analyzer::default();
+    let (maybe_entry, imported) = analyzer.analyze_project("missing_import.sol", resolver);
+    let entry = maybe_entry.unwrap();
+
+    let imported_paths: Vec<_> = imported
+        .iter()
+        .map(|(_, path, _, _)| path.as_str())
+        .collect();
+    assert_eq!(imported_paths, vec!["lib/Base.sol"]);
+    assert_eq!(analyzer.diagnostics.len(), 1);
+    assert!(analyzer.diagnostics[0].1.contains("lib/Missing.sol"));
+    assert!(analyzer.read_imports_from_fs);
+
+    let func = func_named(&analyzer, entry, "use_base");
+    let ctx = func.maybe_body_ctx(&analyzer).expect("No body context");
+    assert!(ctx.underlying(&analyzer).killed.is_none());
+}
+
+#[test]
+fn test_resolve_import_path() {
+    let mut analyzer = Analyzer::default();
+    analyzer
+        .remappings
+        .insert("@oz/".to_string(), "lib/oz/contracts/".to_string());
+    assert_eq!(
+        analyzer.resolve_import_path(Some("src/token/Token.sol"), "../utils/Math.sol"),
+        "src/utils/Math.sol"
+    );
+    assert_eq!(
+        analyzer.resolve_import_path(Some("src/Token.sol"), "./Base.sol"),
+        "src/Base.sol"
+    );
+    assert_eq!(
+        analyzer.resolve_import_path(Some("src/Token.sol"), "@oz/token/ERC20.sol"),
+        "lib/oz/contracts/token/ERC20.sol"
+    );
+    assert_eq!(
+        analyzer.resolve_import_path(None, "src/Token.sol"),
+        "src/Token.sol"
+    );
+}
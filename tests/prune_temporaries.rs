@@ -0,0 +1,91 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::context::analyzers::{findings::FindingsAnalyzer, registry::AnalyzerRegistry};
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+
+const SOL: &str = include_str!("./test_data/prune_temporaries.sol");
+
+#[test]
+fn test_prune_reduces_nodes() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "folded");
+    let nodes_before = analyzer.graph.node_count();
+    let tmps_before = ctx
+        .vars(&analyzer)
+        .into_iter()
+        .filter(|var| var.is_tmp(&analyzer))
+        .count();
+
+    analyzer.prune_temporaries();
+
+    let nodes_after = analyzer.graph.node_count();
+    let tmps_after = ctx
+        .vars(&analyzer)
+        .into_iter()
+        .filter(|var| var.is_tmp(&analyzer))
+        .count();
+    assert!(
+        nodes_after < nodes_before,
+        "no nodes pruned: {nodes_before} before, {nodes_after} after"
+    );
+    assert!(tmps_after < tmps_before);
+}
+
+#[test]
+fn test_prune_preserves_user_ranges() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "folded");
+    analyzer.prune_temporaries();
+
+    let c = ctx
+        .latest_var_by_name(&analyzer, "c")
+        .expect("No variable with that name");
+    let val = Elem::from(Concrete::from(U256::from(37)));
+    assert!(c.evaled_range_min(&analyzer).unwrap().range_eq(&val));
+    assert!(c.evaled_range_max(&analyzer).unwrap().range_eq(&val));
+}
+
+#[test]
+fn test_prune_preserves_referenced_temporaries() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "symbolic");
+    let y = ctx
+        .latest_var_by_name(&analyzer, "y")
+        .expect("No variable with that name");
+    let min_before = y.evaled_range_min(&analyzer).unwrap();
+    let max_before = y.evaled_range_max(&analyzer).unwrap();
+
+    analyzer.prune_temporaries();
+
+    // `y` refers to the temporary for `x / 2` and the return refers to the temporary for `y + 1`
+    assert!(y.evaled_range_min(&analyzer).unwrap().range_eq(&min_before));
+    assert!(y.evaled_range_max(&analyzer).unwrap().range_eq(&max_before));
+    let rets = ctx.return_nodes(&analyzer);
+    assert_eq!(rets.len(), 1);
+    assert!(rets[0].1.evaled_range_max(&analyzer).is_some());
+}
+
+#[test]
+fn test_prune_preserves_lint_findings() {
+    let sol = include_str!("./test_data/prune_lints.sol");
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(sol);
+    let file_mapping = file_mapping("prune_lints.sol");
+    let reported = |analyzer: &Analyzer| {
+        analyzer
+            .findings(&file_mapping, &AnalyzerRegistry::default())
+            .into_iter()
+            .map(|finding| (finding.rule_id, finding.msg))
+            .collect::<Vec<_>>()
+    };
+    let before = reported(&analyzer);
+    assert!(!before.is_empty());
+
+    // every variable a lint reports on outlives the pruning
+    analyzer.prune_temporaries();
+    assert_eq!(reported(&analyzer), before);
+}
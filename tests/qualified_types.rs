@@ -0,0 +1,36 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+use shared::nodes::{TypeNode, VarType};
+
+fn type_name_of(sol: &str, func_name: &str, var_name: &str) -> String {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, sol, func_name);
+    let var = ctx
+        .latest_var_by_name(&analyzer, var_name)
+        .expect("No variable with that name");
+    match var.ty(&analyzer) {
+        VarType::User(TypeNode::Struct(strukt)) => strukt.name(&analyzer),
+        VarType::User(TypeNode::Enum(enu)) => enu.name(&analyzer),
+        e => panic!("Variable was not a struct or enum: {e:?}"),
+    }
+}
+
+#[test]
+fn test_library_qualified_struct() {
+    let sol = include_str!("./test_data/qualified_types.sol");
+    assert_eq!(type_name_of(sol, "declare_struct", "p"), "Point");
+}
+
+#[test]
+fn test_library_qualified_enum() {
+    let sol = include_str!("./test_data/qualified_types.sol");
+    assert_eq!(type_name_of(sol, "declare_enum", "k"), "Kind");
+}
+
+#[test]
+fn test_library_qualified_struct_defined_later() {
+    let sol = include_str!("./test_data/qualified_types.sol");
+    assert_eq!(type_name_of(sol, "declare_later", "l"), "Later");
+}
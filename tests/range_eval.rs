@@ -0,0 +1,40 @@
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::{elem_ty::Elem, RangeEval, SolcRange};
+
+fn range(min: u64, max: u64) -> SolcRange {
+    SolcRange {
+        min: Elem::from(Concrete::from(U256::from(min))),
+        max: Elem::from(Concrete::from(U256::from(max))),
+        exclusions: vec![],
+    }
+}
+
+#[test]
+fn test_contains() {
+    let analyzer = Analyzer::default();
+    assert!(range(0, 10).contains(&range(2, 5), &analyzer));
+    assert!(range(0, 10).contains(&range(0, 10), &analyzer));
+    assert!(!range(0, 10).contains(&range(5, 11), &analyzer));
+    assert!(!range(2, 5).contains(&range(0, 10), &analyzer));
+}
+
+#[test]
+fn test_is_subset_of() {
+    let analyzer = Analyzer::default();
+    assert!(range(2, 5).is_subset_of(&range(0, 10), &analyzer));
+    assert!(range(3, 3).is_subset_of(&range(3, 3), &analyzer));
+    assert!(!range(0, 10).is_subset_of(&range(2, 5), &analyzer));
+}
+
+#[test]
+fn test_overlaps() {
+    let analyzer = Analyzer::default();
+    assert!(range(0, 5).overlaps(&range(5, 10), &analyzer));
+    assert!(range(5, 10).overlaps(&range(0, 5), &analyzer));
+    assert!(range(0, 10).overlaps(&range(2, 3), &analyzer));
+    assert!(range(2, 3).overlaps(&range(0, 10), &analyzer));
+    assert!(!range(0, 1).overlaps(&range(5, 6), &analyzer));
+    assert!(!range(5, 6).overlaps(&range(0, 1), &analyzer));
+}
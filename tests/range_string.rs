@@ -0,0 +1,31 @@
+mod helpers;
+use helpers::*;
+
+#[test]
+fn test_range_string_uint() {
+    let sol = include_str!("./test_data/range_string.sol");
+    assert_eq!(range_string_of(sol, "u_int256", "x"), "uint256[0, 100]");
+}
+
+#[test]
+fn test_range_string_int() {
+    let sol = include_str!("./test_data/range_string.sol");
+    assert_eq!(range_string_of(sol, "i_nt8", "x"), "int8[-5, 5]");
+}
+
+#[test]
+fn test_range_string_large() {
+    let sol = include_str!("./test_data/range_string.sol");
+    assert_eq!(
+        range_string_of(sol, "u_int256_large", "x"),
+        "uint256[115792089237316195423570985008687907853269984665640564039457584007913129639930, 115792089237316195423570985008687907853269984665640564039457584007913129639935]"
+    );
+}
+
+#[test]
+fn test_range_string_dyn() {
+    let sol = include_str!("./test_data/range_string.sol");
+    let range_str = range_string_of(sol, "dyn_len", "x");
+    assert!(range_str.starts_with("uint256[0, "));
+    assert!(range_str.contains("len(arr)"));
+}
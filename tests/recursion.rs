@@ -0,0 +1,33 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::recursion::RecursionAnalyzer;
+use pyrometer::Analyzer;
+
+fn approximated_count(sol: &str, func_name: &str, max_call_depth: usize) -> usize {
+    let mut analyzer = Analyzer {
+        max_call_depth,
+        ..Default::default()
+    };
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("recursion.sol");
+    analyzer.approximated_recursion(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_recursive_factorial() {
+    let sol = include_str!("./test_data/recursion.sol");
+    assert!(approximated_count(sol, "factorial", 3) > 0);
+}
+
+#[test]
+fn test_mutual_recursion() {
+    let sol = include_str!("./test_data/recursion.sol");
+    assert!(approximated_count(sol, "is_even", 3) > 0);
+}
+
+#[test]
+fn test_no_recursion() {
+    let sol = include_str!("./test_data/recursion.sol");
+    assert_eq!(approximated_count(sol, "not_recursive", 3), 0);
+}
@@ -0,0 +1,60 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::findings::FindingsAnalyzer;
+use pyrometer::context::analyzers::registry::AnalyzerRegistry;
+use pyrometer::context::analyzers::requires::{FailableRequireAnalyzer, RedundantRequireAnalysis};
+use pyrometer::Analyzer;
+use solang_parser::pt::Loc;
+
+fn redundant(func_name: &str) -> (Analyzer, Vec<RedundantRequireAnalysis>) {
+    let sol = include_str!("./test_data/redundant_requires.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("redundant_requires.sol");
+    let redundant = analyzer.redundant_requires(&file_mapping, ctx);
+    (analyzer, redundant)
+}
+
+fn line_of(loc: Loc) -> usize {
+    let sol = include_str!("./test_data/redundant_requires.sol");
+    match loc {
+        Loc::File(_, start, _) => sol[..start].lines().count(),
+        _ => panic!("Expected a file location"),
+    }
+}
+
+#[test]
+fn test_duplicate_require() {
+    let (_, redundant) = redundant("duplicate");
+    assert_eq!(redundant.len(), 1);
+    // the second require is the redundant one
+    assert_eq!(line_of(redundant[0].require_span.1), 4);
+}
+
+#[test]
+fn test_implied_require() {
+    let (_, redundant) = redundant("implied");
+    assert_eq!(redundant.len(), 1);
+    assert_eq!(line_of(redundant[0].require_span.1), 9);
+}
+
+#[test]
+fn test_distinct_requires_not_reported() {
+    let (_, redundant) = redundant("distinct");
+    assert!(redundant.is_empty());
+}
+
+#[test]
+fn test_redundant_require_lint() {
+    let (analyzer, _) = redundant("duplicate");
+    let file_mapping = file_mapping("redundant_requires.sol");
+    let findings = analyzer.findings(&file_mapping, &AnalyzerRegistry::default());
+    assert_eq!(
+        findings
+            .iter()
+            .filter(|finding| finding.rule_id == "redundant-require")
+            .count(),
+        2
+    );
+}
@@ -0,0 +1,62 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextNode, nodes::Concrete};
+
+const SOL: &str = include_str!("./test_data/require_continuation.sol");
+
+fn single_path_ctx(analyzer: &mut Analyzer, func_name: &str) -> ContextNode {
+    let ctx = live_body_ctx(analyzer, SOL, func_name);
+    // the false path of a require reverts, so analysis continues on a single path
+    assert!(ctx.forks(analyzer).is_empty());
+    ctx
+}
+
+#[test]
+fn test_read_after_require_is_narrowed() {
+    let mut analyzer = Analyzer::default();
+    let ctx = single_path_ctx(&mut analyzer, "read_after");
+
+    let (min, max) = var_bounds(&analyzer, ctx, "x");
+    assert!(min.range_eq(&uint(0)));
+    assert!(max.range_eq(&uint(9)));
+
+    let (min, max) = var_bounds(&analyzer, ctx, "y");
+    assert!(min.range_eq(&uint(1)));
+    assert!(max.range_eq(&uint(10)));
+}
+
+#[test]
+fn test_require_advances_version() {
+    let mut analyzer = Analyzer::default();
+    let ctx = single_path_ctx(&mut analyzer, "read_after");
+    let x = ctx.latest_var_by_name(&analyzer, "x").unwrap();
+    let first = x.first_version(&analyzer);
+    assert_ne!(x, first);
+    // the version before the require keeps the unconstrained range
+    assert!(first
+        .evaled_range_max(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::MAX))));
+}
+
+#[test]
+fn test_storage_read_after_require_is_narrowed() {
+    let mut analyzer = Analyzer::default();
+    let ctx = single_path_ctx(&mut analyzer, "storage_read_after");
+    let (min, _) = var_bounds(&analyzer, ctx, "s");
+    assert!(min.range_eq(&uint(6)));
+}
+
+#[test]
+fn test_bool_read_after_require_is_narrowed() {
+    let mut analyzer = Analyzer::default();
+    let ctx = single_path_ctx(&mut analyzer, "bool_read_after");
+    let (min, max) = var_bounds(&analyzer, ctx, "f");
+    let t = Elem::from(Concrete::Bool(true));
+    assert!(min.range_eq(&t));
+    assert!(max.range_eq(&t));
+}
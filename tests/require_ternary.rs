@@ -0,0 +1,37 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+
+#[test]
+fn test_require_ternary_per_branch() {
+    let sol = include_str!("./test_data/require_ternary.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, "per_branch");
+
+    let forks = ctx.forks(&analyzer);
+    assert_eq!(forks.len(), 2);
+    let (true_fork, false_fork) = (forks[0], forks[1]);
+
+    assert_range(&analyzer, var(&analyzer, true_fork, "x"), 101, U256::MAX);
+    assert_range(&analyzer, var(&analyzer, true_fork, "y"), 11, U256::MAX);
+
+    assert_range(&analyzer, var(&analyzer, false_fork, "x"), 0, 100);
+    assert_range(&analyzer, var(&analyzer, false_fork, "y"), 0, 4);
+}
+
+#[test]
+fn test_require_ternary_impossible_branch() {
+    let sol = include_str!("./test_data/require_ternary.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, "impossible_branch");
+
+    let forks = ctx.forks(&analyzer);
+    assert_eq!(forks.len(), 2);
+    assert!(forks[0].is_killed(&analyzer));
+    assert!(!forks[1].is_killed(&analyzer));
+    assert!(!ctx.is_killed(&analyzer));
+
+    assert_range(&analyzer, var(&analyzer, forks[1], "x"), 0, 4);
+}
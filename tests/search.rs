@@ -0,0 +1,41 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+use shared::analyzer::Search;
+use shared::context::{ContextEdge, ContextVarNode};
+use shared::{nodes::ContractNode, Edge};
+
+#[test]
+fn test_search_for_descendants_funcs() {
+    let sol = include_str!("./test_data/search.sol");
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, sol);
+
+    let contract = analyzer
+        .search_children(entry, &Edge::Contract)
+        .into_iter()
+        .map(ContractNode::from)
+        .next()
+        .expect("No contract");
+    let funcs = analyzer.search_for_descendants(contract.into(), &Edge::Func);
+    assert_eq!(funcs.len(), 3);
+    // the search doesn't follow edges of other types
+    assert!(analyzer
+        .search_for_descendants(entry, &Edge::Func)
+        .is_empty());
+}
+
+#[test]
+fn test_search_for_descendants_vars() {
+    let sol = include_str!("./test_data/search.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, "a");
+    let mut names = analyzer
+        .search_for_descendants(ctx.into(), &Edge::Context(ContextEdge::Variable))
+        .into_iter()
+        .map(|var| ContextVarNode::from(var).name(&analyzer))
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["x".to_string(), "y".to_string()]);
+}
@@ -0,0 +1,54 @@
+mod helpers;
+use helpers::*;
+
+#[test]
+fn test_shl_assign_masked() {
+    let sol = include_str!("./test_data/shift_assign.sol");
+    assert_eq!(
+        range_string_of(sol, "shl_assign_masked", "x"),
+        "uint8[0, 0]"
+    );
+    assert_eq!(
+        range_string_of(sol, "shl_assign_partial_mask", "x"),
+        "uint8[2, 2]"
+    );
+    assert_eq!(
+        range_string_of(sol, "shl_assign_past_width", "x"),
+        "uint8[0, 0]"
+    );
+}
+
+#[test]
+fn test_shl_assign_unknown_spans_type() {
+    let sol = include_str!("./test_data/shift_assign.sol");
+    assert_eq!(
+        range_string_of(sol, "shl_assign_unknown", "x"),
+        "uint8[0, 255]"
+    );
+}
+
+#[test]
+fn test_signed_shl_assign_masked() {
+    let sol = include_str!("./test_data/shift_assign.sol");
+    assert_eq!(
+        range_string_of(sol, "signed_shl_assign_negative", "x"),
+        "int8[-2, -2]"
+    );
+    assert_eq!(
+        range_string_of(sol, "signed_shl_assign_into_sign_bit", "x"),
+        "int8[-128, -128]"
+    );
+    assert_eq!(
+        range_string_of(sol, "signed_shl_assign_min", "x"),
+        "int8[0, 0]"
+    );
+}
+
+#[test]
+fn test_signed_shl_assign_unknown_spans_type() {
+    let sol = include_str!("./test_data/shift_assign.sol");
+    assert_eq!(
+        range_string_of(sol, "signed_shl_assign_unknown", "x"),
+        "int8[-128, 127]"
+    );
+}
@@ -0,0 +1,48 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::{I256, U256};
+use pyrometer::Analyzer;
+use shared::nodes::{Builtin, Concrete};
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+
+const SOL: &str = include_str!("./test_data/signed_cast.sol");
+
+fn assert_var_range(func_name: &str, var_name: &str, min: u64, max: u64) {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, func_name);
+    let var = ctx.latest_var_by_name(&analyzer, var_name).unwrap();
+    assert!(var
+        .evaled_range_min(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::Uint(8, U256::from(min)))));
+    assert!(var
+        .evaled_range_max(&analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::Uint(8, U256::from(max)))));
+}
+
+#[test]
+fn test_negative_one_wraps_to_max() {
+    assert_eq!(
+        Concrete::Int(8, I256::from(-1i32)).cast(Builtin::Uint(8)),
+        Some(Concrete::Uint(8, U256::from(255)))
+    );
+    assert_var_range("neg_one", "x", 255, 255);
+}
+
+#[test]
+fn test_negative_range_maps_to_upper_half() {
+    assert_var_range("negative", "y", 128, 255);
+}
+
+#[test]
+fn test_nonnegative_range_unchanged() {
+    assert_var_range("nonnegative", "y", 0, 127);
+}
+
+#[test]
+fn test_range_straddling_zero_covers_type() {
+    // [-1, 1] maps to {255} ∪ [0, 1], whose hull is the whole type
+    assert_var_range("straddles_zero", "y", 0, 255);
+}
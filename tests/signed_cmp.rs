@@ -0,0 +1,27 @@
+mod helpers;
+use helpers::*;
+
+#[test]
+fn test_signed_lt_zero() {
+    let sol = include_str!("./test_data/signed_cmp.sol");
+    assert_eq!(range_string_of(sol, "lt_zero", "x"), "int8[-128, -1]");
+}
+
+#[test]
+fn test_signed_gte_zero() {
+    let sol = include_str!("./test_data/signed_cmp.sol");
+    assert_eq!(range_string_of(sol, "gte_zero", "x"), "int8[0, 127]");
+    assert_eq!(range_string_of(sol, "gt_neg_one", "x"), "int8[0, 127]");
+}
+
+#[test]
+fn test_signed_lte_negative() {
+    let sol = include_str!("./test_data/signed_cmp.sol");
+    assert_eq!(range_string_of(sol, "lte_neg", "x"), "int8[-128, -100]");
+}
+
+#[test]
+fn test_signed_type_min() {
+    let sol = include_str!("./test_data/signed_cmp.sol");
+    assert_eq!(range_string_of(sol, "int_min", "x"), "int8[-128, -128]");
+}
@@ -0,0 +1,50 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::context::ContextNode;
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+
+const SOL: &str = include_str!("./test_data/slice.sol");
+
+fn assert_len(analyzer: &Analyzer, ctx: ContextNode, len: u64) {
+    let n = ctx
+        .latest_var_by_name(analyzer, "n")
+        .expect("No variable with that name");
+    let len = Elem::from(Concrete::from(U256::from(len)));
+    assert!(n.evaled_range_min(analyzer).unwrap().range_eq(&len));
+    assert!(n.evaled_range_max(analyzer).unwrap().range_eq(&len));
+}
+
+#[test]
+fn test_slice_len() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "slice_len");
+    assert_len(&analyzer, ctx, 4);
+}
+
+#[test]
+fn test_forked_start_sliced_per_branch() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "slice_forked_start");
+    let forks = ctx.underlying(&analyzer).forks.clone();
+    assert_eq!(forks.len(), 2);
+    assert!(!forks[0].is_killed(&analyzer));
+    assert!(!forks[1].is_killed(&analyzer));
+    assert_len(&analyzer, forks[0], 8);
+    assert_len(&analyzer, forks[1], 6);
+}
+
+#[test]
+fn test_forked_array_sliced_per_branch() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "slice_forked_array");
+    let forks = ctx.underlying(&analyzer).forks.clone();
+    assert_eq!(forks.len(), 2);
+    assert!(!forks[0].is_killed(&analyzer));
+    assert!(!forks[1].is_killed(&analyzer));
+    assert_len(&analyzer, forks[0], 8);
+    assert_len(&analyzer, forks[1], 6);
+}
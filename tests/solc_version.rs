@@ -0,0 +1,62 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::context::analyzers::underflows::UnderflowAnalyzer;
+use pyrometer::Analyzer;
+use shared::context::ContextNode;
+
+fn versioned_ctx(
+    solc_version: Option<(u64, u64, u64)>,
+    func_name: &str,
+) -> (Analyzer, ContextNode) {
+    let sol = include_str!("./test_data/solc_version.sol");
+    let mut analyzer = Analyzer {
+        solc_version,
+        ..Default::default()
+    };
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    (analyzer, ctx)
+}
+
+fn underflow_count(analyzer: &Analyzer, ctx: ContextNode) -> usize {
+    let file_mapping = file_mapping("solc_version.sol");
+    analyzer.possible_underflows(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_default_is_checked() {
+    let (analyzer, ctx) = versioned_ctx(None, "symbolic");
+    assert_eq!(underflow_count(&analyzer, ctx), 1);
+    let z = var(&analyzer, ctx, "z");
+    assert_range(&analyzer, z, 0, 10);
+}
+
+#[test]
+fn test_0_8_is_checked() {
+    let (analyzer, ctx) = versioned_ctx(Some((0, 8, 0)), "symbolic");
+    assert_eq!(underflow_count(&analyzer, ctx), 1);
+    let z = var(&analyzer, ctx, "z");
+    assert_range(&analyzer, z, 0, 10);
+}
+
+#[test]
+fn test_0_7_wraps() {
+    let (analyzer, ctx) = versioned_ctx(Some((0, 7, 6)), "symbolic");
+    // wrapping is what makes the subtraction worth reporting
+    assert_eq!(underflow_count(&analyzer, ctx), 1);
+    let z = var(&analyzer, ctx, "z");
+    assert_range(&analyzer, z, 0, U256::MAX);
+
+    let (analyzer, ctx) = versioned_ctx(Some((0, 7, 6)), "constant_underflow");
+    assert!(!ctx.is_killed(&analyzer));
+    let c = var(&analyzer, ctx, "c");
+    assert_range(&analyzer, c, U256::MAX, U256::MAX);
+}
+
+#[test]
+fn test_0_7_safe_math_not_flagged() {
+    let (analyzer, ctx) = versioned_ctx(Some((0, 7, 6)), "safe_math");
+    assert!(!ctx.is_killed(&analyzer));
+    assert_eq!(underflow_count(&analyzer, ctx), 0);
+}
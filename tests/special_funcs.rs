@@ -0,0 +1,72 @@
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::analyzer::GraphLike;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{
+    context::ContextNode,
+    nodes::{Concrete, FunctionNode},
+    Node,
+};
+use solang_parser::pt::FunctionTy;
+
+fn special_ctx(analyzer: &mut Analyzer, contract_name: &str, ty: FunctionTy) -> ContextNode {
+    let sol = include_str!("./test_data/special_funcs.sol");
+    let _ = analyzer.parse(sol);
+
+    let func = analyzer
+        .graph()
+        .node_indices()
+        .filter(|idx| matches!(analyzer.node(*idx), Node::Function(func) if func.ty == ty))
+        .map(FunctionNode::from)
+        .find(|func| {
+            func.contract(analyzer)
+                .map(|con| con.name(analyzer) == contract_name)
+                .unwrap_or(false)
+        })
+        .expect("No function of that type in the contract");
+    let ctx = func.maybe_body_ctx(analyzer).expect("No body context");
+    assert!(ctx.underlying(analyzer).killed.is_none());
+    assert_eq!(
+        ctx.associated_contract(analyzer).name(analyzer),
+        contract_name
+    );
+    ctx
+}
+
+#[test]
+fn test_receive_body() {
+    let mut analyzer = Analyzer::default();
+    let ctx = special_ctx(&mut analyzer, "Receiver", FunctionTy::Receive);
+    let balance = ctx
+        .latest_var_by_name(&analyzer, "balance")
+        .expect("No variable with that name");
+    let zero = Elem::from(Concrete::from(U256::zero()));
+    assert!(balance.evaled_range_min(&analyzer).unwrap().range_eq(&zero));
+    assert!(ctx.latest_var_by_name(&analyzer, "received").is_none());
+}
+
+#[test]
+fn test_fallback_body() {
+    let mut analyzer = Analyzer::default();
+    let ctx = special_ctx(&mut analyzer, "Receiver", FunctionTy::Fallback);
+    let fallbacks = ctx
+        .latest_var_by_name(&analyzer, "fallbacks")
+        .expect("No variable with that name");
+    let one = Elem::from(Concrete::from(U256::from(1)));
+    assert!(fallbacks
+        .evaled_range_min(&analyzer)
+        .unwrap()
+        .range_eq(&one));
+    assert!(fallbacks
+        .evaled_range_max(&analyzer)
+        .unwrap()
+        .range_eq(&one));
+}
+
+#[test]
+fn test_receive_per_contract() {
+    let mut analyzer = Analyzer::default();
+    let ctx = special_ctx(&mut analyzer, "OtherReceiver", FunctionTy::Receive);
+    assert!(ctx.latest_var_by_name(&analyzer, "received").is_some());
+    assert!(ctx.latest_var_by_name(&analyzer, "balance").is_none());
+}
@@ -0,0 +1,80 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::context::analyzers::findings::{FindingsAnalyzer, Severity};
+use pyrometer::context::analyzers::registry::AnalyzerRegistry;
+use pyrometer::context::analyzers::requires::FailableRequireAnalyzer;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextNode, nodes::Concrete};
+
+fn analyze(strict_requires: bool, func_name: &str) -> (Analyzer, ContextNode, usize) {
+    let sol = include_str!("./test_data/strict_requires.sol");
+    let mut analyzer = Analyzer {
+        strict_requires,
+        ..Default::default()
+    };
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("strict_requires.sol");
+    let failable = analyzer.failable_requires(&file_mapping, ctx).len();
+    (analyzer, ctx, failable)
+}
+
+fn assert_narrowed(analyzer: &Analyzer, ctx: ContextNode) {
+    let x = ctx
+        .latest_var_by_name(analyzer, "x")
+        .expect("No variable with that name");
+    assert!(x
+        .evaled_range_min(analyzer)
+        .unwrap()
+        .range_eq(&Elem::from(Concrete::from(U256::from(11)))));
+}
+
+#[test]
+fn test_default_mode_narrows_silently() {
+    let (analyzer, ctx, failable) = analyze(false, "can_fail");
+    assert_eq!(failable, 0);
+    assert_narrowed(&analyzer, ctx);
+}
+
+#[test]
+fn test_strict_mode_reports_failable_require() {
+    let (analyzer, ctx, failable) = analyze(true, "can_fail");
+    assert_eq!(failable, 1);
+    assert_narrowed(&analyzer, ctx);
+}
+
+#[test]
+fn test_strict_mode_ignores_requires_that_hold() {
+    let (_, _, failable) = analyze(true, "always_holds");
+    assert_eq!(failable, 0);
+
+    // the second require is implied by the first
+    let (_, _, failable) = analyze(true, "implied");
+    assert_eq!(failable, 1);
+}
+
+#[test]
+fn test_failable_assert_reported_as_error() {
+    // asserts are checked regardless of strictness, and aren't reported as requires
+    let (analyzer, ctx, failable) = analyze(false, "assert_can_fail");
+    assert_eq!(failable, 0);
+    let file_mapping = file_mapping("strict_requires.sol");
+    assert_eq!(analyzer.failable_asserts(&file_mapping, ctx).len(), 1);
+
+    let findings = analyzer.findings(&file_mapping, &AnalyzerRegistry::default());
+    assert!(findings.iter().any(|finding| {
+        finding.rule_id == "failable-assert" && finding.severity == Severity::Error
+    }));
+    assert!(!findings
+        .iter()
+        .any(|finding| finding.rule_id == "failable-require"));
+}
+
+#[test]
+fn test_assert_that_holds_not_reported() {
+    let (analyzer, ctx, _) = analyze(true, "assert_holds");
+    let file_mapping = file_mapping("strict_requires.sol");
+    assert!(analyzer.failable_asserts(&file_mapping, ctx).is_empty());
+}
@@ -0,0 +1,108 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::context::analyzers::underflows::UnderflowAnalyzer;
+use pyrometer::Analyzer;
+use shared::context::ContextNode;
+
+const SOL: &str = include_str!("./test_data/sub_underflow.sol");
+
+fn underflow_count(analyzer: &Analyzer, ctx: ContextNode) -> usize {
+    let file_mapping = file_mapping("sub_underflow.sol");
+    analyzer.possible_underflows(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_overlapping_sub_flagged() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "overlapping");
+    assert_eq!(underflow_count(&analyzer, ctx), 1);
+    let left = var(&analyzer, ctx, "left");
+    assert_range(&analyzer, left, 0, 100);
+}
+
+#[test]
+fn test_overlapping_sub_assign_flagged() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "overlapping_assign");
+    assert_eq!(underflow_count(&analyzer, ctx), 1);
+}
+
+#[test]
+fn test_guarded_sub_not_flagged() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "guarded");
+    assert_eq!(underflow_count(&analyzer, ctx), 0);
+}
+
+#[test]
+fn test_disjoint_sub_range() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "disjoint");
+    assert_eq!(underflow_count(&analyzer, ctx), 0);
+    let left = var(&analyzer, ctx, "left");
+    assert_range(&analyzer, left, 50, U256::MAX);
+}
+
+#[test]
+fn test_unchecked_sub_wraps() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "wrapping");
+    assert_eq!(underflow_count(&analyzer, ctx), 0);
+    let c = var(&analyzer, ctx, "c");
+    assert_range(&analyzer, c, U256::MAX, U256::MAX);
+}
+
+#[test]
+fn test_unchecked_symbolic_sub_wraps() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "wrapping_symbolic");
+    // only the subtraction after the `unchecked` block is checked
+    assert_eq!(underflow_count(&analyzer, ctx), 1);
+    let z = var(&analyzer, ctx, "z");
+    assert_range(&analyzer, z, 0, U256::MAX);
+    let w = var(&analyzer, ctx, "w");
+    assert_range(&analyzer, w, 0, 10);
+}
+
+#[test]
+fn test_branch_underflow_path_condition() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "branch_underflow");
+    let file_mapping = file_mapping("sub_underflow.sol");
+    let underflows = analyzer.possible_underflows(&file_mapping, ctx);
+    assert_eq!(underflows.len(), 1);
+    assert_eq!(
+        underflows[0].path_condition.as_deref(),
+        Some("(x > 100) where x: uint256[101, 200]")
+    );
+}
+
+#[test]
+fn test_unbranched_underflow_no_path_condition() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "overlapping");
+    let file_mapping = file_mapping("sub_underflow.sol");
+    let underflows = analyzer.possible_underflows(&file_mapping, ctx);
+    assert_eq!(underflows.len(), 1);
+    assert!(underflows[0].path_condition.is_none());
+}
+
+#[test]
+fn test_equal_const_sub_is_zero() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "equal_consts");
+    assert_eq!(underflow_count(&analyzer, ctx), 0);
+    let c = var(&analyzer, ctx, "c");
+    assert_range(&analyzer, c, 0, 0);
+}
+
+#[test]
+fn test_sub_of_equal_bound_is_zero() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "equal_to_bound");
+    assert_eq!(underflow_count(&analyzer, ctx), 0);
+    let c = var(&analyzer, ctx, "c");
+    assert_range(&analyzer, c, 0, 0);
+}
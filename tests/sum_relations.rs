@@ -0,0 +1,49 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+use shared::context::ContextNode;
+
+const SOL: &str = include_str!("./test_data/sum_relations.sol");
+
+fn bounds(analyzer: &Analyzer, ctx: ContextNode, name: &str) -> (U256, U256) {
+    let var = ctx
+        .latest_var_by_name(analyzer, name)
+        .expect("No variable with that name");
+    (
+        var.min_u256(analyzer).unwrap(),
+        var.max_u256(analyzer).unwrap(),
+    )
+}
+
+#[test]
+fn test_narrowing_one_side_tightens_the_other() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "narrow_after");
+    assert!(!ctx.is_killed(&analyzer));
+    assert_eq!(
+        bounds(&analyzer, ctx, "a"),
+        (U256::from(60), U256::from(100))
+    );
+    assert_eq!(bounds(&analyzer, ctx, "b"), (U256::zero(), U256::from(40)));
+}
+
+#[test]
+fn test_relation_uses_earlier_bounds() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "narrow_before");
+    assert!(!ctx.is_killed(&analyzer));
+    assert_eq!(
+        bounds(&analyzer, ctx, "a"),
+        (U256::from(70), U256::from(100))
+    );
+    assert_eq!(bounds(&analyzer, ctx, "b"), (U256::zero(), U256::from(30)));
+}
+
+#[test]
+fn test_unsatisfiable_sum_kills_context() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "impossible");
+    assert!(ctx.is_killed(&analyzer));
+}
@@ -0,0 +1,56 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::taint::{TaintAnalysis, TaintAnalyzer};
+use pyrometer::Analyzer;
+use shared::context::TaintSink;
+
+fn tainted_sinks(func_name: &str) -> Vec<TaintAnalysis> {
+    let sol = include_str!("./test_data/taint.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("taint.sol");
+    analyzer.tainted_sinks(&file_mapping, ctx)
+}
+
+#[test]
+fn test_param_delegatecall_target() {
+    let sinks = tainted_sinks("delegate");
+    assert_eq!(sinks.len(), 1);
+    assert_eq!(sinks[0].sink, TaintSink::DelegatecallTarget);
+    assert_eq!(sinks[0].source, "target");
+}
+
+#[test]
+fn test_taint_flows_through_assignment() {
+    let sinks = tainted_sinks("delegate_assigned");
+    assert_eq!(sinks.len(), 1);
+    assert_eq!(sinks[0].sink, TaintSink::DelegatecallTarget);
+    assert_eq!(sinks[0].source, "target");
+}
+
+#[test]
+fn test_storage_target_not_tainted() {
+    assert!(tainted_sinks("delegate_owner").is_empty());
+}
+
+#[test]
+fn test_internal_params_not_tainted() {
+    assert!(tainted_sinks("delegate_internal").is_empty());
+}
+
+#[test]
+fn test_taint_flows_through_op() {
+    let sinks = tainted_sinks("index");
+    assert_eq!(sinks.len(), 1);
+    assert_eq!(sinks[0].sink, TaintSink::ArrayIndex);
+    assert_eq!(sinks[0].source, "i");
+}
+
+#[test]
+fn test_call_value() {
+    let sinks = tainted_sinks("send_value");
+    assert_eq!(sinks.len(), 1);
+    assert_eq!(sinks[0].sink, TaintSink::CallValue);
+    assert_eq!(sinks[0].source, "amount");
+}
@@ -0,0 +1,39 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::indexes::OutOfBoundsIndexAnalyzer;
+use pyrometer::Analyzer;
+
+const SOL: &str = include_str!("./test_data/ternary_index.sol");
+
+fn out_of_bounds_count(func_name: &str) -> usize {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, func_name);
+    let file_mapping = file_mapping("ternary_index.sol");
+    analyzer.out_of_bounds_indexes(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_out_of_bounds_branch_flagged() {
+    assert_eq!(out_of_bounds_count("one_out_of_bounds"), 1);
+}
+
+#[test]
+fn test_out_of_bounds_branch_killed() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "one_out_of_bounds");
+    let forks = ctx.underlying(&analyzer).forks.clone();
+    assert_eq!(forks.len(), 2);
+    assert!(!forks[0].is_killed(&analyzer));
+    assert!(forks[1].is_killed(&analyzer));
+}
+
+#[test]
+fn test_in_bounds_branches_not_flagged() {
+    assert_eq!(out_of_bounds_count("both_in_bounds"), 0);
+}
+
+#[test]
+fn test_unbounded_length_not_flagged() {
+    assert_eq!(out_of_bounds_count("unbounded"), 0);
+}
@@ -0,0 +1,32 @@
+interface IToken {
+    function transfer(address to, uint256 amount) external returns (bool);
+}
+
+contract AbiDecode {
+    struct Pair {
+        uint8 x;
+        bool flag;
+    }
+
+    function decode(bytes memory data) public {
+        (uint256 a, bool b) = abi.decode(data, (uint256, bool));
+    }
+
+    function decode_small(bytes memory data) public {
+        (uint8 a, address b) = abi.decode(data, (uint8, address));
+    }
+
+    function decode_struct(bytes memory data) public {
+        Pair memory p = abi.decode(data, (Pair));
+        uint8 a = p.x;
+        bool b = p.flag;
+    }
+
+    function decode_struct_field(bytes memory data) public {
+        uint8 a = abi.decode(data, (Pair)).x;
+    }
+
+    function encode_call(address to, uint256 amount) public {
+        bytes memory data = abi.encodeCall(IToken.transfer, (to, amount));
+    }
+}
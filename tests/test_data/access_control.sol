@@ -0,0 +1,37 @@
+contract AccessControl {
+    address owner;
+    uint256 stored;
+
+    modifier onlyOwner() {
+        require(msg.sender == owner);
+        _;
+    }
+
+    function guarded(uint256 x) public {
+        require(msg.sender == owner);
+        stored = x;
+    }
+
+    function reversed(uint256 x) public {
+        require(owner == msg.sender);
+        stored = x;
+    }
+
+    function known_owner() public {
+        require(owner == address(1));
+        require(msg.sender == owner);
+    }
+
+    function setOwner(address newOwner) public {
+        owner = newOwner;
+    }
+
+    function setOwnerGuarded(address newOwner) public {
+        require(msg.sender == owner);
+        owner = newOwner;
+    }
+
+    function setOwnerOnlyOwner(address newOwner) public onlyOwner {
+        owner = newOwner;
+    }
+}
@@ -0,0 +1,22 @@
+contract AddressCast {
+    function full(uint256 x) public pure {
+        uint160 y = uint160(x);
+        address a = address(y);
+        address b = address(uint160(x));
+    }
+
+    function constant_value() public pure {
+        uint256 x = 2**160 + 5;
+        address a = address(uint160(x));
+    }
+
+    function bounded(uint256 x) public pure {
+        require(x >= 2**160);
+        require(x <= 2**160 + 10);
+        address a = address(uint160(x));
+    }
+
+    function round_trip(address a) public pure {
+        address b = address(uint160(a));
+    }
+}
@@ -0,0 +1,43 @@
+contract Balance {
+    function over_transfer(address payable to) public {
+        require(address(this).balance <= 100);
+        to.transfer(200);
+    }
+
+    function over_send(address payable to) public returns (bool) {
+        require(address(this).balance <= 100);
+        return to.send(200);
+    }
+
+    function in_range(address payable to) public {
+        require(address(this).balance == 100);
+        to.transfer(30);
+        require(address(this).balance == 70);
+    }
+
+    function unknown_balance(address payable to, uint256 amount) public {
+        payable(to).transfer(amount);
+    }
+
+    function ternary_amount(address payable to, bool c) public {
+        require(address(this).balance == 100);
+        to.transfer(c ? 30 : 200);
+    }
+
+    function deposit() public payable {
+        require(msg.value == 10);
+        require(address(this).balance >= 10);
+    }
+
+    function strict_eq(uint256 expected) public {
+        require(address(this).balance == expected);
+    }
+
+    function at_least(uint256 expected) public {
+        require(address(this).balance >= expected);
+    }
+
+    function nonzero() public {
+        require(address(this).balance != 0);
+    }
+}
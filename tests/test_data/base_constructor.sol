@@ -0,0 +1,20 @@
+contract Base {
+    uint256 val;
+
+    constructor(uint256 x) {
+        require(x > 10);
+        val = x;
+    }
+}
+
+contract TooSmall is Base(5) {
+    constructor() {
+        uint256 y = 1;
+    }
+}
+
+contract LargeEnough is Base(20) {
+    constructor() {
+        uint256 y = 1;
+    }
+}
@@ -0,0 +1,17 @@
+contract Bitfield {
+    function extract(uint256 x) public returns (uint256) {
+        uint256 field = (x >> 8) & 0xff;
+        return field;
+    }
+
+    function mask_past_max(uint256 x) public returns (uint256) {
+        require(x <= 0x100);
+        uint256 low = x & 0xff;
+        return low;
+    }
+
+    function mask_const() public returns (uint256) {
+        uint256 val = 0x1234 & 0xff;
+        return val;
+    }
+}
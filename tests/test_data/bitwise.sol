@@ -155,3 +155,29 @@ contract Bitwise {
         require(a20 == -2);
     }
 }
+
+contract ShiftAssign {
+    function shl_assign_masked() public {
+        uint8 x = 0x80;
+        x <<= 1;
+        require(x == 0);
+    }
+
+    function shl_assign_partial_mask() public {
+        uint8 x = 0x81;
+        x <<= 1;
+        require(x == 2);
+    }
+
+    function shl_assign_past_width() public {
+        uint8 x = 0xff;
+        x <<= 8;
+        require(x == 0);
+    }
+
+    function shr_assign() public {
+        uint8 x = 0x80;
+        x >>= 7;
+        require(x == 1);
+    }
+}
@@ -0,0 +1,67 @@
+interface IOracle {
+    function price() external returns (uint256);
+}
+
+contract BranchBudget {
+    function nested(uint256 x) public returns (uint256) {
+        uint256 y;
+        if (x > 1) {
+            if (x > 2) {
+                if (x > 3) {
+                    if (x > 4) {
+                        if (x > 5) {
+                            y = 5;
+                        } else {
+                            y = 4;
+                        }
+                    } else {
+                        y = 3;
+                    }
+                } else {
+                    y = 2;
+                }
+            } else {
+                y = 1;
+            }
+        }
+        return y;
+    }
+
+    function single(uint256 x) public returns (uint256) {
+        uint256 y;
+        if (x > 1) {
+            y = 1;
+        }
+        return y;
+    }
+
+    function looped(uint256 x) public returns (uint256) {
+        uint256 y = 1;
+        for (uint256 i; i < x; i++) {
+            y = 2;
+        }
+        return y;
+    }
+
+    function try_single(IOracle oracle) public returns (uint256) {
+        uint256 y;
+        try oracle.price() returns (uint256 p) {
+            y = 1;
+        } catch {
+            y = 2;
+        }
+        return y;
+    }
+
+    function try_many(IOracle oracle) public returns (uint256) {
+        uint256 y;
+        try oracle.price() returns (uint256 p) {
+            y = 1;
+        } catch Error(string memory reason) {
+            y = 2;
+        } catch (bytes memory data) {
+            y = 3;
+        }
+        return y;
+    }
+}
@@ -0,0 +1,38 @@
+contract BranchJoin {
+    function both_modified(uint256 x) public returns (uint256) {
+        uint256 y;
+        if (x > 10) {
+            y = 1;
+        } else {
+            y = 2;
+        }
+        return y;
+    }
+
+    function one_modified(uint256 x) public returns (uint256) {
+        uint256 y = 5;
+        if (x > 10) {
+            y = 7;
+        }
+        return y;
+    }
+
+    function killed_branch(uint256 x) public returns (uint256) {
+        uint256 y = 1;
+        if (x > 10) {
+            revert();
+        } else {
+            y = 3;
+        }
+        return y;
+    }
+
+    function impossible_branch(uint256 x) public returns (uint256) {
+        require(x > 20);
+        uint256 y = 1;
+        if (x > 10) {
+            y = 2;
+        }
+        return y;
+    }
+}
@@ -0,0 +1,17 @@
+contract BytesIndex {
+    function first(bytes memory b) public pure returns (bytes1) {
+        require(b.length > 0);
+        return b[0];
+    }
+
+    function symbolic(bytes memory b, uint256 i) public pure returns (bytes1) {
+        require(b.length > 0);
+        require(b.length <= 32);
+        return b[i];
+    }
+
+    function empty(bytes memory b) public pure returns (bytes1) {
+        require(b.length == 0);
+        return b[0];
+    }
+}
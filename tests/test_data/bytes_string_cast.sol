@@ -0,0 +1,13 @@
+contract BytesStringCast {
+    function packed(uint256 y) public {
+        string memory s = string(abi.encodePacked("x", y));
+    }
+
+    function packed_const() public {
+        string memory s = string(abi.encodePacked("x", "y"));
+    }
+
+    function to_bytes() public {
+        bytes memory b = bytes("abc");
+    }
+}
@@ -0,0 +1,44 @@
+contract Casts {
+    function safe_narrowing(uint256 x) public returns (uint128) {
+        require(x <= type(uint128).max);
+        uint128 y = uint128(x);
+        return y;
+    }
+
+    function safe_literal_bound(uint256 x) public returns (uint8) {
+        require(x < 256);
+        return uint8(x);
+    }
+
+    function unsafe_narrowing(uint256 x) public returns (uint128) {
+        uint128 y = uint128(x);
+        return y;
+    }
+
+    function unsafe_loose_bound(uint256 x) public returns (uint8) {
+        require(x <= 256);
+        return uint8(x);
+    }
+
+    function widening(uint8 x) public returns (uint256) {
+        return uint256(x);
+    }
+
+    function signed_to_unsigned(int256 x) public returns (uint256) {
+        return uint256(x);
+    }
+
+    function nonneg_signed_to_unsigned(int256 x) public returns (uint256) {
+        require(x >= 0);
+        return uint256(x);
+    }
+
+    function unsigned_to_signed(uint256 x) public returns (int256) {
+        return int256(x);
+    }
+
+    function bounded_unsigned_to_signed(uint256 x) public returns (int256) {
+        require(x <= 100);
+        return int256(x);
+    }
+}
@@ -0,0 +1,54 @@
+contract Token {
+    uint256 public supply;
+
+    function mint(uint256 x) public {
+        supply += x;
+    }
+}
+
+contract Vault {
+    uint256 balance;
+    mapping(address => uint256) balances;
+
+    function write_after_call(uint256 x) public {
+        Token(address(100)).mint(x);
+        balance = x;
+    }
+
+    function write_before_call(uint256 x) public {
+        balance = x;
+        Token(address(100)).mint(x);
+    }
+
+    function separate_branches(uint256 x) public {
+        if (x > 10) {
+            Token(address(100)).mint(x);
+        } else {
+            balance = x;
+        }
+    }
+
+    function write_after_transfer(uint256 x) public {
+        payable(msg.sender).transfer(x);
+        balance = 0;
+    }
+
+    function write_before_transfer(uint256 x) public {
+        balance = 0;
+        payable(msg.sender).transfer(x);
+    }
+
+    function write_after_low_level_call() public {
+        uint256 bal = balances[msg.sender];
+        (bool ok, ) = msg.sender.call{value: bal}("");
+        require(ok);
+        balances[msg.sender] = 0;
+    }
+
+    function write_before_low_level_call() public {
+        uint256 bal = balances[msg.sender];
+        balances[msg.sender] = 0;
+        (bool ok, ) = msg.sender.call{value: bal}("");
+        require(ok);
+    }
+}
@@ -0,0 +1,14 @@
+interface IERC20 {
+    function balanceOf(address owner) external returns (uint256);
+}
+
+interface IWrapper {
+    function underlying() external returns (IERC20);
+}
+
+contract ChainedCall {
+    function wrapped_balance(IWrapper wrapper, address user) public returns (uint256) {
+        uint256 bal = wrapper.underlying().balanceOf(user);
+        return bal;
+    }
+}
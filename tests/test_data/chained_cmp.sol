@@ -0,0 +1,19 @@
+contract ChainedCmp {
+    function chained_require(uint256 x) public pure {
+        require(0 < x < 10);
+    }
+
+    function chained_expr(uint256 x) public pure returns (bool) {
+        bool inRange = 0 < x < 10;
+        return inRange;
+    }
+
+    function combined(uint256 x) public pure {
+        require(0 < x && x < 10);
+    }
+
+    function bool_eq(uint256 x) public pure {
+        bool positive = x > 0;
+        require(positive == true);
+    }
+}
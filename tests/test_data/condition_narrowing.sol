@@ -0,0 +1,17 @@
+contract ConditionNarrowing {
+    function and_require(uint256 a, uint256 b) public {
+        require(a > 10 && (b < 5 && !(a >= 100)));
+    }
+
+    function and_if(uint256 a, uint256 b) public {
+        if (a > 10 && (b < 5 && !(a >= 100))) {} else {}
+    }
+
+    function not_or_require(uint256 a, uint256 b) public {
+        require(!(a < 10 || b > 20));
+    }
+
+    function not_or_if(uint256 a, uint256 b) public {
+        if (!(a < 10 || b > 20)) {} else {}
+    }
+}
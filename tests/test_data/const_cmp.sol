@@ -0,0 +1,18 @@
+contract ConstCmp {
+    function tautology() public {
+        bool b = 5 > 3;
+        require(b);
+    }
+
+    function contradiction() public {
+        bool b = 3 > 5;
+        require(b);
+    }
+
+    function mixed() public {
+        bool lte = 3 <= 3;
+        bool gte = 3 >= 5;
+        bool eq = 4 == 4;
+        bool neq = 4 != 4;
+    }
+}
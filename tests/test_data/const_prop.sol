@@ -0,0 +1,17 @@
+contract ConstProp {
+    function chain() public {
+        uint256 x = 5;
+        uint256 y = x + 1;
+        uint256 z = y;
+        uint256 w = z * 2;
+        require(w == 12);
+    }
+
+    function reassign() public {
+        uint256 x = 5;
+        x = x + 1;
+        x = x + 1;
+        x = x + 1;
+        require(x == 8);
+    }
+}
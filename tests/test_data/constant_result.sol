@@ -0,0 +1,18 @@
+contract ConstantResult {
+    function times_zero(uint256 x) public returns (uint256) {
+        return x * 0;
+    }
+
+    function mask_zero(uint256 x) public returns (uint256) {
+        return x & 0;
+    }
+
+    function literal() public returns (uint256) {
+        uint256 y = 2 * 3;
+        return y;
+    }
+
+    function varying(uint256 x) public returns (uint256) {
+        return x * 2;
+    }
+}
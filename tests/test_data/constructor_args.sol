@@ -0,0 +1,13 @@
+contract Capped {
+    uint256 immutable cap;
+
+    constructor(uint256 _cap) {
+        require(_cap <= 100);
+        cap = _cap;
+    }
+
+    function readCap() public returns (uint256) {
+        uint256 c = cap;
+        return c;
+    }
+}
@@ -0,0 +1,13 @@
+contract ContextParent {
+    function inner(uint256 x) internal returns (uint256) {
+        if (x > 5) {
+            return 1;
+        } else {
+            return 0;
+        }
+    }
+
+    function outer(uint256 x) public returns (uint256) {
+        return inner(x);
+    }
+}
@@ -0,0 +1,15 @@
+contract Child {
+    uint256 x;
+}
+
+contract Factory {
+    function creation() public returns (bytes memory) {
+        bytes memory code = type(Child).creationCode;
+        return code;
+    }
+
+    function runtime() public returns (bytes memory) {
+        bytes memory code = type(Child).runtimeCode;
+        return code;
+    }
+}
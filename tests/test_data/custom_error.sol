@@ -0,0 +1,17 @@
+contract CustomError {
+    error InsufficientBalance(uint256 have, uint256 want);
+
+    uint256 balance;
+
+    function withdraw(uint256 want) public {
+        uint256 have = balance;
+        if (have < want) {
+            revert InsufficientBalance(have, want - have);
+        }
+        balance = have - want;
+    }
+
+    function named(uint256 want) public {
+        revert InsufficientBalance({have: balance, want: want + 1});
+    }
+}
@@ -0,0 +1,15 @@
+contract First {
+    error Failed(uint256 code);
+
+    function fail(uint256 code) public {
+        revert Failed(code);
+    }
+}
+
+contract Second {
+    error Failed(address who, bool retry);
+
+    function failWith(address who) public {
+        revert Failed(who, true);
+    }
+}
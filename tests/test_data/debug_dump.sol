@@ -0,0 +1,5 @@
+contract Dump {
+    function f(uint8 x) public {
+        uint8 y = 5;
+    }
+}
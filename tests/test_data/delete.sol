@@ -0,0 +1,18 @@
+contract Delete {
+    uint256[] stored;
+
+    function delete_elem(uint256[] memory arr, uint256 i) public {
+        require(arr.length == 3);
+        arr[i] = 5;
+        delete arr[i];
+    }
+
+    function delete_all() public {
+        require(stored.length == 3);
+        delete stored;
+    }
+
+    function delete_value(int256 x) public {
+        delete x;
+    }
+}
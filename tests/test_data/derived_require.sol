@@ -0,0 +1,17 @@
+contract DerivedRequire {
+    function add_lhs(uint256 a) public {
+        require(a + 1 > 10);
+    }
+
+    function add_rhs(uint256 a) public {
+        require(10 < a + 1);
+    }
+
+    function sub_const(uint256 a) public {
+        require(a - 5 <= 20);
+    }
+
+    function const_sub(uint256 a) public {
+        require(100 - a >= 40);
+    }
+}
@@ -6,4 +6,24 @@ contract Env {
     function msg_data() public returns (bytes memory) {
         return msg.data;
     }
+
+    function block_chainid() public returns (uint256) {
+        return block.chainid;
+    }
+
+    function block_basefee() public returns (uint256) {
+        return block.basefee;
+    }
+
+    function block_coinbase() public returns (address) {
+        return block.coinbase;
+    }
+
+    function gas_left() public returns (uint256) {
+        return gasleft();
+    }
+
+    function block_hash(uint256 n) public returns (bytes32) {
+        return blockhash(n);
+    }
 }
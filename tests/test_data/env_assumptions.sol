@@ -0,0 +1,13 @@
+contract EnvAssumptions {
+    function no_value() public payable {
+        require(msg.value == 0);
+    }
+
+    function some_value() public payable {
+        require(msg.value > 0);
+    }
+
+    function after_time() public {
+        require(block.timestamp >= 100);
+    }
+}
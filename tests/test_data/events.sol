@@ -0,0 +1,54 @@
+contract Events {
+    event Small(uint8 indexed x, uint256 y);
+
+    function overflows(uint256 a) public {
+        require(a <= 300);
+        emit Small(a + 1, a);
+    }
+
+    function fits(uint256 a) public {
+        require(a < 100);
+        emit Small(a + 1, a);
+    }
+
+    function literal() public {
+        emit Small(5, 1000);
+    }
+}
+
+contract Other {
+    event Moved(uint8 x);
+}
+
+contract Base {
+    event Moved(uint256 x);
+}
+
+contract Child is Base {
+    function inherited(uint256 a) public {
+        require(a <= 1000);
+        emit Moved(a);
+    }
+}
+
+contract Overloads {
+    event Log(uint8 x, uint8 y);
+    event Log(uint256 x);
+    event Pair(uint8 x, uint256 y);
+    event Pair(uint256 x, address y);
+
+    function by_arity(uint256 a) public {
+        require(a <= 1000);
+        emit Log(a);
+    }
+
+    function by_types(uint256 a) public {
+        require(a <= 1000);
+        emit Pair(a, address(this));
+    }
+
+    function overload_overflows(uint256 a) public {
+        require(a <= 1000);
+        emit Log(a, a);
+    }
+}
@@ -0,0 +1,17 @@
+contract ExitRanges {
+    function branching(uint256 x) public returns (uint256) {
+        uint256 y = 0;
+        if (x > 10) {
+            y = 5;
+        } else {
+            y = 20;
+        }
+        return y;
+    }
+
+    function straight(uint8 a) public returns (uint8) {
+        require(a < 100);
+        uint8 b = a + 1;
+        return b;
+    }
+}
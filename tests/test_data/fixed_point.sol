@@ -0,0 +1,8 @@
+contract FixedPoint {
+    ufixed stored;
+
+    function declare() public {
+        ufixed8x1 x;
+        fixed y;
+    }
+}
@@ -0,0 +1,13 @@
+interface IThing {
+    function thing() external;
+}
+
+contract Single {
+    uint256 public total;
+
+    function add(uint256 x) public returns (uint256 y) {
+        require(x < 10);
+        y = x + 1;
+        total = y;
+    }
+}
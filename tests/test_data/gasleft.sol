@@ -0,0 +1,11 @@
+contract GasLeft {
+    function two_reads() public view {
+        uint256 g1 = gasleft();
+        uint256 g2 = gasleft();
+    }
+
+    function bounded_read() public view {
+        require(gasleft() < 100);
+        uint256 g = gasleft();
+    }
+}
@@ -0,0 +1,12 @@
+contract Getters {
+    uint256 public value;
+    uint256[] public values;
+
+    function readValue() public view {
+        uint256 x = value();
+    }
+
+    function readExternal(Getters other) public view {
+        uint256 y = other.value();
+    }
+}
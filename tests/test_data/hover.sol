@@ -0,0 +1,14 @@
+contract Hover {
+    function outer(uint256 x) public returns (uint256) {
+        uint256 a = x;
+        if (x > 5) {
+            if (x > 10) {
+                uint256 deep = x;
+                return deep;
+            }
+        }
+        return a;
+    }
+
+    function other() public {}
+}
@@ -0,0 +1,80 @@
+contract InfiniteLoop {
+    function while_true() public {
+        uint256 x;
+        while (true) {
+            x += 1;
+        }
+    }
+
+    function for_ever() public {
+        uint256 x;
+        for (;;) {
+            x += 1;
+        }
+    }
+
+    function while_true_break() public {
+        uint256 x;
+        while (true) {
+            x += 1;
+            if (x > 10) {
+                break;
+            }
+        }
+    }
+
+    function while_true_return() public returns (uint256) {
+        uint256 x;
+        while (true) {
+            x += 1;
+            return x;
+        }
+    }
+
+    function while_true_nested_break() public {
+        uint256 x;
+        while (true) {
+            for (uint256 i; i < 10; i++) {
+                break;
+            }
+            x += 1;
+        }
+    }
+
+    function while_cond(uint256 x) public {
+        while (x > 10) {
+            x -= 1;
+        }
+    }
+
+    function for_ever_with_init() public {
+        uint256 x;
+        for (uint256 i; true; ) {
+            x += 1;
+        }
+    }
+
+    function for_counter() public {
+        uint256 x;
+        for (uint256 i; i < 10; i++) {
+            x += 1;
+        }
+    }
+
+    function while_flag() public {
+        uint256 x;
+        bool running = true;
+        while (running) {
+            x += 1;
+        }
+    }
+
+    function while_flag_cleared() public {
+        uint256 x;
+        bool running = true;
+        while (running) {
+            x += 1;
+            running = false;
+        }
+    }
+}
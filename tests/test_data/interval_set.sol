@@ -0,0 +1,32 @@
+contract IntervalSet {
+    function add_excl(uint256 x) public returns (uint256) {
+        require(x != 5);
+        uint256 y = x + 1;
+        return y;
+    }
+
+    function sub_excl(uint256 x) public returns (uint256) {
+        require(x > 10);
+        require(x != 15);
+        uint256 y = x - 1;
+        return y;
+    }
+
+    function mul_excl(uint256 x) public returns (uint256) {
+        require(x != 5);
+        uint256 y = x * 2;
+        return y;
+    }
+
+    function div_excl(uint256 x) public returns (uint256) {
+        require(x != 5);
+        uint256 y = x / 2;
+        return y;
+    }
+
+    function mod_excl(uint256 x) public returns (uint256) {
+        require(x != 5);
+        uint256 y = x % 10;
+        return y;
+    }
+}
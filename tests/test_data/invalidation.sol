@@ -0,0 +1,6 @@
+contract Invalidation {
+    function dependent(uint256 x) public {
+        require(x < 100);
+        uint256 y = x + 1;
+    }
+}
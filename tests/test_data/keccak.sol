@@ -0,0 +1,11 @@
+contract Keccak {
+    function literal_hash() public returns (bytes32) {
+        bytes32 h = keccak256(abi.encodePacked("hello"));
+        return h;
+    }
+
+    function symbolic_hash(string memory s) public returns (bytes32) {
+        bytes32 h = keccak256(abi.encodePacked(s));
+        return h;
+    }
+}
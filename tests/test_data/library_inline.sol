@@ -0,0 +1,47 @@
+library SafeMath {
+    function sub(uint256 a, uint256 b) internal pure returns (uint256) {
+        require(b <= a);
+        return a - b;
+    }
+}
+
+library Counters {
+    struct Counter {
+        uint256 value;
+    }
+
+    function increment(Counter storage counter) internal {
+        counter.value += 1;
+    }
+}
+
+contract LibraryInline {
+    using SafeMath for uint256;
+    using Counters for Counters.Counter;
+
+    Counters.Counter c;
+
+    function sub_conc() public returns (uint256) {
+        uint256 x = 100;
+        uint256 y = 30;
+        uint256 ret = x.sub(y);
+        return ret;
+    }
+
+    function sub_lib_call() public returns (uint256) {
+        uint256 x = 100;
+        uint256 y = 30;
+        uint256 ret = SafeMath.sub(x, y);
+        return ret;
+    }
+
+    function sub_require(uint256 b) public returns (uint256) {
+        uint256 a = 100;
+        uint256 ret = a.sub(b);
+        return ret;
+    }
+
+    function inc() public {
+        c.increment();
+    }
+}
@@ -0,0 +1,3 @@
+contract LiteralTy {
+    function f() public {}
+}
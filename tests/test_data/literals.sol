@@ -0,0 +1,15 @@
+contract Literals {
+    function boundary() public pure {
+        uint256 max = 115792089237316195423570985008687907853269984665640564039457584007913129639935;
+        uint256 below_max = max - 1;
+        uint256 sci = 1e18;
+        uint256 big_sci = 1e77;
+        uint256 scaled = 25e17;
+        uint256 separated = 1_000_000;
+    }
+
+    function negative_exponent() public pure returns (uint256) {
+        uint256 x = 25e-1 * 10;
+        return x;
+    }
+}
@@ -0,0 +1,29 @@
+contract LoopCounterOverflow {
+    function strict() public {
+        uint256 sum;
+        for (uint8 i; i < 256; i++) {
+            sum += i;
+        }
+    }
+
+    function inclusive() public {
+        uint256 sum;
+        for (uint8 i; i <= 255; ++i) {
+            sum += i;
+        }
+    }
+
+    function in_range() public {
+        uint256 sum;
+        for (uint8 i; i < 255; i++) {
+            sum += i;
+        }
+    }
+
+    function wide_counter() public {
+        uint256 sum;
+        for (uint256 i; i < 256; i++) {
+            sum += i;
+        }
+    }
+}
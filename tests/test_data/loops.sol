@@ -17,4 +17,10 @@ contract For {
         require(x == 10);
         return x;
     }
+
+    function multi_update() public {
+        uint8 i = 5;
+        uint8 j = 10;
+        for (; i < j; (i++, j--)) {}
+    }
 }
@@ -529,3 +529,34 @@ contract Math {
         return x ** y;
     }
 }
+
+contract IntervalSet {
+    function add_excl(uint256 x) public returns (uint256) {
+        require(x != 5);
+        uint256 y = x + 1;
+        require(y != 6);
+        return y;
+    }
+
+    function sub_excl(uint256 x) public returns (uint256) {
+        require(x > 10);
+        require(x != 15);
+        uint256 y = x - 1;
+        require(y != 14);
+        return y;
+    }
+
+    function div_excl(uint256 x) public returns (uint256) {
+        require(x != 5);
+        uint256 y = x / 2;
+        require(y == 2);
+        return y;
+    }
+
+    function mod_excl(uint256 x) public returns (uint256) {
+        require(x != 5);
+        uint256 y = x % 10;
+        require(y == 5);
+        return y;
+    }
+}
@@ -0,0 +1,27 @@
+library Math {
+    function min(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a < b ? a : b;
+    }
+
+    function max(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a >= b ? a : b;
+    }
+}
+
+contract MinMax {
+    function min(uint256 a, uint256 b) public returns (uint256) {
+        require(a <= 10);
+        require(b >= 5);
+        require(b <= 20);
+        uint256 ret = Math.min(a, b);
+        return ret;
+    }
+
+    function max(uint256 a, uint256 b) public returns (uint256) {
+        require(a <= 10);
+        require(b >= 5);
+        require(b <= 20);
+        uint256 ret = Math.max(a, b);
+        return ret;
+    }
+}
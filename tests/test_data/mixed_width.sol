@@ -0,0 +1,34 @@
+contract MixedWidth {
+    function widen_lhs(uint8 a, uint256 b) public {
+        require(a == 200);
+        require(b == 100);
+        uint256 c = a + b;
+        require(c == 300);
+    }
+
+    function widen_rhs(uint256 b, uint8 a) public {
+        require(a == 200);
+        require(b == 100);
+        uint256 c = b + a;
+        require(c == 300);
+    }
+
+    function widen_mul(uint8 a, uint16 b) public {
+        require(a == 255);
+        require(b == 2);
+        uint16 c = a * b;
+        require(c == 510);
+    }
+
+    function widen_symbolic(uint8 a, uint256 b) public {
+        require(b == 1000);
+        uint256 c = a + b;
+    }
+
+    function shift_keeps_lhs(uint8 a, uint256 b) public {
+        require(a == 1);
+        require(b == 2);
+        uint8 c = a << b;
+        require(c == 4);
+    }
+}
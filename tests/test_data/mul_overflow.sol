@@ -0,0 +1,19 @@
+contract MulOverflow {
+    function near_max_overflow() public {
+        uint256 a = 2**255;
+        uint256 b = 2;
+        uint256 c = a * b;
+    }
+
+    function near_max_no_overflow() public {
+        uint256 a = 2**128 - 1;
+        uint256 b = 2**128 + 1;
+        uint256 c = a * b;
+    }
+
+    function narrow_overflow() public {
+        uint8 a = 16;
+        uint8 b = 16;
+        uint8 c = a * b;
+    }
+}
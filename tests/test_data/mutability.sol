@@ -0,0 +1,19 @@
+contract Mutability {
+    uint256 storageVar;
+
+    function view_writes(uint256 x) public view {
+        storageVar = x;
+    }
+
+    function view_reads() public view returns (uint256) {
+        return storageVar;
+    }
+
+    function pure_reads() public pure returns (uint256) {
+        return storageVar;
+    }
+
+    function pure_math(uint256 x) public pure returns (uint256) {
+        return x + 1;
+    }
+}
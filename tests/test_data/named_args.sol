@@ -0,0 +1,11 @@
+contract NamedArgs {
+    function args_after_fork(bool c) public {
+        uint256 x;
+        if (c) {
+            x = 1;
+        } else {
+            x = 2;
+        }
+        { value: x++ }
+    }
+}
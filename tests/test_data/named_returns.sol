@@ -0,0 +1,18 @@
+contract NamedReturns {
+    function bare_return(uint256 x) public returns (uint256 result) {
+        require(x < 10);
+        result = x + 5;
+        return;
+    }
+
+    function implicit_return(uint256 x) public returns (uint256 result) {
+        require(x < 10);
+        result = x * 2;
+    }
+
+    function caller(uint256 x) public returns (uint256) {
+        require(x < 10);
+        uint256 y = implicit_return(x);
+        return y;
+    }
+}
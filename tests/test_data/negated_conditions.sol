@@ -0,0 +1,17 @@
+contract NegatedConditions {
+    function not_var(bool done) public {
+        if (!done) {} else {}
+    }
+
+    function not_and(uint256 x, uint256 y) public {
+        if (!(x > 10 && y > 10)) {} else {}
+    }
+
+    function not_or(uint256 x, uint256 y) public {
+        if (!(x > 10 || y > 10)) {} else {}
+    }
+
+    function double_not(uint256 x) public {
+        if (!!(x > 10)) {} else {}
+    }
+}
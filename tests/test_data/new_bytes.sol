@@ -0,0 +1,18 @@
+contract NewBytes {
+    function alloc_bytes(uint256 len) public {
+        require(len <= 32);
+        bytes memory b = new bytes(len);
+        uint256 l = b.length;
+    }
+
+    function alloc_string(uint256 len) public {
+        require(len >= 4);
+        require(len <= 8);
+        string memory s = new string(len);
+    }
+
+    function alloc_const() public {
+        bytes memory b = new bytes(20);
+        uint256 l = b.length;
+    }
+}
@@ -0,0 +1,21 @@
+contract NonConstRequire {
+    function gt(uint256 a, uint256 b) public {
+        require(a <= 10);
+        require(a > b);
+    }
+
+    function gte(uint256 a, uint256 b) public {
+        require(a <= 10);
+        require(a >= b);
+    }
+
+    function lt(uint256 a, uint256 b) public {
+        require(a >= 10);
+        require(a < b);
+    }
+
+    function lte(uint256 a, uint256 b) public {
+        require(a >= 10);
+        require(a <= b);
+    }
+}
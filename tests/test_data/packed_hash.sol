@@ -0,0 +1,16 @@
+contract PackedHash {
+    function two_strings(string memory a, string memory b) public pure returns (bytes32) {
+        bytes32 h = keccak256(abi.encodePacked(a, b));
+        return h;
+    }
+
+    function string_and_uint(string memory a, uint256 b) public pure returns (bytes32) {
+        bytes32 h = keccak256(abi.encodePacked(a, b));
+        return h;
+    }
+
+    function encoded_strings(string memory a, string memory b) public pure returns (bytes32) {
+        bytes32 h = keccak256(abi.encode(a, b));
+        return h;
+    }
+}
@@ -0,0 +1,16 @@
+contract PayableSender {
+    function withdraw(uint256 x) public {
+        require(address(this).balance == 100);
+        require(x <= 40);
+        payable(msg.sender).transfer(x);
+        require(address(this).balance >= 60);
+    }
+
+    function withdraw_all() public {
+        payable(msg.sender).transfer(address(this).balance);
+    }
+
+    function try_withdraw(uint256 x) public returns (bool) {
+        return payable(msg.sender).send(x);
+    }
+}
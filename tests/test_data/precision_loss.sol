@@ -0,0 +1,17 @@
+contract PrecisionLoss {
+    function div_then_mul(uint256 a, uint256 b, uint256 c) public pure returns (uint256) {
+        return a / b * c;
+    }
+
+    function mul_then_div(uint256 a, uint256 b, uint256 c) public pure returns (uint256) {
+        return a * c / b;
+    }
+
+    function exact_const_div(uint256 c) public pure returns (uint256) {
+        return 100 / 4 * c;
+    }
+
+    function inexact_const_div(uint256 c) public pure returns (uint256) {
+        return 10 / 4 * c;
+    }
+}
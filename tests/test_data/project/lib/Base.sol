@@ -0,0 +1,7 @@
+import "../main.sol";
+
+contract Base {
+    function double(uint256 x) internal returns (uint256) {
+        return x * 2;
+    }
+}
@@ -0,0 +1,5 @@
+library Math {
+    function add(uint256 a, uint256 b) internal returns (uint256) {
+        return a + b;
+    }
+}
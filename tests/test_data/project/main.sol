@@ -0,0 +1,9 @@
+import "./lib/Base.sol";
+import "@math/Math.sol";
+
+contract Main is Base {
+    function use_base() public {
+        uint256 y = double(5);
+        uint256 z = Math.add(y, 1);
+    }
+}
@@ -0,0 +1,8 @@
+import "./lib/Base.sol";
+import "./lib/Missing.sol";
+
+contract UsesMissing is Base {
+    function use_base() public {
+        uint256 y = double(5);
+    }
+}
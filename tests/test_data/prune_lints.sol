@@ -0,0 +1,43 @@
+contract PruneLints {
+    event Small(uint8 indexed x, uint256 y);
+
+    uint256[] values;
+
+    function constant_result(uint256 x) public returns (uint256) {
+        return x * 0;
+    }
+
+    function underflow(uint256 x, uint256 y) public returns (uint256) {
+        if (x > 10) {
+            return x - y;
+        }
+        return 0;
+    }
+
+    function gas() public returns (uint256) {
+        uint256 first = gasleft();
+        uint256 second = gasleft();
+        return first - second;
+    }
+
+    function sum(uint256 x, uint256 y) public returns (uint256) {
+        require(x + y <= 100);
+        return x;
+    }
+
+    function event_overflow(uint256 a) public {
+        require(a <= 300);
+        emit Small(a + 1, a);
+    }
+
+    function tainted_index(uint256 i) public returns (uint256) {
+        return values[i + 1];
+    }
+
+    function counter_overflow() public {
+        uint256 total;
+        for (uint8 i; i < 256; i++) {
+            total += i;
+        }
+    }
+}
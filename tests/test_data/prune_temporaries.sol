@@ -0,0 +1,13 @@
+contract PruneTemporaries {
+    function folded() public returns (uint256) {
+        uint256 a = 2 + 3;
+        uint256 b = a * 4;
+        uint256 c = (b - 1) + (b - 2);
+        return c;
+    }
+
+    function symbolic(uint256 x) public returns (uint256) {
+        uint256 y = x / 2;
+        return y + 1;
+    }
+}
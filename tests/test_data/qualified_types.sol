@@ -0,0 +1,32 @@
+library Lib {
+    struct Point {
+        uint256 x;
+        uint256 y;
+    }
+
+    enum Kind {
+        A,
+        B
+    }
+}
+
+contract UsesLib {
+    function declare_struct() public {
+        Lib.Point memory p;
+        p.x = 5;
+    }
+
+    function declare_enum() public {
+        Lib.Kind k;
+    }
+
+    function declare_later() public {
+        LaterLib.Later memory l;
+    }
+}
+
+library LaterLib {
+    struct Later {
+        uint256 z;
+    }
+}
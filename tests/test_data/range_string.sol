@@ -0,0 +1,18 @@
+contract RangeString {
+    function u_int256(uint256 x) public {
+        require(x <= 100);
+    }
+
+    function i_nt8(int8 x) public {
+        require(x >= -5);
+        require(x <= 5);
+    }
+
+    function u_int256_large(uint256 x) public {
+        require(x >= 115792089237316195423570985008687907853269984665640564039457584007913129639930);
+    }
+
+    function dyn_len(uint256[] memory arr, uint256 x) public {
+        require(x < arr.length);
+    }
+}
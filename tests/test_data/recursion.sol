@@ -0,0 +1,30 @@
+contract Recursion {
+    function factorial(uint256 n) public returns (uint256) {
+        if (n == 0) {
+            return 1;
+        }
+        return n * factorial(n - 1);
+    }
+
+    function is_even(uint256 n) public returns (bool) {
+        if (n == 0) {
+            return true;
+        }
+        return is_odd(n - 1);
+    }
+
+    function is_odd(uint256 n) public returns (bool) {
+        if (n == 0) {
+            return false;
+        }
+        return is_even(n - 1);
+    }
+
+    function not_recursive(uint256 n) public returns (uint256) {
+        return double(n);
+    }
+
+    function double(uint256 n) internal returns (uint256) {
+        return n * 2;
+    }
+}
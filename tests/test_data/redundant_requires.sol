@@ -0,0 +1,16 @@
+contract RedundantRequires {
+    function duplicate(uint256 x) public {
+        require(x > 0);
+        require(x > 0);
+    }
+
+    function implied(uint256 x) public {
+        require(x > 10);
+        require(x > 5);
+    }
+
+    function distinct(uint256 x) public {
+        require(x > 5);
+        require(x > 10);
+    }
+}
@@ -0,0 +1,60 @@
+contract Token {
+    uint256 public supply;
+
+    function mint(uint256 x) public {
+        supply += x;
+    }
+}
+
+contract Guarded {
+    bool locked;
+    uint256 balance;
+
+    modifier nonReentrant() {
+        require(!locked);
+        locked = true;
+        _;
+        locked = false;
+    }
+
+    function guarded(uint256 x) public nonReentrant {
+        Token(address(100)).mint(x);
+        balance = x;
+    }
+
+    function unguarded(uint256 x) public {
+        Token(address(100)).mint(x);
+        balance = x;
+    }
+}
+
+contract ReentrancyGuard {
+    uint256 private constant NOT_ENTERED = 1;
+    uint256 private constant ENTERED = 2;
+
+    uint256 private _status;
+
+    modifier nonReentrant() {
+        _nonReentrantBefore();
+        _;
+        _nonReentrantAfter();
+    }
+
+    function _nonReentrantBefore() private {
+        require(_status != ENTERED, "ReentrancyGuard: reentrant call");
+        _status = ENTERED;
+    }
+
+    function _nonReentrantAfter() private {
+        _status = NOT_ENTERED;
+    }
+}
+
+contract OzGuarded is ReentrancyGuard {
+    uint256 balance;
+
+    function oz_guarded(uint256 x) public nonReentrant {
+        Token(address(100)).mint(x);
+        balance = x;
+    }
+}
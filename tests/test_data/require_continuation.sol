@@ -0,0 +1,21 @@
+contract RequireContinuation {
+    uint256 stored;
+
+    function read_after(uint256 x) public returns (uint256) {
+        require(x < 10);
+        uint256 y = x + 1;
+        return y;
+    }
+
+    function storage_read_after() public returns (uint256) {
+        require(stored > 5);
+        uint256 s = stored;
+        return s;
+    }
+
+    function bool_read_after(bool flag) public returns (bool) {
+        require(flag);
+        bool f = flag;
+        return f;
+    }
+}
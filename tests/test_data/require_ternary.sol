@@ -0,0 +1,9 @@
+contract RequireTernary {
+    function per_branch(uint256 x, uint256 y) public {
+        require(x > 100 ? y > 10 : y < 5);
+    }
+
+    function impossible_branch(uint256 x) public {
+        require(x > 100 ? x < 50 : x < 5);
+    }
+}
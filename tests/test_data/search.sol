@@ -0,0 +1,7 @@
+contract Search {
+    function a(uint256 x, uint256 y) public {}
+
+    function b() public {}
+
+    function c() public {}
+}
@@ -0,0 +1,39 @@
+contract ShiftAssign {
+    function shl_assign_masked() public {
+        uint8 x = 0x80;
+        x <<= 1;
+    }
+
+    function shl_assign_partial_mask() public {
+        uint8 x = 0x81;
+        x <<= 1;
+    }
+
+    function shl_assign_past_width() public {
+        uint8 x = 0xff;
+        x <<= 8;
+    }
+
+    function shl_assign_unknown(uint8 x) public {
+        x <<= 1;
+    }
+
+    function signed_shl_assign_negative() public {
+        int8 x = -1;
+        x <<= 1;
+    }
+
+    function signed_shl_assign_into_sign_bit() public {
+        int8 x = 64;
+        x <<= 1;
+    }
+
+    function signed_shl_assign_min() public {
+        int8 x = -128;
+        x <<= 1;
+    }
+
+    function signed_shl_assign_unknown(int8 x) public {
+        x <<= 1;
+    }
+}
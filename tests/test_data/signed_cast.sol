@@ -0,0 +1,25 @@
+contract SignedCast {
+    function neg_one() public returns (uint8) {
+        uint8 x = uint8(int8(-1));
+        return x;
+    }
+
+    function negative(int8 x) public returns (uint8) {
+        require(x < 0);
+        uint8 y = uint8(x);
+        return y;
+    }
+
+    function nonnegative(int8 x) public returns (uint8) {
+        require(x >= 0);
+        uint8 y = uint8(x);
+        return y;
+    }
+
+    function straddles_zero(int8 x) public returns (uint8) {
+        require(x >= -1);
+        require(x <= 1);
+        uint8 y = uint8(x);
+        return y;
+    }
+}
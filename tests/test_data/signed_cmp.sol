@@ -0,0 +1,21 @@
+contract SignedCmp {
+    function lt_zero(int8 x) public {
+        require(x < 0);
+    }
+
+    function gte_zero(int8 x) public {
+        require(x >= 0);
+    }
+
+    function gt_neg_one(int8 x) public {
+        require(x > -1);
+    }
+
+    function lte_neg(int8 x) public {
+        require(x <= -100);
+    }
+
+    function int_min(int8 x) public {
+        require(x == type(int8).min);
+    }
+}
@@ -0,0 +1,40 @@
+contract Slice {
+    function slice_from(bytes calldata data) public {
+        require(data.length >= 4);
+        bytes calldata rest = data[4:];
+        require(rest.length == data.length - 4);
+    }
+
+    function slice_to(bytes calldata data) public {
+        bytes calldata selector = data[:4];
+        require(selector.length == 4);
+    }
+
+    function slice_range(bytes calldata data, uint256 start, uint256 end) public {
+        bytes calldata part = data[start:end];
+        require(start <= end);
+        require(end <= data.length);
+    }
+
+    function slice_len(bytes calldata data) public returns (uint256) {
+        require(data.length == 10);
+        bytes calldata part = data[2:6];
+        uint256 n = part.length;
+        return n;
+    }
+
+    function slice_forked_start(bytes calldata data, bool c) public returns (uint256) {
+        require(data.length == 10);
+        bytes calldata part = data[(c ? 2 : 4):];
+        uint256 n = part.length;
+        return n;
+    }
+
+    function slice_forked_array(bytes calldata a, bytes calldata b, bool c) public returns (uint256) {
+        require(a.length == 10);
+        require(b.length == 8);
+        bytes calldata part = (c ? a : b)[2:];
+        uint256 n = part.length;
+        return n;
+    }
+}
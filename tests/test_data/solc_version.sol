@@ -0,0 +1,18 @@
+contract SolcVersion {
+    function symbolic(uint256 x, uint256 y) public pure {
+        require(x <= 10);
+        require(y <= 10);
+        uint256 z = x - y;
+    }
+
+    function constant_underflow() public pure {
+        uint256 a = 1;
+        uint256 b = 2;
+        uint256 c = a - b;
+    }
+
+    function safe_math(uint256 x, uint256 y) public pure {
+        require(y <= x, "SafeMath: subtraction overflow");
+        uint256 z = x - y;
+    }
+}
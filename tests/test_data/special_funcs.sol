@@ -0,0 +1,20 @@
+contract Receiver {
+    uint256 balance;
+    uint256 fallbacks;
+
+    receive() external payable {
+        balance += msg.value;
+    }
+
+    fallback() external {
+        fallbacks += 1;
+    }
+}
+
+contract OtherReceiver {
+    uint256 received;
+
+    receive() external payable {
+        received = msg.value;
+    }
+}
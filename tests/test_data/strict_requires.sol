@@ -0,0 +1,23 @@
+contract StrictRequires {
+    function can_fail(uint256 x) public {
+        require(x > 10);
+    }
+
+    function always_holds(uint256 x) public {
+        require(x >= 0);
+    }
+
+    function implied(uint256 x) public {
+        require(x > 10);
+        require(x > 5);
+    }
+
+    function assert_can_fail(uint256 x) public {
+        assert(x > 10);
+    }
+
+    function assert_holds(uint256 x) public {
+        require(x > 10);
+        assert(x > 5);
+    }
+}
@@ -0,0 +1,61 @@
+contract SubUnderflow {
+    function overlapping(uint256 balance, uint256 amount) public pure {
+        require(balance >= 10);
+        require(balance <= 100);
+        require(amount <= 50);
+        uint256 left = balance - amount;
+    }
+
+    function overlapping_assign(uint256 balance, uint256 amount) public pure {
+        balance -= amount;
+    }
+
+    function guarded(uint256 balance, uint256 amount) public pure {
+        require(balance >= amount);
+        uint256 left = balance - amount;
+    }
+
+    function disjoint(uint256 balance, uint256 amount) public pure {
+        require(balance >= 100);
+        require(amount <= 50);
+        uint256 left = balance - amount;
+    }
+
+    function wrapping() public pure {
+        uint256 a = 1;
+        uint256 b = 2;
+        uint256 c;
+        unchecked {
+            c = a - b;
+        }
+    }
+
+    function wrapping_symbolic(uint256 x, uint256 y) public pure {
+        require(x <= 10);
+        require(y <= 10);
+        uint256 z;
+        unchecked {
+            z = x - y;
+        }
+        uint256 w = x - y;
+    }
+
+    function branch_underflow(uint256 x, uint256 y) public pure {
+        require(x <= 200);
+        if (x > 100) {
+            uint256 z = y - x;
+        }
+    }
+
+    function equal_consts() public pure {
+        uint256 a = 5;
+        uint256 b = 5;
+        uint256 c = a - b;
+    }
+
+    function equal_to_bound(uint256 x) public pure {
+        require(x == 100);
+        uint256 a = 100;
+        uint256 c = a - x;
+    }
+}
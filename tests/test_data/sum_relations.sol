@@ -0,0 +1,16 @@
+contract SumRelations {
+    function narrow_after(uint256 a, uint256 b) public {
+        require(a + b == 100);
+        require(a >= 60);
+    }
+
+    function narrow_before(uint256 a, uint256 b) public {
+        require(b <= 30);
+        require(a + b == 100);
+    }
+
+    function impossible(uint256 a, uint256 b) public {
+        require(a > 100);
+        require(a + b == 100);
+    }
+}
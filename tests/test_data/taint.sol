@@ -0,0 +1,29 @@
+contract Taint {
+    address owner;
+    uint256[] values;
+
+    function delegate(address target, bytes memory data) public {
+        target.delegatecall(data);
+    }
+
+    function delegate_assigned(address target, bytes memory data) public {
+        address t = target;
+        t.delegatecall(data);
+    }
+
+    function delegate_owner(bytes memory data) public {
+        owner.delegatecall(data);
+    }
+
+    function delegate_internal(address target) internal {
+        target.delegatecall("");
+    }
+
+    function index(uint256 i) public returns (uint256) {
+        return values[i + 1];
+    }
+
+    function send_value(address to, uint256 amount) public {
+        to.call{value: amount}("");
+    }
+}
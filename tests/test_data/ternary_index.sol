@@ -0,0 +1,15 @@
+contract TernaryIndex {
+    function one_out_of_bounds(uint256[] memory arr, bool flag) public returns (uint256) {
+        require(arr.length == 3);
+        return arr[flag ? 1 : 5];
+    }
+
+    function both_in_bounds(uint256[] memory arr, bool flag) public returns (uint256) {
+        require(arr.length == 3);
+        return arr[flag ? 0 : 2];
+    }
+
+    function unbounded(uint256[] memory arr, bool flag) public returns (uint256) {
+        return arr[flag ? 1 : 5];
+    }
+}
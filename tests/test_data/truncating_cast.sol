@@ -0,0 +1,6 @@
+contract TruncatingCast {
+    function truncate(uint256 x) public pure returns (uint8) {
+        require(x > 255);
+        return uint8(x);
+    }
+}
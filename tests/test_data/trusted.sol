@@ -0,0 +1,20 @@
+contract Trusted {
+    uint256 total;
+
+    function five() internal pure returns (uint256) {
+        return 5;
+    }
+
+    function addTotal(uint256 amount) internal returns (uint256) {
+        total += amount;
+        return total;
+    }
+
+    function callsFive() public pure {
+        uint256 x = five();
+    }
+
+    function callsAddTotal() public {
+        uint256 y = addTotal(10);
+    }
+}
@@ -0,0 +1,12 @@
+contract TupleReturn {
+    function two() public returns (uint256, uint256) {
+        uint256 a = 1;
+        uint256 b = 2;
+        return (a, b);
+    }
+
+    function two_bounded(uint256 x) public returns (uint256, uint256) {
+        require(x < 10);
+        return (x, x + 5);
+    }
+}
@@ -0,0 +1,11 @@
+contract U256Bounds {
+    function unsigned(uint256 x) public {
+        require(x > 5);
+        require(x < 100);
+    }
+
+    function signed(int256 x) public {
+        require(x > -10);
+        require(x < 10);
+    }
+}
@@ -0,0 +1,49 @@
+contract UnboundedLoop {
+    uint256[] users;
+
+    function uncapped() public {
+        uint256 total = 0;
+        for (uint256 i = 0; i < users.length; i++) {
+            total += 1;
+        }
+    }
+
+    function uncapped_while() public {
+        uint256 i = 0;
+        while (i < users.length) {
+            i++;
+        }
+    }
+
+    function uncapped_param(uint256[] memory xs) public {
+        uint256 total = 0;
+        for (uint256 i = 0; i < xs.length; i++) {
+            total += 1;
+        }
+    }
+
+    function capped() public {
+        require(users.length <= 100);
+        uint256 total = 0;
+        for (uint256 i = 0; i < users.length; i++) {
+            total += 1;
+        }
+    }
+
+    function not_a_length(uint256 n) public {
+        uint256 total = 0;
+        for (uint256 i = 0; i < n; i++) {
+            total += 1;
+        }
+    }
+
+    function capped_after_loop() public returns (uint256) {
+        require(users.length <= 100);
+        uint256 total = 0;
+        for (uint256 i = 0; i < users.length; i++) {
+            total += 1;
+        }
+        uint256 len = users.length;
+        return len;
+    }
+}
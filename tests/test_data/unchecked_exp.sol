@@ -0,0 +1,29 @@
+contract UncheckedExp {
+    function checked_const() public pure {
+        uint8 a = 3;
+        uint8 b = a ** 6;
+    }
+
+    function unchecked_const() public pure {
+        uint8 a = 3;
+        uint8 b;
+        unchecked {
+            b = a ** 6;
+        }
+    }
+
+    function unchecked_symbolic(uint8 x) public pure {
+        uint8 b;
+        unchecked {
+            b = x ** 2;
+        }
+    }
+
+    function unchecked_no_wrap(uint8 x) public pure {
+        require(x < 16);
+        uint8 b;
+        unchecked {
+            b = x ** 2;
+        }
+    }
+}
@@ -0,0 +1,34 @@
+contract Token {
+    uint256 public supply;
+
+    function transfer(address to, uint256 amt) public returns (bool) {
+        supply += amt;
+        return true;
+    }
+
+    function burn(uint256 amt) public {
+        supply -= amt;
+    }
+}
+
+contract Wallet {
+    function ignored(address to, uint256 amt) public {
+        Token(address(100)).transfer(to, amt);
+    }
+
+    function bound(address to, uint256 amt) public {
+        bool ok = Token(address(100)).transfer(to, amt);
+    }
+
+    function no_returns(uint256 amt) public {
+        Token(address(100)).burn(amt);
+    }
+
+    function helper(uint256 x) internal returns (uint256) {
+        return x + 1;
+    }
+
+    function ignored_internal(uint256 x) public {
+        helper(x);
+    }
+}
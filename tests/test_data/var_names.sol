@@ -0,0 +1,9 @@
+contract VarNames {
+    function names(uint256 a) public {
+        uint256 b = a + 1;
+    }
+
+    function decoded(bytes memory data) public {
+        (uint256 x, bool y) = abi.decode(data, (uint256, bool));
+    }
+}
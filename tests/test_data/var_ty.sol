@@ -0,0 +1,11 @@
+contract VarTy {
+    struct Point {
+        uint256 x;
+        uint256 y;
+    }
+
+    function declared() public {
+        int128 a = -5;
+        Point memory p;
+    }
+}
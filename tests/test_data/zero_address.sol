@@ -0,0 +1,14 @@
+contract ZeroAddress {
+    function zero() public returns (address) {
+        address z = address(0);
+        return z;
+    }
+
+    function non_zero(address to) public {
+        require(to != address(0));
+    }
+
+    function is_zero(address to) public {
+        require(to == address(0));
+    }
+}
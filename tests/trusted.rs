@@ -0,0 +1,82 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::Analyzer;
+
+fn assert_range_of(trusted_fns: Vec<&str>, func_name: &str, var_name: &str, min: U256, max: U256) {
+    let sol = include_str!("./test_data/trusted.sol");
+    let mut analyzer = Analyzer {
+        trusted_fns: trusted_fns
+            .into_iter()
+            .map(|pattern| pattern.to_string())
+            .collect(),
+        ..Default::default()
+    };
+    let var = body_var(&mut analyzer, sol, func_name, var_name);
+    assert_range(&analyzer, var, min, max);
+}
+
+#[test]
+fn test_untrusted_fn_inlined() {
+    assert_range_of(vec![], "callsFive", "x", U256::from(5), U256::from(5));
+}
+
+#[test]
+fn test_trusted_fn_full_range() {
+    assert_range_of(vec!["five"], "callsFive", "x", U256::zero(), U256::MAX);
+}
+
+#[test]
+fn test_trusted_fn_qualified() {
+    assert_range_of(
+        vec!["Trusted.five"],
+        "callsFive",
+        "x",
+        U256::zero(),
+        U256::MAX,
+    );
+    assert_range_of(
+        vec!["Other.five"],
+        "callsFive",
+        "x",
+        U256::from(5),
+        U256::from(5),
+    );
+}
+
+#[test]
+fn test_trusted_fn_wildcard() {
+    assert_range_of(
+        vec!["Trusted.add*"],
+        "callsAddTotal",
+        "y",
+        U256::zero(),
+        U256::MAX,
+    );
+    assert_range_of(
+        vec!["Trusted.add*"],
+        "callsFive",
+        "x",
+        U256::from(5),
+        U256::from(5),
+    );
+}
+
+#[test]
+fn test_trusted_fn_signature() {
+    assert_range_of(
+        vec!["Trusted.addTotal(uint256)"],
+        "callsAddTotal",
+        "y",
+        U256::zero(),
+        U256::MAX,
+    );
+    assert_range_of(
+        vec!["five(uint256)"],
+        "callsFive",
+        "x",
+        U256::from(5),
+        U256::from(5),
+    );
+}
@@ -0,0 +1,26 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+
+const SOL: &str = include_str!("./test_data/tuple_return.sol");
+
+#[test]
+fn test_two_value_return() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "two");
+    let rets = ctx.return_nodes(&analyzer);
+    assert_eq!(rets.len(), 2);
+    assert_range(&analyzer, rets[0].1, 1, 1);
+    assert_range(&analyzer, rets[1].1, 2, 2);
+}
+
+#[test]
+fn test_two_value_return_keeps_order() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "two_bounded");
+    let rets = ctx.return_nodes(&analyzer);
+    assert_eq!(rets.len(), 2);
+    assert_range(&analyzer, rets[0].1, 0, 9);
+    assert_range(&analyzer, rets[1].1, 5, 14);
+}
@@ -0,0 +1,34 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::{I256, U256};
+use pyrometer::Analyzer;
+
+const SOL: &str = include_str!("./test_data/u256_bounds.sol");
+
+#[test]
+fn test_unsigned_bounds_as_u256() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "unsigned");
+    let x = ctx
+        .latest_var_by_name(&analyzer, "x")
+        .expect("No variable with that name");
+    assert_eq!(x.min_u256(&analyzer), Some(U256::from(6)));
+    assert_eq!(x.max_u256(&analyzer), Some(U256::from(99)));
+    assert_eq!(x.min_i256(&analyzer), Some(I256::from(6)));
+    assert_eq!(x.max_i256(&analyzer), Some(I256::from(99)));
+}
+
+#[test]
+fn test_signed_bounds_as_i256() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "signed");
+    let x = ctx
+        .latest_var_by_name(&analyzer, "x")
+        .expect("No variable with that name");
+    assert_eq!(x.min_i256(&analyzer), Some(I256::from(-9)));
+    assert_eq!(x.max_i256(&analyzer), Some(I256::from(9)));
+    // a negative minimum has no unsigned representation
+    assert_eq!(x.min_u256(&analyzer), None);
+    assert_eq!(x.max_u256(&analyzer), Some(U256::from(9)));
+}
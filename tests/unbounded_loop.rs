@@ -0,0 +1,44 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::U256;
+use pyrometer::context::analyzers::loops::UnboundedLoopAnalyzer;
+use pyrometer::Analyzer;
+use shared::nodes::Concrete;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+
+fn unbounded_loop_count(func_name: &str) -> usize {
+    let sol = include_str!("./test_data/unbounded_loop.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("unbounded_loop.sol");
+    analyzer.unbounded_loops(&file_mapping, ctx).len()
+}
+
+#[test]
+fn test_unbounded_loop_flagged() {
+    assert_eq!(unbounded_loop_count("uncapped"), 1);
+    assert_eq!(unbounded_loop_count("uncapped_while"), 1);
+    assert_eq!(unbounded_loop_count("uncapped_param"), 1);
+}
+
+#[test]
+fn test_unbounded_loop_not_flagged() {
+    assert_eq!(unbounded_loop_count("capped"), 0);
+    assert_eq!(unbounded_loop_count("not_a_length"), 0);
+}
+
+#[test]
+fn test_loop_bound_keeps_length_cap() {
+    let sol = include_str!("./test_data/unbounded_loop.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, sol, "capped_after_loop");
+    let file_mapping = file_mapping("unbounded_loop.sol");
+    assert_eq!(analyzer.unbounded_loops(&file_mapping, ctx).len(), 0);
+    // checking the bound doesn't touch the array, so the loop doesn't widen its length
+    let len = ctx
+        .latest_var_by_name(&analyzer, "len")
+        .expect("No variable with that name");
+    let max = Elem::from(Concrete::from(U256::from(100)));
+    assert!(len.evaled_range_max(&analyzer).unwrap().range_eq(&max));
+}
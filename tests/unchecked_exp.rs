@@ -0,0 +1,38 @@
+mod helpers;
+use helpers::*;
+
+const SOL: &str = include_str!("./test_data/unchecked_exp.sol");
+
+#[test]
+fn test_checked_exp_saturates() {
+    // 3 ** 6 = 729, which reverts when checked, so the range saturates at the type maximum
+    assert_eq!(
+        range_string_of(SOL, "checked_const", "b"),
+        "uint8[255, 255]"
+    );
+}
+
+#[test]
+fn test_unchecked_exp_wraps() {
+    // 729 mod 256 = 217
+    assert_eq!(
+        range_string_of(SOL, "unchecked_const", "b"),
+        "uint8[217, 217]"
+    );
+}
+
+#[test]
+fn test_unchecked_symbolic_exp_widens() {
+    assert_eq!(
+        range_string_of(SOL, "unchecked_symbolic", "b"),
+        "uint8[0, 255]"
+    );
+}
+
+#[test]
+fn test_unchecked_exp_without_wrap() {
+    assert_eq!(
+        range_string_of(SOL, "unchecked_no_wrap", "b"),
+        "uint8[0, 225]"
+    );
+}
@@ -0,0 +1,42 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::context::analyzers::unused_returns::UnusedReturnAnalyzer;
+use pyrometer::Analyzer;
+
+fn unused_returns(func_name: &str) -> Vec<(String, Vec<String>)> {
+    let sol = include_str!("./test_data/unused_return.sol");
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, sol, func_name);
+    let file_mapping = file_mapping("unused_return.sol");
+    analyzer
+        .unused_returns(&file_mapping, ctx)
+        .into_iter()
+        .map(|analysis| (analysis.call_name, analysis.return_tys))
+        .collect()
+}
+
+#[test]
+fn test_ignored_bool_return() {
+    let unused = unused_returns("ignored");
+    assert_eq!(unused.len(), 1);
+    assert!(unused[0].0.starts_with("transfer"));
+    assert_eq!(unused[0].1, vec!["bool".to_string()]);
+}
+
+#[test]
+fn test_bound_return() {
+    assert!(unused_returns("bound").is_empty());
+}
+
+#[test]
+fn test_no_returns() {
+    assert!(unused_returns("no_returns").is_empty());
+}
+
+#[test]
+fn test_ignored_internal_return() {
+    let unused = unused_returns("ignored_internal");
+    assert_eq!(unused.len(), 1);
+    assert!(unused[0].0.starts_with("helper"));
+}
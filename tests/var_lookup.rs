@@ -0,0 +1,78 @@
+use pyrometer::Analyzer;
+use shared::analyzer::GraphLike;
+use shared::context::{ContextNode, ContextVarNode};
+use shared::Node;
+use std::collections::BTreeSet;
+
+const FIXTURES: [&str; 6] = [
+    include_str!("./test_data/math.sol"),
+    include_str!("./test_data/loops.sol"),
+    include_str!("./test_data/function_calls.sol"),
+    include_str!("./test_data/modifier.sol"),
+    include_str!("./test_data/require.sol"),
+    include_str!("./test_data/logical.sol"),
+];
+
+fn contexts(analyzer: &Analyzer) -> Vec<ContextNode> {
+    analyzer
+        .graph
+        .node_indices()
+        .filter(|idx| matches!(analyzer.node(*idx), Node::Context(_)))
+        .map(ContextNode::from)
+        .collect()
+}
+
+fn var_names(analyzer: &Analyzer, ctx: ContextNode) -> BTreeSet<String> {
+    ctx.vars(analyzer)
+        .into_iter()
+        .map(|var| var.name(analyzer))
+        .collect()
+}
+
+fn assert_lookups_agree(analyzer: &Analyzer) {
+    for ctx in contexts(analyzer) {
+        for name in var_names(analyzer, ctx) {
+            let scanned = ctx.var_by_name_from_edges(analyzer, &name);
+            assert_eq!(
+                ctx.var_by_name(analyzer, &name),
+                scanned,
+                "var_by_name mismatch for {name} in {}",
+                ctx.path(analyzer)
+            );
+            assert_eq!(
+                ctx.latest_var_by_name(analyzer, &name),
+                scanned.map(|var| var.latest_version(analyzer)),
+                "latest_var_by_name mismatch for {name} in {}",
+                ctx.path(analyzer)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_cached_lookup_matches_edge_scan() {
+    for sol in FIXTURES {
+        let mut analyzer = Analyzer::default();
+        let _ = analyzer.parse(sol);
+        assert_lookups_agree(&analyzer);
+    }
+}
+
+#[test]
+fn test_cached_lookup_matches_edge_scan_after_pruning() {
+    for sol in FIXTURES {
+        let mut analyzer = Analyzer::default();
+        let _ = analyzer.parse(sol);
+        analyzer.prune_temporaries();
+        assert_lookups_agree(&analyzer);
+    }
+}
+
+#[test]
+fn test_missing_var_not_found() {
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(FIXTURES[0]);
+    for ctx in contexts(&analyzer) {
+        assert_eq!(ctx.latest_var_by_name(&analyzer, "not_a_var"), None);
+    }
+}
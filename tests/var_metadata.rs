@@ -0,0 +1,37 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+
+const SOL: &str = include_str!("./test_data/var_metadata.sol");
+
+#[derive(Debug, PartialEq)]
+struct Tainted(&'static str);
+
+#[derive(Debug, PartialEq)]
+struct Origin(usize);
+
+#[test]
+fn test_set_and_get_metadata() {
+    let mut analyzer = Analyzer::default();
+    let y = body_var(&mut analyzer, SOL, "tagged", "y");
+    assert!(y.metadata::<Tainted>(&analyzer).is_none());
+
+    y.set_metadata(&mut analyzer, Tainted("x"));
+    y.set_metadata(&mut analyzer, Origin(1));
+    assert_eq!(y.metadata::<Tainted>(&analyzer), Some(&Tainted("x")));
+    assert_eq!(y.metadata::<Origin>(&analyzer), Some(&Origin(1)));
+
+    // setting the same type again replaces the previous value
+    y.set_metadata(&mut analyzer, Tainted("calldata"));
+    assert_eq!(y.metadata::<Tainted>(&analyzer), Some(&Tainted("calldata")));
+}
+
+#[test]
+fn test_metadata_ignored_in_equality() {
+    let mut analyzer = Analyzer::default();
+    let y = body_var(&mut analyzer, SOL, "tagged", "y");
+    let before = y.underlying(&analyzer).clone();
+    y.set_metadata(&mut analyzer, Tainted("x"));
+    assert_eq!(&before, y.underlying(&analyzer));
+}
@@ -0,0 +1,50 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+
+const SOL: &str = include_str!("./test_data/var_names.sol");
+
+#[test]
+fn test_user_variable_names() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "names");
+    let b = ctx
+        .latest_var_by_name(&analyzer, "b")
+        .expect("No variable with that name");
+    assert!(!b.is_tmp(&analyzer));
+    assert_eq!(b.name(&analyzer), "b");
+    assert_eq!(b.display_name(&analyzer), "b");
+}
+
+#[test]
+fn test_temporary_names() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "names");
+    let tmp = ctx
+        .vars(&analyzer)
+        .into_iter()
+        .find(|var| var.is_tmp(&analyzer) && var.name(&analyzer).ends_with("(a + 1)"))
+        .expect("No temporary for a + 1");
+    assert!(tmp.name(&analyzer).starts_with("tmp"));
+    assert_eq!(tmp.display_name(&analyzer), "(a + 1)");
+}
+
+#[test]
+fn test_no_internal_names_displayed() {
+    let mut analyzer = Analyzer::default();
+    let ctx = body_ctx(&mut analyzer, SOL, "decoded");
+    let tmps: Vec<_> = ctx
+        .vars(&analyzer)
+        .into_iter()
+        .filter(|var| var.is_tmp(&analyzer))
+        .collect();
+    assert!(!tmps.is_empty());
+    tmps.iter().for_each(|tmp| {
+        let display_name = tmp.display_name(&analyzer);
+        assert!(
+            !display_name.starts_with("tmp"),
+            "internal name displayed: {display_name}"
+        );
+    });
+}
@@ -0,0 +1,49 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+use shared::analyzer::Search;
+use shared::{nodes::FunctionNode, Edge};
+use solang_parser::pt::Loc;
+
+/// The name and location of each variable of each function's body context, in `vars_sorted` order
+fn vars_by_func(sol: &str) -> Vec<(String, Vec<(String, Option<Loc>)>)> {
+    let mut analyzer = Analyzer::default();
+    let entry = parse(&mut analyzer, sol);
+
+    let mut funcs: Vec<_> = analyzer
+        .search_children(entry, &Edge::Func)
+        .into_iter()
+        .map(FunctionNode::from)
+        .collect();
+    funcs.sort_by_key(|func| func.name(&analyzer));
+    funcs
+        .into_iter()
+        .filter_map(|func| {
+            let ctx = func.maybe_body_ctx(&analyzer)?;
+            let vars = ctx
+                .vars_sorted(&analyzer)
+                .into_iter()
+                .map(|var| (var.name(&analyzer), var.underlying(&analyzer).loc))
+                .collect();
+            Some((func.name(&analyzer), vars))
+        })
+        .collect()
+}
+
+#[test]
+fn test_vars_stable_across_runs() {
+    let sol = include_str!("./test_data/math.sol");
+    assert_eq!(vars_by_func(sol), vars_by_func(sol));
+}
+
+#[test]
+fn test_vars_ordered_by_loc_then_name() {
+    let sol = include_str!("./test_data/math.sol");
+    vars_by_func(sol).into_iter().for_each(|(_, vars)| {
+        let keys: Vec<_> = vars.iter().map(|(name, loc)| (*loc, name)).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    });
+}
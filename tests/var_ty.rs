@@ -0,0 +1,32 @@
+mod helpers;
+use helpers::*;
+
+use pyrometer::Analyzer;
+use shared::nodes::{Builtin, TypeNode};
+
+const SOL: &str = include_str!("./test_data/var_ty.sol");
+
+#[test]
+fn test_builtin_ty() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "declared");
+    let a = ctx
+        .latest_var_by_name(&analyzer, "a")
+        .expect("No variable with that name");
+    assert_eq!(a.builtin(&analyzer), Some(Builtin::Int(128)));
+    assert!(a.user_ty(&analyzer).is_none());
+}
+
+#[test]
+fn test_user_ty() {
+    let mut analyzer = Analyzer::default();
+    let ctx = live_body_ctx(&mut analyzer, SOL, "declared");
+    let p = ctx
+        .latest_var_by_name(&analyzer, "p")
+        .expect("No variable with that name");
+    assert!(p.builtin(&analyzer).is_none());
+    match p.user_ty(&analyzer) {
+        Some(TypeNode::Struct(strukt)) => assert_eq!(strukt.name(&analyzer), "Point"),
+        other => panic!("Expected a struct type, got {other:?}"),
+    }
+}
@@ -0,0 +1,52 @@
+mod helpers;
+use helpers::*;
+
+use ethers_core::types::Address;
+use pyrometer::Analyzer;
+use shared::range::{elem::RangeElem, elem_ty::Elem};
+use shared::{context::ContextVarNode, nodes::Concrete};
+
+fn var_range(
+    analyzer: &mut Analyzer,
+    sol: &str,
+    func_name: &str,
+    var_name: &str,
+) -> (Elem<Concrete>, Elem<Concrete>) {
+    let ctx = live_body_ctx(analyzer, sol, func_name);
+    let var: ContextVarNode = ctx
+        .latest_var_by_name(analyzer, var_name)
+        .expect("No variable with that name");
+    (
+        var.evaled_range_min(analyzer).unwrap(),
+        var.evaled_range_max(analyzer).unwrap(),
+    )
+}
+
+fn address(last_byte: u8) -> Elem<Concrete> {
+    let mut bytes = [0u8; 20];
+    bytes[19] = last_byte;
+    Elem::from(Concrete::Address(Address::from_slice(&bytes)))
+}
+
+#[test]
+fn test_zero_address_is_single_point() {
+    let sol = include_str!("./test_data/zero_address.sol");
+    let (min, max) = var_range(&mut Analyzer::default(), sol, "zero", "z");
+    assert!(min.range_eq(&address(0)));
+    assert!(max.range_eq(&address(0)));
+}
+
+#[test]
+fn test_require_non_zero_address() {
+    let sol = include_str!("./test_data/zero_address.sol");
+    let (min, _max) = var_range(&mut Analyzer::default(), sol, "non_zero", "to");
+    assert!(min.range_eq(&address(1)));
+}
+
+#[test]
+fn test_require_zero_address() {
+    let sol = include_str!("./test_data/zero_address.sol");
+    let (min, max) = var_range(&mut Analyzer::default(), sol, "is_zero", "to");
+    assert!(min.range_eq(&address(0)));
+    assert!(max.range_eq(&address(0)));
+}